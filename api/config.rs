@@ -0,0 +1,93 @@
+// Server configuration for the NebulaVRF API.
+
+use axum::http::HeaderValue;
+use std::time::Duration;
+
+use super::rate_limit::RateLimitConfig;
+
+/// Default request body cap, overridable with the `NEBULA_MAX_BODY_BYTES` env var.
+const DEFAULT_MAX_BODY_BYTES: usize = 64 * 1024;
+
+/// Default request timeout, overridable with the `NEBULA_REQUEST_TIMEOUT_SECS` env var.
+const DEFAULT_REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Settings applied by `create_routes` to every route.
+///
+/// The defaults are conservative: no cross-origin access, 64 KiB request bodies,
+/// and a 30 second timeout.
+#[derive(Debug, Clone)]
+pub struct ApiConfig {
+    /// Origins allowed to call the API from a browser, e.g. `https://app.example.com`.
+    /// Empty disables CORS.
+    pub allowed_origins: Vec<HeaderValue>,
+    /// Largest request body accepted; larger bodies get `413 Payload Too Large`.
+    pub max_body_bytes: usize,
+    /// Time after which a request is aborted with `408 Request Timeout`.
+    pub request_timeout: Duration,
+    /// Per-client rate limit.
+    pub rate_limit: RateLimitConfig,
+}
+
+impl Default for ApiConfig {
+    fn default() -> Self {
+        ApiConfig {
+            allowed_origins: Vec::new(),
+            max_body_bytes: DEFAULT_MAX_BODY_BYTES,
+            request_timeout: DEFAULT_REQUEST_TIMEOUT,
+            rate_limit: RateLimitConfig::default(),
+        }
+    }
+}
+
+impl ApiConfig {
+    /// Reads the configuration from the environment, falling back to defaults.
+    ///
+    /// `NEBULA_CORS_ORIGINS` is a comma-separated list of origins; entries that
+    /// are not valid header values are skipped with a warning.
+    pub fn from_env() -> Self {
+        let mut config = ApiConfig::default().rate_limit(RateLimitConfig::from_env());
+        if let Ok(origins) = std::env::var("NEBULA_CORS_ORIGINS") {
+            for origin in origins.split(',').map(str::trim).filter(|o| !o.is_empty()) {
+                match HeaderValue::from_str(origin) {
+                    Ok(value) => config = config.allow_origin(value),
+                    Err(_) => tracing::warn!(origin, "ignoring invalid CORS origin"),
+                }
+            }
+        }
+        if let Some(bytes) = std::env::var("NEBULA_MAX_BODY_BYTES").ok().and_then(|v| v.parse().ok()) {
+            config = config.max_body_bytes(bytes);
+        }
+        if let Some(secs) = std::env::var("NEBULA_REQUEST_TIMEOUT_SECS")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .filter(|secs: &u64| *secs > 0)
+        {
+            config = config.request_timeout(Duration::from_secs(secs));
+        }
+        config
+    }
+
+    /// Allows browser requests from `origin`.
+    pub fn allow_origin(mut self, origin: HeaderValue) -> Self {
+        self.allowed_origins.push(origin);
+        self
+    }
+
+    /// Sets the largest accepted request body, in bytes.
+    pub fn max_body_bytes(mut self, bytes: usize) -> Self {
+        self.max_body_bytes = bytes;
+        self
+    }
+
+    /// Sets the request timeout.
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = timeout;
+        self
+    }
+
+    /// Sets the per-client rate limit.
+    pub fn rate_limit(mut self, rate_limit: RateLimitConfig) -> Self {
+        self.rate_limit = rate_limit;
+        self
+    }
+}
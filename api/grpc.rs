@@ -0,0 +1,134 @@
+// gRPC service mirroring the REST endpoints, generated from proto/nebula_vrf.proto.
+
+use nebula_vrf::vrf::commit::{commit, commit_with_salt, verify_commit, verify_commit_with_salt};
+use nebula_vrf::vrf::{generate_random, verify_output};
+use tonic::{Request, Response, Status};
+
+use super::handlers::{blocking, random_seed, ApiError};
+use super::state::AppState;
+
+pub mod pb {
+    tonic::include_proto!("nebula_vrf.v1");
+}
+
+use pb::vrf_server::Vrf;
+use pb::{
+    CommitRequest, CommitResponse, GetRandomRequest, GetRandomResponse, VerifyCommitRequest,
+    VerifyCommitResponse, VerifyRandomRequest, VerifyRandomResponse,
+};
+
+pub use pb::vrf_server::VrfServer;
+
+/// Implements the `nebula_vrf.v1.Vrf` service on top of the shared API state.
+#[derive(Clone, Default)]
+pub struct VrfService {
+    state: AppState,
+}
+
+impl VrfService {
+    pub fn new(state: AppState) -> Self {
+        Self { state }
+    }
+
+    /// Wraps the service for use with `tonic::transport::Server`.
+    pub fn into_server(self) -> VrfServer<Self> {
+        VrfServer::new(self)
+    }
+}
+
+/// Maps a REST error body to a gRPC status, keeping the error code in the message.
+fn to_status((status, body): ApiError) -> Status {
+    let message = format!("{}: {}", body.code, body.message);
+    if status.is_client_error() {
+        Status::invalid_argument(message)
+    } else {
+        Status::internal(message)
+    }
+}
+
+// `Status` is large, but it is what tonic handlers return anyway.
+#[allow(clippy::result_large_err)]
+fn fixed_len<const N: usize>(field: &str, bytes: &[u8]) -> Result<[u8; N], Status> {
+    bytes.try_into().map_err(|_| {
+        Status::invalid_argument(format!(
+            "invalid_length: Field `{}` must be {} bytes, got {}",
+            field,
+            N,
+            bytes.len()
+        ))
+    })
+}
+
+#[tonic::async_trait]
+impl Vrf for VrfService {
+    async fn get_random(
+        &self,
+        request: Request<GetRandomRequest>,
+    ) -> Result<Response<GetRandomResponse>, Status> {
+        let req = request.into_inner();
+        let seed = match &req.seed {
+            Some(seed) => fixed_len::<32>("seed", seed)?,
+            None => random_seed(),
+        };
+
+        let vrf = blocking(move || generate_random(&seed))
+            .await
+            .map_err(to_status)?
+            .map_err(|e| {
+                if e.is_retryable() {
+                    Status::unavailable(e.to_string())
+                } else {
+                    Status::internal(e.to_string())
+                }
+            })?;
+        self.state.metrics.record_generated(1);
+
+        Ok(Response::new(GetRandomResponse {
+            seed: seed.to_vec(),
+            randomness: vrf.randomness().map_err(|e| Status::internal(e.to_string()))?.to_vec(),
+            proof: req.proof.then_some(vrf.proof),
+            public_key: req.proof.then_some(vrf.public_key),
+            commitment: req.commit.then(|| commit(&seed).to_vec()),
+        }))
+    }
+
+    async fn verify_random(
+        &self,
+        request: Request<VerifyRandomRequest>,
+    ) -> Result<Response<VerifyRandomResponse>, Status> {
+        let req = request.into_inner();
+        let valid = blocking(move || {
+            verify_output(&req.seed, &req.output, &req.proof, &req.public_key).is_ok()
+        })
+        .await
+        .map_err(to_status)?;
+        self.state.metrics.record_verification(valid);
+        Ok(Response::new(VerifyRandomResponse { valid }))
+    }
+
+    async fn commit(
+        &self,
+        request: Request<CommitRequest>,
+    ) -> Result<Response<CommitResponse>, Status> {
+        let req = request.into_inner();
+        let commitment = match &req.salt {
+            Some(salt) => commit_with_salt(&req.seed, salt),
+            None => commit(&req.seed),
+        };
+        Ok(Response::new(CommitResponse { commitment: commitment.to_vec() }))
+    }
+
+    async fn verify_commit(
+        &self,
+        request: Request<VerifyCommitRequest>,
+    ) -> Result<Response<VerifyCommitResponse>, Status> {
+        let req = request.into_inner();
+        let commitment = fixed_len::<32>("commitment", &req.commitment)?;
+        let valid = match &req.salt {
+            Some(salt) => verify_commit_with_salt(&req.seed, salt, &commitment),
+            None => verify_commit(&req.seed, &commitment),
+        };
+        self.state.metrics.record_verification(valid);
+        Ok(Response::new(VerifyCommitResponse { valid }))
+    }
+}
@@ -0,0 +1,178 @@
+// Error responses shared by the NebulaVRF API handlers.
+
+use axum::{http::StatusCode, Json};
+use base64::{engine::general_purpose, Engine as _};
+use nebula_vrf::vrf::VRFError;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// JSON body returned for every failed request.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ErrorResponse {
+    /// Machine-readable error code.
+    pub code: &'static str,
+    /// Human-readable explanation.
+    pub message: String,
+}
+
+/// Error type returned by handlers: a status code and a JSON error body.
+pub type ApiError = (StatusCode, Json<ErrorResponse>);
+
+/// Builds a 400 response with the given code and message.
+pub fn bad_request(code: &'static str, message: impl Into<String>) -> ApiError {
+    (
+        StatusCode::BAD_REQUEST,
+        Json(ErrorResponse { code, message: message.into() }),
+    )
+}
+
+/// Builds a 404 response with the given code and message.
+pub fn not_found(code: &'static str, message: impl Into<String>) -> ApiError {
+    (
+        StatusCode::NOT_FOUND,
+        Json(ErrorResponse { code, message: message.into() }),
+    )
+}
+
+/// Builds a 413 response with the given code and message.
+pub fn payload_too_large(code: &'static str, message: impl Into<String>) -> ApiError {
+    (
+        StatusCode::PAYLOAD_TOO_LARGE,
+        Json(ErrorResponse { code, message: message.into() }),
+    )
+}
+
+/// Builds a 500 response with the given code and message.
+pub fn internal_error(code: &'static str, message: impl Into<String>) -> ApiError {
+    (
+        StatusCode::INTERNAL_SERVER_ERROR,
+        Json(ErrorResponse { code, message: message.into() }),
+    )
+}
+
+/// Builds a 429 response for a client that exceeded its rate limit.
+pub fn too_many_requests(message: impl Into<String>) -> ApiError {
+    (
+        StatusCode::TOO_MANY_REQUESTS,
+        Json(ErrorResponse { code: "rate_limited", message: message.into() }),
+    )
+}
+
+/// Maps a VRF error raised while serving a request to a 500 response, or to
+/// 503 when the error is retryable.
+pub fn vrf_error(err: VRFError) -> ApiError {
+    let status = if err.is_retryable() {
+        StatusCode::SERVICE_UNAVAILABLE
+    } else {
+        StatusCode::INTERNAL_SERVER_ERROR
+    };
+    (status, Json(ErrorResponse { code: vrf_error_code(&err), message: err.to_string() }))
+}
+
+/// Machine-readable code for a VRF error.
+pub fn vrf_error_code(err: &VRFError) -> &'static str {
+    match err {
+        VRFError::InvalidSignature => "invalid_signature",
+        VRFError::InvalidPublicKey => "invalid_public_key",
+        VRFError::InvalidCommitment => "invalid_commitment",
+        VRFError::DeserializationError => "deserialization_error",
+        VRFError::VerificationFailed => "verification_failed",
+        VRFError::InvalidLength { .. } => "invalid_length",
+        VRFError::PublicKeyNotInSubgroup => "public_key_not_in_subgroup",
+        VRFError::IdentityPoint => "identity_point",
+        VRFError::SeedTooShort { .. } => "seed_too_short",
+        VRFError::SignatureNotInSubgroup => "signature_not_in_subgroup",
+        VRFError::Expired { .. } => "expired",
+        VRFError::NonCanonical => "non_canonical",
+        VRFError::DuplicateKey => "duplicate_key",
+        VRFError::UnsupportedVersion { .. } => "unsupported_version",
+        VRFError::Transient => "transient",
+    }
+}
+
+/// Decodes a hex request field, naming the field in the error on failure.
+///
+/// Surrounding whitespace and one `0x`/`0X` prefix are ignored, so values
+/// copied from a terminal or a block explorer decode as expected.
+pub fn decode_hex(field: &str, value: &str) -> Result<Vec<u8>, ApiError> {
+    hex::decode(normalize_hex(value)).map_err(|e| {
+        bad_request("invalid_hex", format!("Field `{}` is not valid hex: {}", field, e))
+    })
+}
+
+/// Trims surrounding whitespace and an optional `0x`/`0X` prefix from a hex value.
+fn normalize_hex(value: &str) -> &str {
+    let value = value.trim();
+    value
+        .strip_prefix("0x")
+        .or_else(|| value.strip_prefix("0X"))
+        .unwrap_or(value)
+}
+
+/// Text encoding of binary request fields.
+#[derive(Debug, Clone, Copy, Default, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum FieldEncoding {
+    #[default]
+    Hex,
+    Base64,
+}
+
+/// Decodes a request field in the given encoding, naming the field in the error on failure.
+///
+/// Surrounding whitespace is ignored in both encodings.
+pub fn decode_field(field: &str, value: &str, encoding: FieldEncoding) -> Result<Vec<u8>, ApiError> {
+    match encoding {
+        FieldEncoding::Hex => decode_hex(field, value),
+        FieldEncoding::Base64 => general_purpose::STANDARD.decode(value.trim()).map_err(|e| {
+            bad_request("invalid_base64", format!("Field `{}` is not valid base64: {}", field, e))
+        }),
+    }
+}
+
+/// Decodes a request field that may be at most `max` bytes long.
+///
+/// The decoded length is worked out from the encoded text first, so an
+/// oversized field is rejected with `413` before its bytes are allocated.
+pub fn decode_field_max(
+    field: &str,
+    value: &str,
+    encoding: FieldEncoding,
+    max: usize,
+) -> Result<Vec<u8>, ApiError> {
+    let value = value.trim();
+    let decoded_len = match encoding {
+        FieldEncoding::Hex => normalize_hex(value).len() / 2,
+        FieldEncoding::Base64 => {
+            let padding = value.bytes().rev().take_while(|&b| b == b'=').count();
+            (value.len() / 4 * 3).saturating_sub(padding)
+        }
+    };
+    if decoded_len > max {
+        return Err(payload_too_large(
+            "field_too_large",
+            format!("Field `{}` must be at most {} bytes, got {}", field, max, decoded_len),
+        ));
+    }
+    decode_field(field, value, encoding)
+}
+
+/// Decodes a hex request field that must be exactly `N` bytes long.
+pub fn decode_hex_array<const N: usize>(field: &str, value: &str) -> Result<[u8; N], ApiError> {
+    decode_field_array(field, value, FieldEncoding::Hex)
+}
+
+/// Decodes a request field that must be exactly `N` bytes long.
+pub fn decode_field_array<const N: usize>(
+    field: &str,
+    value: &str,
+    encoding: FieldEncoding,
+) -> Result<[u8; N], ApiError> {
+    let bytes = decode_field(field, value, encoding)?;
+    bytes.as_slice().try_into().map_err(|_| {
+        bad_request(
+            "invalid_length",
+            format!("Field `{}` must be {} bytes, got {}", field, N, bytes.len()),
+        )
+    })
+}
@@ -0,0 +1,78 @@
+// Request extractors that report malformed input as `ErrorResponse` bodies.
+
+use axum::{
+    async_trait,
+    extract::{rejection::{JsonRejection, QueryRejection}, FromRequest, FromRequestParts, Query, Request},
+    http::{request::Parts, StatusCode},
+    Json,
+};
+use serde::de::DeserializeOwned;
+
+use super::error::{bad_request, ApiError, ErrorResponse};
+
+/// JSON body extractor that rejects bad bodies with a `400` naming the problem.
+///
+/// Request structs use `#[serde(deny_unknown_fields)]`, so a misspelled field
+/// such as `publickey` fails here with `invalid_field` instead of being
+/// ignored. axum's own `Json` would answer `422` with a plain-text body.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct StrictJson<T>(pub T);
+
+#[async_trait]
+impl<T, S> FromRequest<S> for StrictJson<T>
+where
+    T: DeserializeOwned,
+    S: Send + Sync,
+{
+    type Rejection = ApiError;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, ApiError> {
+        match Json::<T>::from_request(req, state).await {
+            Ok(Json(value)) => Ok(StrictJson(value)),
+            Err(rejection) => Err(json_rejection(rejection)),
+        }
+    }
+}
+
+/// Query string extractor that rejects bad parameters with a `400` naming the problem.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct StrictQuery<T>(pub T);
+
+#[async_trait]
+impl<T, S> FromRequestParts<S> for StrictQuery<T>
+where
+    T: DeserializeOwned,
+    S: Send + Sync,
+{
+    type Rejection = ApiError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, ApiError> {
+        match Query::<T>::from_request_parts(parts, state).await {
+            Ok(Query(value)) => Ok(StrictQuery(value)),
+            Err(rejection) => Err(query_rejection(rejection)),
+        }
+    }
+}
+
+fn json_rejection(rejection: JsonRejection) -> ApiError {
+    let message = rejection.body_text();
+    match rejection {
+        JsonRejection::JsonDataError(_) => bad_request("invalid_field", message),
+        JsonRejection::JsonSyntaxError(_) => bad_request("invalid_json", message),
+        JsonRejection::MissingJsonContentType(_) => error(StatusCode::UNSUPPORTED_MEDIA_TYPE, "unsupported_media_type", message),
+        // Reading the body failed, e.g. because it exceeded the size limit.
+        rejection => {
+            let status = rejection.status();
+            let code = if status == StatusCode::PAYLOAD_TOO_LARGE { "body_too_large" } else { "invalid_body" };
+            error(status, code, message)
+        }
+    }
+}
+
+fn query_rejection(rejection: QueryRejection) -> ApiError {
+    bad_request("invalid_query", rejection.body_text())
+}
+
+fn error(status: StatusCode, code: &'static str, message: String) -> ApiError {
+    (status, Json(ErrorResponse { code, message }))
+}
@@ -1,77 +1,276 @@
-use axum::{Json, extract::Query};
+mod error;
+mod extract;
+
+use axum::{Json, extract::State, http::header, response::IntoResponse};
 use serde::{Deserialize, Serialize};
-use nebula_vrf::vrf::{generate_random, verify_proof};
-use nebula_vrf::vrf::commit::{commit, verify_commit};
+use utoipa::{IntoParams, ToSchema};
+use nebula_vrf::vrf::{
+    derive_child_from_output, generate_random, proof_to_hash, verify_output, verify_proofs_batch, VRFError,
+    VRF_PUBLIC_KEY_UNCOMPRESSED_SIZE, VRF_SIGNATURE_UNCOMPRESSED_SIZE,
+};
+use nebula_vrf::vrf::commit::{commit, commit_with_salt, verify_commit, verify_commit_with_salt};
+use nebula_vrf::utils::encoding::{encode as multibase_encode, Base};
+use nebula_vrf::helpers::{OsSeedSource, SeedSource, MAX_PAYLOAD_FIELD_LEN};
 use nebula_vrf::SamplePayload;
 
-use rand::rngs::OsRng;
-use rand::RngCore;
+use std::sync::{Arc, OnceLock};
+use tokio::sync::Semaphore;
+use tokio::task::JoinSet;
+
+use super::state::AppState;
 
-/// GET /get-random?seed=<hex>&proof=true&commit=true
-#[derive(Debug, Deserialize)]
+pub use error::{too_many_requests, ApiError, ErrorResponse, FieldEncoding};
+use extract::{StrictJson, StrictQuery};
+use error::{
+    bad_request, decode_field_array, decode_field_max, decode_hex_array, internal_error, not_found,
+    vrf_error, vrf_error_code,
+};
+
+/// GET /get-random?seed=<hex>&proof=true&commit=true&encoding=base58btc
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+#[serde(deny_unknown_fields)]
 pub struct RandomRequest {
+    /// Hex-encoded 32-byte seed; a random seed is used when omitted.
     pub seed: Option<String>,
+    /// Include the proof and public key in the response.
     pub proof: Option<bool>,
+    /// Include a commitment to the seed in the response.
     pub commit: Option<bool>,
+    /// Text encoding of the response fields (default `hex`).
+    #[param(inline)]
+    pub encoding: Option<ResponseEncoding>,
+}
+
+/// Text encoding of binary response fields.
+///
+/// `hex` is plain hex as before; the other values are multibase strings whose
+/// first character names the base, for IPFS and libp2p interop.
+#[derive(Debug, Clone, Copy, Default, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum ResponseEncoding {
+    #[default]
+    Hex,
+    Base16,
+    Base32,
+    Base58btc,
+    Base64,
+    Base64url,
+}
+
+impl ResponseEncoding {
+    fn encode(self, bytes: &[u8]) -> String {
+        let base = match self {
+            ResponseEncoding::Hex => return hex::encode(bytes),
+            ResponseEncoding::Base16 => Base::Base16,
+            ResponseEncoding::Base32 => Base::Base32,
+            ResponseEncoding::Base58btc => Base::Base58Btc,
+            ResponseEncoding::Base64 => Base::Base64,
+            ResponseEncoding::Base64url => Base::Base64Url,
+        };
+        multibase_encode(bytes, base)
+    }
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct RandomResponse {
     seed: String,
     randomness: String,
     #[serde(skip_serializing_if = "Option::is_none")]
+    proof: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     public_key: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     commitment: Option<String>,
 }
 
-pub async fn get_random_handler(Query(params): Query<RandomRequest>) -> Json<RandomResponse> {
-    // Use user-supplied seed if provided and valid, else generate random
+#[utoipa::path(
+    get,
+    path = "/get-random",
+    params(RandomRequest),
+    responses(
+        (status = 200, description = "Fresh VRF randomness", body = RandomResponse),
+        (status = 400, description = "Malformed request", body = ErrorResponse),
+        (status = 429, description = "Rate limit exceeded", body = ErrorResponse),
+        (status = 500, description = "Generation failed", body = ErrorResponse),
+    )
+)]
+pub async fn get_random_handler(
+    State(state): State<AppState>,
+    StrictQuery(params): StrictQuery<RandomRequest>,
+) -> Result<Json<RandomResponse>, ApiError> {
+    // Use user-supplied seed if provided, else generate random
     let seed = if let Some(seed_hex) = &params.seed {
-        match hex::decode(seed_hex) {
-            Ok(bytes) if bytes.len() == 32 => bytes,
-            _ => {
-                let mut s = [0u8; 32];
-                OsRng.fill_bytes(&mut s);
-                s.to_vec()
-            }
-        }
+        decode_hex_array::<32>("seed", seed_hex)?
     } else {
-        let mut s = [0u8; 32];
-        OsRng.fill_bytes(&mut s);
-        s.to_vec()
+        random_seed()
     };
 
+    let include_proof = params.proof.unwrap_or(false);
+    let include_commit = params.commit.unwrap_or(false);
+    let encoding = params.encoding.unwrap_or_default();
+    let response = blocking(move || random_response(&seed, include_proof, include_commit, encoding))
+        .await?
+        .map_err(vrf_error)?;
+    state.metrics.record_generated(1);
+
+    Ok(Json(response))
+}
+
+/// Runs CPU-bound crypto on the blocking pool so async workers stay responsive.
+///
+/// Shares the batch permits, so at most one task per core does crypto at a time.
+pub async fn blocking<T, F>(f: F) -> Result<T, ApiError>
+where
+    F: FnOnce() -> T + Send + 'static,
+    T: Send + 'static,
+{
+    let permit = crypto_permits()
+        .acquire_owned()
+        .await
+        .map_err(|e| internal_error("task_failed", e.to_string()))?;
+    let span = tracing::Span::current();
+    tokio::task::spawn_blocking(move || {
+        let _permit = permit;
+        span.in_scope(f)
+    })
+    .await
+    .map_err(|e| internal_error("task_failed", e.to_string()))
+}
+
+pub fn random_seed() -> [u8; 32] {
+    let mut s = [0u8; 32];
+    OsSeedSource.fill(&mut s);
+    s
+}
+
+fn random_response(
+    seed: &[u8],
+    include_proof: bool,
+    include_commit: bool,
+    encoding: ResponseEncoding,
+) -> Result<RandomResponse, VRFError> {
     // Generate randomness using NebulaVRF
-    let vrf = generate_random(&seed).expect("VRF generation failed");
+    let vrf = generate_random(seed)?;
 
-    let response = RandomResponse {
-        seed: hex::encode(&seed),
-        randomness: hex::encode(vrf.output),
-        public_key: if params.proof.unwrap_or(false) {
-            Some(hex::encode(vrf.public_key))
+    Ok(RandomResponse {
+        seed: encoding.encode(seed),
+        randomness: encoding.encode(&vrf.randomness()?),
+        proof: if include_proof {
+            Some(encoding.encode(&vrf.proof))
         } else {
             None
         },
-        commitment: if params.commit.unwrap_or(false) {
-            Some(hex::encode(commit(&seed)))
+        public_key: if include_proof {
+            Some(encoding.encode(&vrf.public_key))
         } else {
             None
         },
-    };
+        commitment: if include_commit {
+            Some(encoding.encode(&commit(seed)))
+        } else {
+            None
+        },
+    })
+}
+
+/// POST /get-random-batch
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct RandomBatchRequest {
+    pub count: u32,
+    pub proof: Option<bool>,
+}
+
+/// Default cap on `count`, overridable with the `NEBULA_MAX_BATCH_COUNT` env var.
+const DEFAULT_MAX_BATCH_COUNT: u32 = 10_000;
+
+/// Number of draws generated by a single blocking task.
+const BATCH_CHUNK_SIZE: u32 = 250;
 
-    Json(response)
+fn max_batch_count() -> u32 {
+    std::env::var("NEBULA_MAX_BATCH_COUNT")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(DEFAULT_MAX_BATCH_COUNT)
+}
+
+/// Permits shared by all crypto work, bounding how many blocking tasks run at
+/// once so a single large batch cannot starve other requests.
+fn crypto_permits() -> Arc<Semaphore> {
+    static PERMITS: OnceLock<Arc<Semaphore>> = OnceLock::new();
+    PERMITS
+        .get_or_init(|| {
+            let workers = std::thread::available_parallelism().map_or(4, |n| n.get());
+            Arc::new(Semaphore::new(workers))
+        })
+        .clone()
+}
+
+#[utoipa::path(
+    post,
+    path = "/get-random-batch",
+    request_body = RandomBatchRequest,
+    responses(
+        (status = 200, description = "One draw per requested seed", body = [RandomResponse]),
+        (status = 400, description = "Malformed request", body = ErrorResponse),
+        (status = 429, description = "Rate limit exceeded", body = ErrorResponse),
+        (status = 500, description = "Generation failed", body = ErrorResponse),
+    )
+)]
+pub async fn get_random_batch_handler(
+    State(state): State<AppState>,
+    StrictJson(req): StrictJson<RandomBatchRequest>,
+) -> Result<Json<Vec<RandomResponse>>, ApiError> {
+    let max = max_batch_count();
+    if req.count > max {
+        return Err(bad_request(
+            "batch_too_large",
+            format!("Field `count` must be at most {}, got {}", max, req.count),
+        ));
+    }
+    let include_proof = req.proof.unwrap_or(false);
+
+    let mut tasks = JoinSet::new();
+    let mut start = 0;
+    while start < req.count {
+        let len = BATCH_CHUNK_SIZE.min(req.count - start);
+        let permit = crypto_permits()
+            .acquire_owned()
+            .await
+            .map_err(|e| internal_error("task_failed", e.to_string()))?;
+        tasks.spawn_blocking(move || {
+            let _permit = permit;
+            let chunk = (0..len)
+                .map(|_| random_response(&random_seed(), include_proof, false, ResponseEncoding::Hex))
+                .collect::<Result<Vec<_>, VRFError>>();
+            (start, chunk)
+        });
+        start += len;
+    }
+
+    let mut chunks = Vec::new();
+    while let Some(joined) = tasks.join_next().await {
+        let (start, chunk) = joined.map_err(|e| internal_error("task_failed", e.to_string()))?;
+        chunks.push((start, chunk.map_err(vrf_error)?));
+    }
+    chunks.sort_by_key(|(start, _)| *start);
+    state.metrics.record_generated(u64::from(req.count));
+
+    Ok(Json(chunks.into_iter().flat_map(|(_, chunk)| chunk).collect()))
 }
 
 // --- New Handlers ---
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+#[serde(deny_unknown_fields)]
 pub struct PayloadRequest {
     pub seed_len: Option<usize>,
     pub salt_len: Option<usize>,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct PayloadGroup {
     pub seed: String,
     pub salt: String,
@@ -80,20 +279,38 @@ pub struct PayloadGroup {
     pub signature: String,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct PayloadResponse {
     pub hex: PayloadGroup,
     pub base64: PayloadGroup,
 }
 
-pub async fn payloads_handler(Query(params): Query<PayloadRequest>) -> Json<PayloadResponse> {
-    let seed_len = params.seed_len.unwrap_or(8);
-    let salt_len = params.salt_len.unwrap_or(8);
+#[utoipa::path(
+    get,
+    path = "/payloads",
+    params(PayloadRequest),
+    responses(
+        (status = 200, description = "Soroban testnet payload", body = PayloadResponse),
+        (status = 400, description = "Length out of bounds", body = ErrorResponse),
+        (status = 429, description = "Rate limit exceeded", body = ErrorResponse),
+        (status = 500, description = "Generation failed", body = ErrorResponse),
+    )
+)]
+pub async fn payloads_handler(
+    StrictQuery(params): StrictQuery<PayloadRequest>,
+) -> Result<Json<PayloadResponse>, ApiError> {
+    let seed_len = payload_field_len("seed_len", params.seed_len)?;
+    let salt_len = payload_field_len("salt_len", params.salt_len)?;
 
-    let payload = SamplePayload::generate(seed_len, salt_len)
-        .expect("Failed to generate sample payload");
+    let payload = blocking(move || SamplePayload::generate(seed_len, salt_len))
+        .await?
+        .map_err(|e| internal_error("payload_generation_failed", e))?;
 
-    Json(PayloadResponse {
+    Ok(Json(payload_response(&payload)))
+}
+
+fn payload_response(payload: &SamplePayload) -> PayloadResponse {
+    PayloadResponse {
         hex: PayloadGroup {
             seed: payload.seed_hex(),
             salt: payload.salt_hex(),
@@ -108,64 +325,462 @@ pub async fn payloads_handler(Query(params): Query<PayloadRequest>) -> Json<Payl
             pubkey: payload.pubkey_base64(),
             signature: payload.signature_base64(),
         },
-    })
+    }
 }
 
-#[derive(Debug, Deserialize)]
+/// POST /generate-payload
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GeneratePayloadRequest {
+    /// Seed length in bytes, 1 to 1024 (default 8).
+    pub seed_len: Option<usize>,
+    /// Salt length in bytes, 1 to 1024 (default 8).
+    pub salt_len: Option<usize>,
+}
+
+fn payload_field_len(field: &str, len: Option<usize>) -> Result<usize, ApiError> {
+    let len = len.unwrap_or(8);
+    if len == 0 || len > MAX_PAYLOAD_FIELD_LEN {
+        return Err(bad_request(
+            "invalid_length",
+            format!("Field `{}` must be between 1 and {}, got {}", field, MAX_PAYLOAD_FIELD_LEN, len),
+        ));
+    }
+    Ok(len)
+}
+
+#[utoipa::path(
+    post,
+    path = "/generate-payload",
+    request_body = GeneratePayloadRequest,
+    responses(
+        (status = 200, description = "Ready-to-submit Soroban payload", body = PayloadResponse),
+        (status = 400, description = "Length out of bounds", body = ErrorResponse),
+        (status = 429, description = "Rate limit exceeded", body = ErrorResponse),
+        (status = 500, description = "Generation failed", body = ErrorResponse),
+    )
+)]
+pub async fn generate_payload_handler(
+    StrictJson(req): StrictJson<GeneratePayloadRequest>,
+) -> Result<Json<PayloadResponse>, ApiError> {
+    let seed_len = payload_field_len("seed_len", req.seed_len)?;
+    let salt_len = payload_field_len("salt_len", req.salt_len)?;
+
+    let payload = blocking(move || SamplePayload::generate(seed_len, salt_len))
+        .await?
+        .map_err(|e| internal_error("payload_generation_failed", e))?;
+
+    Ok(Json(payload_response(&payload)))
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
 pub struct VerifyRandomRequest {
     pub seed: String,
     pub output: String,
+    pub proof: String,
     pub public_key: String,
+    #[serde(default)]
+    pub encoding: FieldEncoding,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct VerifyRandomResponse {
     pub valid: bool,
+    /// Error code explaining a failed verification, e.g. `invalid_length` or
+    /// `verification_failed`. Set by `/verify-random` and `/verify-by-keyid` when
+    /// `valid` is false.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<&'static str>,
+}
+
+/// Default cap on decoded seeds and salts, overridable with the
+/// `NEBULA_MAX_SEED_BYTES` env var. The request body limit applies as well.
+const DEFAULT_MAX_SEED_BYTES: usize = 1024 * 1024;
+
+/// Size of a VRF output in bytes.
+const OUTPUT_SIZE: usize = 32;
+
+fn max_seed_bytes() -> usize {
+    std::env::var("NEBULA_MAX_SEED_BYTES")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(DEFAULT_MAX_SEED_BYTES)
+}
+
+/// Decodes the seed, output, proof and public key of a verification request.
+///
+/// Seeds are capped by `max_seed_bytes`, and the other fields by the largest
+/// valid encoding, so no field can force a large allocation. Shorter wrong
+/// lengths still reach verification, which reports them as `invalid_length`.
+fn decode_verify_fields(req: &VerifyRandomRequest) -> Result<[Vec<u8>; 4], ApiError> {
+    Ok([
+        decode_field_max("seed", &req.seed, req.encoding, max_seed_bytes())?,
+        decode_field_max("output", &req.output, req.encoding, OUTPUT_SIZE)?,
+        decode_field_max("proof", &req.proof, req.encoding, VRF_SIGNATURE_UNCOMPRESSED_SIZE)?,
+        decode_field_max("public_key", &req.public_key, req.encoding, VRF_PUBLIC_KEY_UNCOMPRESSED_SIZE)?,
+    ])
 }
 
-pub async fn verify_random_handler(Json(req): Json<VerifyRandomRequest>) -> Json<VerifyRandomResponse> {
-    let seed = hex::decode(&req.seed).unwrap_or_default();
-    let output = hex::decode(&req.output).unwrap_or_default();
-    let public_key = hex::decode(&req.public_key).unwrap_or_default();
-    let valid = verify_proof(&seed, &output, &public_key).is_ok();
-    Json(VerifyRandomResponse { valid })
+#[utoipa::path(
+    post,
+    path = "/verify-random",
+    request_body = VerifyRandomRequest,
+    responses(
+        (status = 200, description = "Verification result", body = VerifyRandomResponse),
+        (status = 400, description = "Malformed request", body = ErrorResponse),
+        (status = 413, description = "A field exceeds its size limit", body = ErrorResponse),
+        (status = 429, description = "Rate limit exceeded", body = ErrorResponse),
+    )
+)]
+pub async fn verify_random_handler(
+    State(state): State<AppState>,
+    StrictJson(req): StrictJson<VerifyRandomRequest>,
+) -> Result<Json<VerifyRandomResponse>, ApiError> {
+    let [seed, output, proof, public_key] = decode_verify_fields(&req)?;
+    let result = blocking(move || verify_output(&seed, &output, &proof, &public_key)).await?;
+    let reason = result.as_ref().err().map(vrf_error_code);
+    state.metrics.record_verification(reason.is_none());
+    match &result {
+        Ok(()) => tracing::debug!(valid = true, "verified VRF output"),
+        Err(err) => tracing::info!(valid = false, reason, error = %err, "VRF output failed verification"),
+    }
+    Ok(Json(VerifyRandomResponse { valid: reason.is_none(), reason }))
+}
+
+/// POST /verify-by-keyid
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct VerifyByKeyIdRequest {
+    pub seed: String,
+    pub output: String,
+    pub proof: String,
+    /// ID of a key in the server's registry.
+    pub key_id: String,
+    #[serde(default)]
+    pub encoding: FieldEncoding,
+}
+
+#[utoipa::path(
+    post,
+    path = "/verify-by-keyid",
+    request_body = VerifyByKeyIdRequest,
+    responses(
+        (status = 200, description = "Verification result against the registered key", body = VerifyRandomResponse),
+        (status = 400, description = "Malformed request", body = ErrorResponse),
+        (status = 404, description = "Unknown key ID", body = ErrorResponse),
+        (status = 413, description = "A field exceeds its size limit", body = ErrorResponse),
+        (status = 429, description = "Rate limit exceeded", body = ErrorResponse),
+    )
+)]
+pub async fn verify_by_key_id_handler(
+    State(state): State<AppState>,
+    StrictJson(req): StrictJson<VerifyByKeyIdRequest>,
+) -> Result<Json<VerifyRandomResponse>, ApiError> {
+    let verifier = state.keys.get(&req.key_id).ok_or_else(|| {
+        not_found("unknown_key", format!("No key is registered with ID `{}`", req.key_id))
+    })?;
+    let seed = decode_field_max("seed", &req.seed, req.encoding, max_seed_bytes())?;
+    let output = decode_field_max("output", &req.output, req.encoding, OUTPUT_SIZE)?;
+    let proof = decode_field_max("proof", &req.proof, req.encoding, VRF_SIGNATURE_UNCOMPRESSED_SIZE)?;
+
+    let result = blocking(move || {
+        verifier.verify(&seed, &proof)?;
+        if proof_to_hash(&proof)?.as_slice() == output {
+            Ok(())
+        } else {
+            Err(VRFError::VerificationFailed)
+        }
+    })
+    .await?;
+    let reason = result.as_ref().err().map(vrf_error_code);
+    state.metrics.record_verification(reason.is_none());
+    tracing::debug!(key_id = %req.key_id, valid = reason.is_none(), reason, "verified VRF output by key ID");
+    Ok(Json(VerifyRandomResponse { valid: reason.is_none(), reason }))
 }
 
-#[derive(Debug, Deserialize)]
+/// Default cap on `/verify-random-batch` entries, overridable with the
+/// `NEBULA_MAX_VERIFY_BATCH_LEN` env var. About 100 hex entries fit in the
+/// default 64 KiB body limit.
+const DEFAULT_MAX_VERIFY_BATCH_LEN: usize = 100;
+
+fn max_verify_batch_len() -> usize {
+    std::env::var("NEBULA_MAX_VERIFY_BATCH_LEN")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(DEFAULT_MAX_VERIFY_BATCH_LEN)
+}
+
+#[utoipa::path(
+    post,
+    path = "/verify-random-batch",
+    request_body = [VerifyRandomRequest],
+    responses(
+        (status = 200, description = "One verification result per entry, in request order", body = [VerifyRandomResponse]),
+        (status = 400, description = "Too many entries", body = ErrorResponse),
+        (status = 429, description = "Rate limit exceeded", body = ErrorResponse),
+    )
+)]
+pub async fn verify_random_batch_handler(
+    State(state): State<AppState>,
+    StrictJson(entries): StrictJson<Vec<VerifyRandomRequest>>,
+) -> Result<Json<Vec<VerifyRandomResponse>>, ApiError> {
+    let max = max_verify_batch_len();
+    if entries.len() > max {
+        return Err(bad_request(
+            "batch_too_large",
+            format!("At most {} entries are accepted, got {}", max, entries.len()),
+        ));
+    }
+
+    let results = blocking(move || verify_entries(&entries)).await??;
+    for valid in &results {
+        state.metrics.record_verification(*valid);
+    }
+    tracing::debug!(count = results.len(), "verified VRF output batch");
+    Ok(Json(results.into_iter().map(|valid| VerifyRandomResponse { valid, reason: None }).collect()))
+}
+
+/// Batch-verifies the proofs and checks each claimed output. Entries that fail
+/// to decode are reported as invalid instead of failing the whole batch.
+fn verify_entries(entries: &[VerifyRandomRequest]) -> Result<Vec<bool>, ApiError> {
+    let decoded: Vec<Option<[Vec<u8>; 4]>> = entries
+        .iter()
+        .map(|e| decode_verify_fields(e).ok())
+        .collect();
+
+    let indices: Vec<usize> = (0..decoded.len()).filter(|&i| decoded[i].is_some()).collect();
+    let items: Vec<(&[u8], &[u8], &[u8])> = decoded
+        .iter()
+        .flatten()
+        .map(|[seed, _, proof, public_key]| (seed.as_slice(), proof.as_slice(), public_key.as_slice()))
+        .collect();
+    let proofs_valid = verify_proofs_batch(&items).map_err(vrf_error)?;
+
+    let mut results = vec![false; entries.len()];
+    for (index, proof_valid) in indices.into_iter().zip(proofs_valid) {
+        let [_, output, proof, _] = decoded[index].as_ref().expect("index of a decoded entry");
+        results[index] = proof_valid && proof_to_hash(proof).is_ok_and(|hash| hash.as_slice() == output);
+    }
+    Ok(results)
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
 pub struct CommitRequest {
     pub seed: String,
+    pub salt: Option<String>,
+    #[serde(default)]
+    pub encoding: FieldEncoding,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct CommitResponse {
     pub commitment: String,
 }
 
-pub async fn commit_handler(Json(req): Json<CommitRequest>) -> Json<CommitResponse> {
-    let seed = hex::decode(&req.seed).unwrap_or_default();
-    let commitment = commit(&seed);
-    Json(CommitResponse { commitment: hex::encode(commitment) })
+#[utoipa::path(
+    post,
+    path = "/commit",
+    request_body = CommitRequest,
+    responses(
+        (status = 200, description = "Commitment to the seed", body = CommitResponse),
+        (status = 400, description = "Malformed request", body = ErrorResponse),
+        (status = 413, description = "A field exceeds its size limit", body = ErrorResponse),
+        (status = 429, description = "Rate limit exceeded", body = ErrorResponse),
+    )
+)]
+pub async fn commit_handler(
+    StrictJson(req): StrictJson<CommitRequest>,
+) -> Result<Json<CommitResponse>, ApiError> {
+    let max = max_seed_bytes();
+    let seed = decode_field_max("seed", &req.seed, req.encoding, max)?;
+    let commitment = match &req.salt {
+        Some(salt) => commit_with_salt(&seed, &decode_field_max("salt", salt, req.encoding, max)?),
+        None => commit(&seed),
+    };
+    Ok(Json(CommitResponse { commitment: hex::encode(commitment) }))
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
 pub struct VerifyCommitRequest {
     pub seed: String,
+    pub salt: Option<String>,
     pub commitment: String,
+    #[serde(default)]
+    pub encoding: FieldEncoding,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct VerifyCommitResponse {
     pub valid: bool,
 }
 
-pub async fn verify_commit_handler(Json(req): Json<VerifyCommitRequest>) -> Json<VerifyCommitResponse> {
-    let seed = hex::decode(&req.seed).unwrap_or_default();
-    let mut commitment_bytes = [0u8; 32];
-    if let Ok(bytes) = hex::decode(&req.commitment) {
-        if bytes.len() == 32 {
-            commitment_bytes.copy_from_slice(&bytes);
+#[utoipa::path(
+    post,
+    path = "/verify-commit",
+    request_body = VerifyCommitRequest,
+    responses(
+        (status = 200, description = "Verification result", body = VerifyCommitResponse),
+        (status = 400, description = "Malformed request", body = ErrorResponse),
+        (status = 413, description = "A field exceeds its size limit", body = ErrorResponse),
+        (status = 429, description = "Rate limit exceeded", body = ErrorResponse),
+    )
+)]
+pub async fn verify_commit_handler(
+    State(state): State<AppState>,
+    StrictJson(req): StrictJson<VerifyCommitRequest>,
+) -> Result<Json<VerifyCommitResponse>, ApiError> {
+    let max = max_seed_bytes();
+    let seed = decode_field_max("seed", &req.seed, req.encoding, max)?;
+    let commitment_bytes = decode_field_array::<32>("commitment", &req.commitment, req.encoding)?;
+    let valid = match &req.salt {
+        Some(salt) => {
+            let salt = decode_field_max("salt", salt, req.encoding, max)?;
+            verify_commit_with_salt(&seed, &salt, &commitment_bytes)
         }
-    }
-    let valid = verify_commit(&seed, &commitment_bytes);
-    Json(VerifyCommitResponse { valid })
+        None => verify_commit(&seed, &commitment_bytes),
+    };
+    state.metrics.record_verification(valid);
+    tracing::debug!(valid, "verified commitment");
+    Ok(Json(VerifyCommitResponse { valid }))
+}
+
+/// POST /verify-reveal
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct VerifyRevealRequest {
+    /// `sha256(seed || salt)`, published before the reveal.
+    pub commitment: String,
+    pub seed: String,
+    pub salt: String,
+    pub output: String,
+    pub proof: String,
+    pub public_key: String,
+    #[serde(default)]
+    pub encoding: FieldEncoding,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct VerifyRevealResponse {
+    /// The seed and salt open the commitment.
+    pub commit_valid: bool,
+    /// The proof and output are valid for the seed under the public key.
+    pub proof_valid: bool,
+    /// Both checks passed.
+    pub overall_valid: bool,
+    /// Error code explaining why `proof_valid` is false.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<&'static str>,
+}
+
+/// Checks a commit-reveal opening and the VRF proof over the revealed seed in
+/// one call. Both checks always run, so each result is reported on its own.
+#[utoipa::path(
+    post,
+    path = "/verify-reveal",
+    request_body = VerifyRevealRequest,
+    responses(
+        (status = 200, description = "Verification result for each check", body = VerifyRevealResponse),
+        (status = 400, description = "Malformed request", body = ErrorResponse),
+        (status = 413, description = "A field exceeds its size limit", body = ErrorResponse),
+        (status = 429, description = "Rate limit exceeded", body = ErrorResponse),
+    )
+)]
+pub async fn verify_reveal_handler(
+    State(state): State<AppState>,
+    StrictJson(req): StrictJson<VerifyRevealRequest>,
+) -> Result<Json<VerifyRevealResponse>, ApiError> {
+    let max = max_seed_bytes();
+    let commitment = decode_field_array::<32>("commitment", &req.commitment, req.encoding)?;
+    let seed = decode_field_max("seed", &req.seed, req.encoding, max)?;
+    let salt = decode_field_max("salt", &req.salt, req.encoding, max)?;
+    let output = decode_field_max("output", &req.output, req.encoding, OUTPUT_SIZE)?;
+    let proof = decode_field_max("proof", &req.proof, req.encoding, VRF_SIGNATURE_UNCOMPRESSED_SIZE)?;
+    let public_key =
+        decode_field_max("public_key", &req.public_key, req.encoding, VRF_PUBLIC_KEY_UNCOMPRESSED_SIZE)?;
+
+    let commit_valid = verify_commit_with_salt(&seed, &salt, &commitment);
+    let result = blocking(move || verify_output(&seed, &output, &proof, &public_key)).await?;
+    let reason = result.as_ref().err().map(vrf_error_code);
+    let proof_valid = reason.is_none();
+    let overall_valid = commit_valid && proof_valid;
+    state.metrics.record_verification(overall_valid);
+    tracing::debug!(commit_valid, proof_valid, reason, "verified reveal");
+    Ok(Json(VerifyRevealResponse { commit_valid, proof_valid, overall_valid, reason }))
+}
+
+/// POST /derive
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct DeriveRequest {
+    /// The 32-byte VRF output to derive from.
+    pub parent_output: String,
+    /// The sub-use the child is for, e.g. `shuffle`.
+    pub label: String,
+    #[serde(default)]
+    pub encoding: FieldEncoding,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct DeriveResponse {
+    /// Hex-encoded 32-byte child value.
+    pub child: String,
+}
+
+/// Derives a labelled child value from a VRF output with HKDF-SHA256.
+///
+/// This is a key derivation, not a new VRF proof: the parent proof must be
+/// verified separately, and anyone with the parent output can derive the child.
+#[utoipa::path(
+    post,
+    path = "/derive",
+    request_body = DeriveRequest,
+    responses(
+        (status = 200, description = "Child value for the label", body = DeriveResponse),
+        (status = 400, description = "Malformed request", body = ErrorResponse),
+        (status = 413, description = "A field exceeds its size limit", body = ErrorResponse),
+        (status = 429, description = "Rate limit exceeded", body = ErrorResponse),
+    )
+)]
+pub async fn derive_handler(
+    StrictJson(req): StrictJson<DeriveRequest>,
+) -> Result<Json<DeriveResponse>, ApiError> {
+    let parent_output = decode_field_array::<OUTPUT_SIZE>("parent_output", &req.parent_output, req.encoding)?;
+    let label = decode_field_max("label", &req.label, req.encoding, max_seed_bytes())?;
+    let child = derive_child_from_output(&parent_output, &label);
+    Ok(Json(DeriveResponse { child: hex::encode(child) }))
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct HealthResponse {
+    pub status: &'static str,
+    pub version: &'static str,
+}
+
+/// GET /health
+#[utoipa::path(
+    get,
+    path = "/health",
+    responses((status = 200, description = "Service is up", body = HealthResponse))
+)]
+pub async fn health_handler() -> Json<HealthResponse> {
+    Json(HealthResponse {
+        status: "ok",
+        version: env!("CARGO_PKG_VERSION"),
+    })
+}
+
+/// GET /metrics
+#[utoipa::path(
+    get,
+    path = "/metrics",
+    responses((status = 200, description = "Prometheus text exposition", content_type = "text/plain", body = String))
+)]
+pub async fn metrics_handler(State(state): State<AppState>) -> impl IntoResponse {
+    (
+        [(header::CONTENT_TYPE, "text/plain; version=0.0.4")],
+        state.metrics.render(),
+    )
 }
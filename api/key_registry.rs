@@ -0,0 +1,64 @@
+// Trusted public keys for the NebulaVRF API, looked up by key ID.
+
+use nebula_vrf::vrf::Verifier;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Public keys the server trusts, keyed by an operator-chosen ID.
+///
+/// `/verify-random` checks a proof against whatever key the client sends, so a
+/// client can prove anything with a key of its own. `/verify-by-keyid` looks the
+/// key up here instead. Keys are parsed and subgroup-checked once, at startup.
+#[derive(Default)]
+pub struct KeyRegistry {
+    keys: HashMap<String, Arc<Verifier>>,
+}
+
+impl KeyRegistry {
+    /// Loads keys from the environment, falling back to an empty registry.
+    ///
+    /// `NEBULA_KEY_REGISTRY_FILE` names a JSON file holding an object of
+    /// `"key_id": "<hex public key>"` entries. `NEBULA_KEYS` adds
+    /// comma-separated `key_id=<hex public key>` pairs on top. Unlike
+    /// `ApiConfig::from_env`, invalid entries are an error: silently dropping a
+    /// trusted key would turn valid proofs into 404s.
+    pub fn from_env() -> Result<Self, String> {
+        let mut registry = KeyRegistry::default();
+        if let Ok(path) = std::env::var("NEBULA_KEY_REGISTRY_FILE") {
+            let contents = std::fs::read_to_string(&path)
+                .map_err(|e| format!("cannot read key registry `{}`: {}", path, e))?;
+            let entries: HashMap<String, String> = serde_json::from_str(&contents)
+                .map_err(|e| format!("key registry `{}` is not a JSON object of hex keys: {}", path, e))?;
+            for (key_id, key_hex) in entries {
+                registry.insert_hex(&key_id, &key_hex)?;
+            }
+        }
+        if let Ok(pairs) = std::env::var("NEBULA_KEYS") {
+            for pair in pairs.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+                let (key_id, key_hex) = pair
+                    .split_once('=')
+                    .ok_or_else(|| format!("NEBULA_KEYS entry `{}` is not `key_id=<hex>`", pair))?;
+                registry.insert_hex(key_id.trim(), key_hex.trim())?;
+            }
+        }
+        Ok(registry)
+    }
+
+    /// Registers a hex-encoded public key under `key_id`, replacing any earlier key.
+    pub fn insert_hex(&mut self, key_id: &str, key_hex: &str) -> Result<(), String> {
+        let key = hex::decode(key_hex).map_err(|e| format!("key `{}` is not valid hex: {}", key_id, e))?;
+        let verifier = Verifier::new(&key).map_err(|e| format!("key `{}` is invalid: {}", key_id, e))?;
+        self.keys.insert(key_id.to_string(), Arc::new(verifier));
+        Ok(())
+    }
+
+    /// Returns the verifier for `key_id`, if registered.
+    pub fn get(&self, key_id: &str) -> Option<Arc<Verifier>> {
+        self.keys.get(key_id).cloned()
+    }
+
+    /// Returns the number of registered keys.
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+}
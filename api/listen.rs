@@ -0,0 +1,153 @@
+// Listening sockets for the NebulaVRF API: TCP over IPv4 or IPv6, and Unix domain sockets.
+
+use std::fmt;
+use std::future::Future;
+use std::io;
+use std::net::SocketAddr;
+#[cfg(unix)]
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use axum::Router;
+use tokio::net::TcpListener;
+#[cfg(unix)]
+use tokio::net::{UnixListener, UnixStream};
+
+/// Where the REST API listens.
+///
+/// Parsed from `--addr`/`NEBULA_BIND_ADDR`: a socket address such as
+/// `127.0.0.1:3000` or `[::]:3000`, or `unix:<path>` for a Unix domain socket.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Listen {
+    /// A TCP address. `[::]:3000` also accepts IPv4 clients on systems where
+    /// IPv6 sockets are dual-stack by default, such as Linux.
+    Tcp(SocketAddr),
+    /// A Unix domain socket path, for sidecars on the same host.
+    #[cfg(unix)]
+    Unix(PathBuf),
+}
+
+impl FromStr for Listen {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, String> {
+        if let Some(path) = value.strip_prefix("unix:") {
+            #[cfg(unix)]
+            return match path {
+                "" => Err("`unix:` needs a socket path, e.g. unix:/run/nebula-vrf.sock".to_string()),
+                path => Ok(Listen::Unix(PathBuf::from(path))),
+            };
+            #[cfg(not(unix))]
+            return Err(format!("Unix sockets are not supported on this platform: `{}`", path));
+        }
+        value.parse().map(Listen::Tcp).map_err(|e| {
+            format!("{} (expected e.g. 127.0.0.1:3000, [::]:3000 or unix:/run/nebula-vrf.sock)", e)
+        })
+    }
+}
+
+impl fmt::Display for Listen {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Listen::Tcp(addr) => write!(f, "{}", addr),
+            #[cfg(unix)]
+            Listen::Unix(path) => write!(f, "unix:{}", path.display()),
+        }
+    }
+}
+
+/// Binds `listen` and serves `app` until `shutdown` completes, then waits for
+/// in-flight requests to finish.
+///
+/// A Unix socket file is removed again once the server stops.
+pub async fn serve(
+    listen: &Listen,
+    app: Router,
+    shutdown: impl Future<Output = ()> + Send + 'static,
+) -> io::Result<()> {
+    match listen {
+        Listen::Tcp(addr) => {
+            let listener = TcpListener::bind(addr).await?;
+            tracing::info!("🚀 Running NebulaVRF API on http://{}", listener.local_addr()?);
+            axum::serve(listener, app.into_make_service_with_connect_info::<SocketAddr>())
+                .with_graceful_shutdown(shutdown)
+                .await
+        }
+        #[cfg(unix)]
+        Listen::Unix(path) => {
+            let listener = bind_unix(path).await?;
+            tracing::info!("🚀 Running NebulaVRF API on {}", listen);
+            serve_unix(listener, app, shutdown).await;
+            let _ = std::fs::remove_file(path);
+            Ok(())
+        }
+    }
+}
+
+/// Binds a Unix socket at `path`, first removing a socket file left behind by
+/// a server that did not shut down cleanly.
+///
+/// A socket that still accepts connections belongs to a running server and is
+/// left alone, as is any path that is not a socket.
+#[cfg(unix)]
+pub async fn bind_unix(path: &Path) -> io::Result<UnixListener> {
+    use std::os::unix::fs::FileTypeExt;
+
+    match std::fs::symlink_metadata(path) {
+        Ok(meta) if meta.file_type().is_socket() => {
+            if UnixStream::connect(path).await.is_ok() {
+                return Err(io::Error::new(
+                    io::ErrorKind::AddrInUse,
+                    format!("another server is listening on {}", path.display()),
+                ));
+            }
+            tracing::info!(path = %path.display(), "removing stale socket");
+            std::fs::remove_file(path)?;
+        }
+        Ok(_) => {
+            return Err(io::Error::new(
+                io::ErrorKind::AlreadyExists,
+                format!("{} exists and is not a socket", path.display()),
+            ));
+        }
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+        Err(e) => return Err(e),
+    }
+    UnixListener::bind(path)
+}
+
+/// Serves HTTP/1.1 connections from a Unix listener.
+///
+/// Unix clients have no IP address, so they all share the rate limiter's
+/// fallback bucket.
+#[cfg(unix)]
+pub async fn serve_unix(listener: UnixListener, app: Router, shutdown: impl Future<Output = ()>) {
+    use hyper::server::conn::http1;
+    use hyper_util::rt::TokioIo;
+    use hyper_util::server::graceful::GracefulShutdown;
+    use hyper_util::service::TowerToHyperService;
+
+    let graceful = GracefulShutdown::new();
+    tokio::pin!(shutdown);
+    loop {
+        let stream = tokio::select! {
+            accepted = listener.accept() => match accepted {
+                Ok((stream, _)) => stream,
+                Err(e) => {
+                    tracing::warn!(error = %e, "failed to accept Unix connection");
+                    continue;
+                }
+            },
+            _ = &mut shutdown => break,
+        };
+        let connection = http1::Builder::new()
+            .serve_connection(TokioIo::new(stream), TowerToHyperService::new(app.clone()));
+        let connection = graceful.watch(connection);
+        tokio::spawn(async move {
+            if let Err(e) = connection.await {
+                tracing::debug!(error = %e, "Unix connection closed with an error");
+            }
+        });
+    }
+    graceful.shutdown().await;
+}
@@ -1,17 +1,159 @@
+#[cfg(feature = "grpc")]
 use tokio::net::TcpListener;
-use tracing_subscriber;
+use tracing_subscriber::fmt::format::FmtSpan;
+use tracing_subscriber::EnvFilter;
 
+mod config;
+#[cfg(feature = "grpc")]
+mod grpc;
 mod handlers;
+mod key_registry;
+mod listen;
+mod openapi;
+mod rate_limit;
 mod routes;
+mod state;
+#[cfg(test)]
+mod tests;
+use config::ApiConfig;
+use key_registry::KeyRegistry;
+use listen::Listen;
 use routes::create_routes;
+use state::AppState;
+
+/// Address the REST API binds when neither `--addr` nor `NEBULA_BIND_ADDR` is set.
+const DEFAULT_BIND_ADDR: &str = "0.0.0.0:3000";
 
 #[tokio::main]
 async fn main() {
-    tracing_subscriber::fmt::init();
+    let listen = match resolve_bind_addr(std::env::args().skip(1), std::env::var("NEBULA_BIND_ADDR").ok()) {
+        Ok(listen) => listen,
+        Err(e) => {
+            eprintln!("nebula_vrf_api: {}", e);
+            std::process::exit(2);
+        }
+    };
+
+    // RUST_LOG=nebula_vrf=debug adds per-operation spans; span timings are logged on close.
+    tracing_subscriber::fmt()
+        .with_env_filter(
+            EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info")),
+        )
+        .with_span_events(FmtSpan::CLOSE)
+        .init();
+
+    let keys = match KeyRegistry::from_env() {
+        Ok(keys) => keys,
+        Err(e) => {
+            eprintln!("nebula_vrf_api: {}", e);
+            std::process::exit(2);
+        }
+    };
+    tracing::info!(keys = keys.len(), "loaded key registry");
+    let state = AppState::with_keys(keys);
+
+    // Bind before serving REST, so a taken gRPC port stops startup instead of
+    // leaving the REST API running alone.
+    #[cfg(feature = "grpc")]
+    {
+        let listener = match bind_grpc().await {
+            Ok(listener) => listener,
+            Err(e) => {
+                eprintln!("nebula_vrf_api: {}", e);
+                std::process::exit(1);
+            }
+        };
+        let state = state.clone();
+        tokio::spawn(async move {
+            if let Err(e) = serve_grpc(listener, state).await {
+                eprintln!("nebula_vrf_api: {}", e);
+                std::process::exit(1);
+            }
+        });
+    }
+
+    let app = create_routes(ApiConfig::from_env(), state);
+    if let Err(e) = listen::serve(&listen, app, shutdown_signal()).await {
+        eprintln!("nebula_vrf_api: failed to serve on {}: {}", listen, e);
+        std::process::exit(1);
+    }
+}
+
+/// Completes on SIGINT or SIGTERM. The servers then stop accepting connections
+/// and wait for in-flight requests to finish.
+async fn shutdown_signal() {
+    let ctrl_c = async {
+        tokio::signal::ctrl_c().await.expect("failed to install Ctrl+C handler");
+    };
+
+    #[cfg(unix)]
+    let terminate = async {
+        tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate())
+            .expect("failed to install SIGTERM handler")
+            .recv()
+            .await;
+    };
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        _ = ctrl_c => {},
+        _ = terminate => {},
+    }
+    tracing::info!("shutting down");
+}
+
+/// Picks the REST bind address: `--addr <addr>` (or `--addr=<addr>`) wins over
+/// `NEBULA_BIND_ADDR`, which wins over `DEFAULT_BIND_ADDR`. A `unix:<path>`
+/// value listens on a Unix domain socket instead of TCP.
+fn resolve_bind_addr(
+    mut args: impl Iterator<Item = String>,
+    env: Option<String>,
+) -> Result<Listen, String> {
+    let mut from_args = None;
+    while let Some(arg) = args.next() {
+        if arg == "--addr" {
+            from_args = Some(args.next().ok_or("`--addr` needs a value, e.g. --addr 127.0.0.1:3000")?);
+        } else if let Some(value) = arg.strip_prefix("--addr=") {
+            from_args = Some(value.to_string());
+        } else {
+            return Err(format!("unknown argument `{}`", arg));
+        }
+    }
+
+    let (source, value) = match (from_args, env) {
+        (Some(value), _) => ("--addr", value),
+        (None, Some(value)) => ("NEBULA_BIND_ADDR", value),
+        (None, None) => ("default", DEFAULT_BIND_ADDR.to_string()),
+    };
+    value
+        .parse()
+        .map_err(|e| format!("invalid bind address `{}` from {}: {}", value, source, e))
+}
+
+/// Binds the gRPC listener on `NEBULA_GRPC_ADDR` (default `0.0.0.0:50051`).
+#[cfg(feature = "grpc")]
+async fn bind_grpc() -> Result<TcpListener, String> {
+    let addr = std::env::var("NEBULA_GRPC_ADDR").unwrap_or_else(|_| "0.0.0.0:50051".to_string());
+    TcpListener::bind(&addr)
+        .await
+        .map_err(|e| format!("failed to bind gRPC on {}: {}", addr, e))
+}
 
-    let app = create_routes();
-    let listener = TcpListener::bind("0.0.0.0:3000").await.unwrap();
-    tracing::info!("🚀 Running NebulaVRF API on http://{}", listener.local_addr().unwrap());
+/// Serves the gRPC API on `listener` until shutdown.
+#[cfg(feature = "grpc")]
+async fn serve_grpc(listener: TcpListener, state: AppState) -> Result<(), String> {
+    let addr = listener
+        .local_addr()
+        .map_err(|e| format!("failed to read gRPC listener address: {}", e))?;
+    tracing::info!("🚀 Running NebulaVRF gRPC on {}", addr);
 
-    axum::serve(listener, app.into_make_service()).await.unwrap();
+    tonic::transport::Server::builder()
+        .add_service(grpc::VrfService::new(state).into_server())
+        .serve_with_incoming_shutdown(
+            tokio_stream::wrappers::TcpListenerStream::new(listener),
+            shutdown_signal(),
+        )
+        .await
+        .map_err(|e| format!("failed to serve gRPC on {}: {}", addr, e))
 }
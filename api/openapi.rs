@@ -0,0 +1,54 @@
+// OpenAPI document for the NebulaVRF API, served at /openapi.json.
+
+use axum::Json;
+use utoipa::OpenApi;
+
+use super::handlers::{self, ErrorResponse, FieldEncoding};
+
+#[derive(OpenApi)]
+#[openapi(
+    info(title = "NebulaVRF API", description = "Verifiable randomness, commit-reveal, and Soroban payloads."),
+    paths(
+        handlers::get_random_handler,
+        handlers::get_random_batch_handler,
+        handlers::payloads_handler,
+        handlers::generate_payload_handler,
+        handlers::verify_random_handler,
+        handlers::verify_random_batch_handler,
+        handlers::verify_by_key_id_handler,
+        handlers::commit_handler,
+        handlers::verify_commit_handler,
+        handlers::verify_reveal_handler,
+        handlers::derive_handler,
+        handlers::health_handler,
+        handlers::metrics_handler,
+    ),
+    components(schemas(
+        handlers::RandomResponse,
+        handlers::ResponseEncoding,
+        handlers::RandomBatchRequest,
+        handlers::PayloadGroup,
+        handlers::PayloadResponse,
+        handlers::GeneratePayloadRequest,
+        handlers::VerifyRandomRequest,
+        handlers::VerifyRandomResponse,
+        handlers::VerifyByKeyIdRequest,
+        handlers::CommitRequest,
+        handlers::CommitResponse,
+        handlers::VerifyCommitRequest,
+        handlers::VerifyCommitResponse,
+        handlers::VerifyRevealRequest,
+        handlers::VerifyRevealResponse,
+        handlers::DeriveRequest,
+        handlers::DeriveResponse,
+        handlers::HealthResponse,
+        ErrorResponse,
+        FieldEncoding,
+    ))
+)]
+pub struct ApiDoc;
+
+/// GET /openapi.json
+pub async fn openapi_handler() -> Json<utoipa::openapi::OpenApi> {
+    Json(ApiDoc::openapi())
+}
@@ -0,0 +1,122 @@
+// Per-client token-bucket rate limiting for the NebulaVRF API.
+
+use axum::{
+    extract::{ConnectInfo, Request, State},
+    http::header::RETRY_AFTER,
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use std::collections::HashMap;
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+
+use super::handlers::too_many_requests;
+
+/// Default sustained rate, overridable with the `NEBULA_RATE_LIMIT_RPS` env var.
+const DEFAULT_REQUESTS_PER_SECOND: f64 = 20.0;
+
+/// Default burst size, overridable with the `NEBULA_RATE_LIMIT_BURST` env var.
+const DEFAULT_BURST: u32 = 40;
+
+/// Number of tracked clients above which idle buckets are evicted.
+const PRUNE_THRESHOLD: usize = 10_000;
+
+/// Token-bucket parameters applied to every client.
+#[derive(Debug, Clone, Copy)]
+pub struct RateLimitConfig {
+    /// Tokens refilled per second. Zero disables rate limiting.
+    pub requests_per_second: f64,
+    /// Bucket capacity: requests a client may make back to back.
+    pub burst: u32,
+}
+
+impl Default for RateLimitConfig {
+    fn default() -> Self {
+        RateLimitConfig { requests_per_second: DEFAULT_REQUESTS_PER_SECOND, burst: DEFAULT_BURST }
+    }
+}
+
+impl RateLimitConfig {
+    /// Reads the configuration from the environment, falling back to defaults.
+    pub fn from_env() -> Self {
+        RateLimitConfig {
+            requests_per_second: std::env::var("NEBULA_RATE_LIMIT_RPS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .filter(|rps: &f64| rps.is_finite() && *rps >= 0.0)
+                .unwrap_or(DEFAULT_REQUESTS_PER_SECOND),
+            burst: std::env::var("NEBULA_RATE_LIMIT_BURST")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(DEFAULT_BURST),
+        }
+    }
+}
+
+struct Bucket {
+    tokens: f64,
+    updated: Instant,
+}
+
+/// Shared per-IP token buckets.
+#[derive(Clone)]
+pub struct RateLimiter {
+    config: RateLimitConfig,
+    buckets: Arc<Mutex<HashMap<IpAddr, Bucket>>>,
+}
+
+impl RateLimiter {
+    pub fn new(config: RateLimitConfig) -> Self {
+        RateLimiter { config, buckets: Arc::default() }
+    }
+
+    /// Takes a token for `client`, or returns the seconds until one is available.
+    fn check(&self, client: IpAddr) -> Result<(), u64> {
+        let RateLimitConfig { requests_per_second: rate, burst } = self.config;
+        if rate == 0.0 {
+            return Ok(());
+        }
+        let capacity = f64::from(burst.max(1));
+        let now = Instant::now();
+
+        let mut buckets = self.buckets.lock().unwrap_or_else(|e| e.into_inner());
+        if buckets.len() >= PRUNE_THRESHOLD {
+            buckets.retain(|_, b| b.tokens + now.duration_since(b.updated).as_secs_f64() * rate < capacity);
+        }
+
+        let bucket = buckets
+            .entry(client)
+            .or_insert(Bucket { tokens: capacity, updated: now });
+        let elapsed = now.duration_since(bucket.updated).as_secs_f64();
+        bucket.tokens = (bucket.tokens + elapsed * rate).min(capacity);
+        bucket.updated = now;
+
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            Ok(())
+        } else {
+            Err(((1.0 - bucket.tokens) / rate).ceil().max(1.0) as u64)
+        }
+    }
+}
+
+/// Middleware rejecting requests over the client's budget with `429` and `Retry-After`.
+///
+/// Clients are keyed by peer IP. Requests without connection info (e.g. when the
+/// router is served without `into_make_service_with_connect_info`) share one bucket.
+pub async fn rate_limit(State(limiter): State<RateLimiter>, request: Request, next: Next) -> Response {
+    let client = request
+        .extensions()
+        .get::<ConnectInfo<SocketAddr>>()
+        .map_or(IpAddr::V4(Ipv4Addr::UNSPECIFIED), |ConnectInfo(addr)| addr.ip());
+
+    match limiter.check(client) {
+        Ok(()) => next.run(request).await,
+        Err(retry_after) => (
+            [(RETRY_AFTER, retry_after.to_string())],
+            too_many_requests(format!("Rate limit exceeded, retry after {} seconds", retry_after)),
+        )
+            .into_response(),
+    }
+}
@@ -2,19 +2,81 @@
 
 use super::handlers::{
     get_random_handler,
+    get_random_batch_handler,
     verify_random_handler,
+    verify_random_batch_handler,
+    verify_by_key_id_handler,
     commit_handler,
     verify_commit_handler,
+    verify_reveal_handler,
+    derive_handler,
     payloads_handler,
+    generate_payload_handler,
+    health_handler,
+    metrics_handler,
 };
-use axum::{Router, routing::{get, post}};
+use super::config::ApiConfig;
+use super::openapi::openapi_handler;
+use super::rate_limit::{rate_limit, RateLimiter};
+use super::state::AppState;
+use axum::{
+    extract::{DefaultBodyLimit, Request},
+    http::{header, Method, StatusCode},
+    middleware::{self, Next},
+    response::Response,
+    routing::{get, post},
+    Router,
+};
+use tower_http::cors::{AllowOrigin, CorsLayer};
+use tower_http::limit::RequestBodyLimitLayer;
+use tower_http::timeout::TimeoutLayer;
+use tracing::{field, Instrument};
+
+/// Creates all API routes for NebulaVRF with the CORS policy, body limit,
+/// timeout, and rate limit from `config`.
+///
+/// `/health`, `/metrics`, and `/openapi.json` are exempt from rate limiting so
+/// probes and scrapers are never throttled.
+pub fn create_routes(config: ApiConfig, state: AppState) -> Router {
+    let cors = CorsLayer::new()
+        .allow_origin(AllowOrigin::list(config.allowed_origins))
+        .allow_methods([Method::GET, Method::POST])
+        .allow_headers([header::CONTENT_TYPE]);
 
-/// Creates all API routes for NebulaVRF.
-pub fn create_routes() -> Router {
     Router::new()
         .route("/get-random", get(get_random_handler))
+        .route("/get-random-batch", post(get_random_batch_handler))
         .route("/payloads", get(payloads_handler))
+        .route("/generate-payload", post(generate_payload_handler))
         .route("/verify-random", post(verify_random_handler))
+        .route("/verify-random-batch", post(verify_random_batch_handler))
+        .route("/verify-by-keyid", post(verify_by_key_id_handler))
         .route("/commit", post(commit_handler))
         .route("/verify-commit", post(verify_commit_handler))
+        .route("/verify-reveal", post(verify_reveal_handler))
+        .route("/derive", post(derive_handler))
+        .route_layer(middleware::from_fn_with_state(RateLimiter::new(config.rate_limit), rate_limit))
+        .route("/health", get(health_handler))
+        .route("/metrics", get(metrics_handler))
+        .route("/openapi.json", get(openapi_handler))
+        // Replace axum's fixed 2 MB extractor limit with the configured one.
+        .layer(DefaultBodyLimit::disable())
+        .layer(RequestBodyLimitLayer::new(config.max_body_bytes))
+        .layer(TimeoutLayer::with_status_code(StatusCode::REQUEST_TIMEOUT, config.request_timeout))
+        .layer(middleware::from_fn(trace_request))
+        .layer(cors)
+        .with_state(state)
+}
+
+/// Wraps each request in an info span recording its route and response status.
+async fn trace_request(request: Request, next: Next) -> Response {
+    let span = tracing::info_span!(
+        "request",
+        method = %request.method(),
+        path = request.uri().path(),
+        status = field::Empty,
+    );
+    let response = next.run(request).instrument(span.clone()).await;
+    span.record("status", response.status().as_u16());
+    response
 }
@@ -0,0 +1,74 @@
+// Shared application state for the NebulaVRF API.
+
+use std::fmt::Write;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
+use super::key_registry::KeyRegistry;
+
+/// State shared by all handlers via axum's `with_state`.
+#[derive(Clone, Default)]
+pub struct AppState {
+    pub metrics: Arc<Metrics>,
+    /// Trusted keys for `/verify-by-keyid`.
+    pub keys: Arc<KeyRegistry>,
+}
+
+impl AppState {
+    /// Creates state that verifies `/verify-by-keyid` requests against `keys`.
+    pub fn with_keys(keys: KeyRegistry) -> Self {
+        AppState { keys: Arc::new(keys), ..AppState::default() }
+    }
+}
+
+/// Service counters exposed at `/metrics`.
+#[derive(Default)]
+pub struct Metrics {
+    randomness_generated: AtomicU64,
+    verifications: AtomicU64,
+    verification_failures: AtomicU64,
+}
+
+impl Metrics {
+    /// Records `count` random values handed out to clients.
+    pub fn record_generated(&self, count: u64) {
+        self.randomness_generated.fetch_add(count, Ordering::Relaxed);
+    }
+
+    /// Records one verification and whether it failed.
+    pub fn record_verification(&self, valid: bool) {
+        self.verifications.fetch_add(1, Ordering::Relaxed);
+        if !valid {
+            self.verification_failures.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    /// Renders the counters in the Prometheus text exposition format.
+    pub fn render(&self) -> String {
+        let counters = [
+            (
+                "nebula_vrf_randomness_generated_total",
+                "Random values generated.",
+                &self.randomness_generated,
+            ),
+            (
+                "nebula_vrf_verifications_total",
+                "Verifications performed.",
+                &self.verifications,
+            ),
+            (
+                "nebula_vrf_verification_failures_total",
+                "Verifications that did not succeed.",
+                &self.verification_failures,
+            ),
+        ];
+
+        let mut out = String::new();
+        for (name, help, value) in counters {
+            let _ = writeln!(out, "# HELP {} {}", name, help);
+            let _ = writeln!(out, "# TYPE {} counter", name);
+            let _ = writeln!(out, "{} {}", name, value.load(Ordering::Relaxed));
+        }
+        out
+    }
+}
@@ -0,0 +1,817 @@
+// Integration tests for the NebulaVRF API routes.
+
+use axum::body::Body;
+use axum::extract::ConnectInfo;
+use axum::Router;
+use axum::http::{header, Request, StatusCode};
+use http_body_util::BodyExt;
+use serde_json::{json, Value};
+use std::collections::HashSet;
+use std::net::SocketAddr;
+use tower::ServiceExt;
+
+use super::config::ApiConfig;
+use super::key_registry::KeyRegistry;
+use super::rate_limit::RateLimitConfig;
+use super::routes::create_routes;
+use super::state::AppState;
+
+async fn send_raw(app: &Router, request: Request<Body>) -> (StatusCode, String) {
+    let response = app.clone().oneshot(request).await.unwrap();
+    let status = response.status();
+    let bytes = response.into_body().collect().await.unwrap().to_bytes();
+    (status, String::from_utf8(bytes.to_vec()).unwrap())
+}
+
+async fn send(request: Request<Body>) -> (StatusCode, Value) {
+    let (status, body) = send_raw(&create_routes(ApiConfig::default(), AppState::default()), request).await;
+    (status, serde_json::from_str(&body).unwrap_or(Value::Null))
+}
+
+async fn get(uri: &str) -> (StatusCode, Value) {
+    send(Request::get(uri).body(Body::empty()).unwrap()).await
+}
+
+async fn post(uri: &str, body: Value) -> (StatusCode, Value) {
+    let request = Request::post(uri)
+        .header("content-type", "application/json")
+        .body(Body::from(body.to_string()))
+        .unwrap();
+    send(request).await
+}
+
+#[tokio::test]
+async fn get_random_returns_verifiable_output() {
+    let (status, body) = get("/get-random?proof=true").await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (status, verified) = post("/verify-random", json!({
+        "seed": body["seed"],
+        "output": body["randomness"],
+        "proof": body["proof"],
+        "public_key": body["public_key"],
+    })).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(verified["valid"], true);
+}
+
+#[tokio::test]
+async fn verify_random_reports_failure_reason() {
+    let (_, body) = get("/get-random?proof=true").await;
+    let mut proof = hex::decode(body["proof"].as_str().unwrap()).unwrap();
+    proof[0] ^= 0xff;
+
+    let (status, verified) = post("/verify-random", json!({
+        "seed": body["seed"],
+        "output": body["randomness"],
+        "proof": hex::encode(&proof),
+        "public_key": body["public_key"],
+    })).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(verified["valid"], false);
+    assert!(verified["reason"].is_string(), "A failed verification must carry a reason");
+
+    let (status, verified) = post("/verify-random", json!({
+        "seed": body["seed"],
+        "output": body["randomness"],
+        "proof": body["proof"],
+        "public_key": &body["public_key"].as_str().unwrap()[..10],
+    })).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(verified["reason"], "invalid_length");
+
+    let (_, verified) = post("/verify-random", json!({
+        "seed": body["seed"],
+        "output": body["randomness"],
+        "proof": body["proof"],
+        "public_key": body["public_key"],
+    })).await;
+    assert!(verified.get("reason").is_none(), "A valid proof must not carry a reason");
+}
+
+#[tokio::test]
+async fn verify_random_rejects_malformed_hex() {
+    let (status, body) = post("/verify-random", json!({
+        "seed": "00",
+        "output": "00",
+        "proof": "not-hex",
+        "public_key": "00",
+    })).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["code"], "invalid_hex");
+    assert!(body["message"].as_str().unwrap().contains("`proof`"));
+}
+
+#[tokio::test]
+async fn hex_fields_accept_prefix_and_surrounding_whitespace() {
+    let (_, body) = get("/get-random?proof=true").await;
+    let field = |name: &str| body[name].as_str().unwrap().to_string();
+
+    let (status, verified) = post("/verify-random", json!({
+        "seed": format!("0x{}", field("seed")),
+        "output": format!("{}\n", field("randomness")),
+        "proof": format!("0X{}\r\n", field("proof")),
+        "public_key": format!("  0x{}\t", field("public_key")),
+    })).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(verified["valid"], true);
+
+    let (status, random) = get(&format!("/get-random?seed=0x{}%0A", field("seed"))).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(random["seed"], body["seed"]);
+
+    let (status, error) = post("/commit", json!({ "seed": "0x0xab" })).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(error["code"], "invalid_hex");
+}
+
+#[tokio::test]
+async fn verify_random_batch_reports_each_entry() {
+    let mut entries = Vec::new();
+    for _ in 0..4 {
+        let (_, body) = get("/get-random?proof=true").await;
+        entries.push(json!({
+            "seed": body["seed"],
+            "output": body["randomness"],
+            "proof": body["proof"],
+            "public_key": body["public_key"],
+        }));
+    }
+    // A proof from another entry, a wrong output, and undecodable hex.
+    entries[1]["proof"] = entries[0]["proof"].clone();
+    entries[2]["output"] = json!("00".repeat(32));
+    entries.push(json!({ "seed": "00", "output": "00", "proof": "not-hex", "public_key": "00" }));
+
+    let (status, body) = post("/verify-random-batch", Value::Array(entries)).await;
+    assert_eq!(status, StatusCode::OK);
+    let valid: Vec<bool> = body.as_array().unwrap().iter().map(|e| e["valid"].as_bool().unwrap()).collect();
+    assert_eq!(valid, [true, false, false, true, false]);
+}
+
+#[tokio::test]
+async fn verify_random_batch_rejects_too_many_entries() {
+    let entry = json!({ "seed": "00", "output": "00", "proof": "00", "public_key": "00" });
+    let (status, body) = post("/verify-random-batch", Value::Array(vec![entry; 101])).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["code"], "batch_too_large");
+}
+
+#[tokio::test]
+async fn verify_by_key_id_uses_registered_key() {
+    let keypair = nebula_vrf::vrf::derive_keypair(b"registry-master-secret-material!", b"beacon");
+    let mut keys = KeyRegistry::default();
+    keys.insert_hex("beacon-1", &hex::encode(keypair.public_key())).unwrap();
+    let app = create_routes(ApiConfig::default(), AppState::with_keys(keys));
+    let verify = |body: Value| {
+        let request = Request::post("/verify-by-keyid")
+            .header("content-type", "application/json")
+            .body(Body::from(body.to_string()))
+            .unwrap();
+        let app = app.clone();
+        async move {
+            let (status, body) = send_raw(&app, request).await;
+            (status, serde_json::from_str::<Value>(&body).unwrap())
+        }
+    };
+
+    let vrf = keypair.prove(b"round-1");
+    let (status, body) = verify(json!({
+        "seed": hex::encode(b"round-1"),
+        "output": hex::encode(&vrf.output),
+        "proof": hex::encode(&vrf.proof),
+        "key_id": "beacon-1",
+    })).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["valid"], true);
+
+    // A proof under the client's own key does not verify against the registered one.
+    let forged = nebula_vrf::vrf::generate_random(b"round-1").unwrap();
+    let (status, body) = verify(json!({
+        "seed": hex::encode(b"round-1"),
+        "output": hex::encode(&forged.output),
+        "proof": hex::encode(&forged.proof),
+        "key_id": "beacon-1",
+    })).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["valid"], false);
+    assert_eq!(body["reason"], "verification_failed");
+
+    let (status, body) = verify(json!({
+        "seed": "00",
+        "output": "00",
+        "proof": "00",
+        "key_id": "unknown",
+    })).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(body["code"], "unknown_key");
+}
+
+#[test]
+fn key_registry_rejects_invalid_keys() {
+    let mut keys = KeyRegistry::default();
+    assert!(keys.insert_hex("bad-hex", "zz").is_err());
+    assert!(keys.insert_hex("short", "0102").is_err());
+    assert!(keys.get("short").is_none());
+}
+
+fn hex_to_base64(value: &Value) -> String {
+    use base64::{engine::general_purpose, Engine as _};
+    general_purpose::STANDARD.encode(hex::decode(value.as_str().unwrap()).unwrap())
+}
+
+#[tokio::test]
+async fn verify_random_accepts_base64() {
+    let (_, body) = get("/get-random?proof=true").await;
+
+    let (status, verified) = post("/verify-random", json!({
+        "seed": hex_to_base64(&body["seed"]),
+        "output": hex_to_base64(&body["randomness"]),
+        "proof": hex_to_base64(&body["proof"]),
+        "public_key": hex_to_base64(&body["public_key"]),
+        "encoding": "base64",
+    })).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(verified["valid"], true);
+
+    let (status, body) = post("/verify-random", json!({
+        "seed": "AA==",
+        "output": "AA==",
+        "proof": "***",
+        "public_key": "AA==",
+        "encoding": "base64",
+    })).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["code"], "invalid_base64");
+    assert!(body["message"].as_str().unwrap().contains("`proof`"));
+}
+
+#[tokio::test]
+async fn commit_accepts_base64() {
+    let (_, hex_commit) = post("/commit", json!({ "seed": "0102", "salt": "0304" })).await;
+    let (status, b64_commit) = post("/commit", json!({
+        "seed": "AQI=",
+        "salt": "AwQ=",
+        "encoding": "base64",
+    })).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(hex_commit["commitment"], b64_commit["commitment"]);
+
+    let (status, verified) = post("/verify-commit", json!({
+        "seed": "AQI=",
+        "salt": "AwQ=",
+        "commitment": hex_to_base64(&hex_commit["commitment"]),
+        "encoding": "base64",
+    })).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(verified["valid"], true);
+}
+
+#[tokio::test]
+async fn verify_reveal_reports_each_check() {
+    let (_, random) = get("/get-random?proof=true").await;
+    let (_, committed) = post("/commit", json!({ "seed": random["seed"], "salt": "5a17" })).await;
+    let mut tampered = hex::decode(random["proof"].as_str().unwrap()).unwrap();
+    tampered[0] ^= 0xff;
+    let tampered = hex::encode(tampered);
+
+    for (salt, proof, commit_valid, proof_valid) in [
+        ("5a17", random["proof"].as_str().unwrap(), true, true),
+        ("5a18", random["proof"].as_str().unwrap(), false, true),
+        ("5a17", tampered.as_str(), true, false),
+        ("5a18", tampered.as_str(), false, false),
+    ] {
+        let (status, body) = post("/verify-reveal", json!({
+            "commitment": committed["commitment"],
+            "seed": random["seed"],
+            "salt": salt,
+            "output": random["randomness"],
+            "proof": proof,
+            "public_key": random["public_key"],
+        })).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["commit_valid"], commit_valid, "{}", body);
+        assert_eq!(body["proof_valid"], proof_valid, "{}", body);
+        assert_eq!(body["overall_valid"], commit_valid && proof_valid, "{}", body);
+        assert_eq!(body.get("reason").is_none(), proof_valid, "{}", body);
+    }
+}
+
+#[tokio::test]
+async fn derive_is_reproducible_per_label() {
+    let (_, random) = get("/get-random").await;
+    let parent = random["randomness"].as_str().unwrap();
+    let derive = |label: &'static str| post("/derive", json!({ "parent_output": parent, "label": label }));
+
+    let (status, shuffle) = derive("73687566666c65").await;
+    assert_eq!(status, StatusCode::OK);
+    let (_, again) = derive("73687566666c65").await;
+    assert_eq!(shuffle["child"], again["child"], "The same label must give the same child");
+    let (_, draw) = derive("6472617733").await;
+    assert_ne!(shuffle["child"], draw["child"], "Different labels must give different children");
+
+    let parent: [u8; 32] = hex::decode(parent).unwrap().try_into().unwrap();
+    let expected = nebula_vrf::vrf::derive_child_from_output(&parent, b"shuffle");
+    assert_eq!(shuffle["child"], hex::encode(expected));
+
+    let (status, _) = post("/derive", json!({ "parent_output": "abcd", "label": "00" })).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn misspelled_fields_are_rejected_with_400() {
+    let (_, random) = get("/get-random?proof=true").await;
+    let (status, body) = post("/verify-random", json!({
+        "seed": random["seed"],
+        "output": random["randomness"],
+        "proof": random["proof"],
+        "publickey": random["public_key"],
+        "public_key": random["public_key"],
+    })).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST, "{}", body);
+    assert_eq!(body["code"], "invalid_field");
+    assert!(body["message"].as_str().unwrap().contains("`publickey`"), "{}", body);
+
+    let (status, body) = post("/commit", json!({ "seed": "00", "slat": "01" })).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert!(body["message"].as_str().unwrap().contains("`slat`"), "{}", body);
+
+    let (status, body) = post("/verify-commit", json!({ "seed": "00" })).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert!(body["message"].as_str().unwrap().contains("`commitment`"), "{}", body);
+
+    let (status, body) = get("/get-random?proof=true&commitment=true").await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["code"], "invalid_query");
+    assert!(body["message"].as_str().unwrap().contains("`commitment`"), "{}", body);
+}
+
+#[tokio::test]
+async fn get_random_encodes_fields_in_requested_base() {
+    use nebula_vrf::utils::encoding::{decode, Base};
+
+    let seed = "ab".repeat(32);
+    let (_, hex_body) = get(&format!("/get-random?seed={}&proof=true&commit=true", seed)).await;
+    let from_hex = |field: &str| hex::decode(hex_body[field].as_str().unwrap()).unwrap();
+
+    for (param, base) in [
+        ("base16", Base::Base16),
+        ("base32", Base::Base32),
+        ("base58btc", Base::Base58Btc),
+        ("base64", Base::Base64),
+        ("base64url", Base::Base64Url),
+    ] {
+        let uri = format!("/get-random?seed={}&proof=true&commit=true&encoding={}", seed, param);
+        let (status, body) = get(&uri).await;
+        assert_eq!(status, StatusCode::OK);
+        for field in ["seed", "randomness", "proof", "public_key", "commitment"] {
+            let decoded = decode(body[field].as_str().unwrap()).unwrap();
+            assert_eq!(decoded, (base, from_hex(field)), "{} field `{}` must round-trip", param, field);
+        }
+    }
+
+    let (status, _) = get(&format!("/get-random?seed={}&encoding=base2", seed)).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn get_random_rejects_oversized_seed() {
+    let seed = "ab".repeat(64);
+    let (status, body) = get(&format!("/get-random?seed={}", seed)).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["code"], "invalid_length");
+    assert!(body["message"].as_str().unwrap().contains("`seed`"));
+}
+
+#[tokio::test]
+async fn verify_commit_rejects_oversized_commitment() {
+    let (status, body) = post("/verify-commit", json!({
+        "seed": "00",
+        "commitment": "ab".repeat(33),
+    })).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["code"], "invalid_length");
+}
+
+#[tokio::test]
+async fn commit_rejects_malformed_salt() {
+    let (status, body) = post("/commit", json!({ "seed": "00", "salt": "zz" })).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert!(body["message"].as_str().unwrap().contains("`salt`"));
+}
+
+#[tokio::test]
+async fn get_random_batch_returns_distinct_seeds() {
+    let (status, body) = post("/get-random-batch", json!({ "count": 1000, "proof": true })).await;
+    assert_eq!(status, StatusCode::OK);
+
+    let entries = body.as_array().unwrap();
+    assert_eq!(entries.len(), 1000);
+    assert!(entries.iter().all(|e| e["proof"].is_string() && e["public_key"].is_string()));
+
+    let seeds: HashSet<&str> = entries.iter().map(|e| e["seed"].as_str().unwrap()).collect();
+    assert_eq!(seeds.len(), 1000, "Every batch entry must have an independent seed");
+}
+
+#[tokio::test]
+async fn get_random_batch_rejects_count_above_cap() {
+    let (status, body) = post("/get-random-batch", json!({ "count": 10_001 })).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["code"], "batch_too_large");
+}
+
+#[tokio::test]
+async fn generate_payload_returns_verifiable_bundle() {
+    let (status, body) = post("/generate-payload", json!({ "seed_len": 16, "salt_len": 4 })).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["hex"]["seed"].as_str().unwrap().len(), 32);
+    assert_eq!(body["hex"]["salt"].as_str().unwrap().len(), 8);
+
+    let hex_field = |name: &str| hex::decode(body["hex"][name].as_str().unwrap()).unwrap();
+    let commitment: [u8; 32] = hex_field("commitment").try_into().unwrap();
+    assert!(nebula_vrf::soroban::verify_soroban_payload(
+        &commitment,
+        &hex_field("pubkey"),
+        &hex_field("signature"),
+    )
+    .is_ok());
+    assert_eq!(body["base64"]["seed"], hex_to_base64(&body["hex"]["seed"]));
+}
+
+#[tokio::test]
+async fn generate_payload_rejects_out_of_bounds_lengths() {
+    for bad in [json!({ "seed_len": 0 }), json!({ "salt_len": 1025 })] {
+        let (status, body) = post("/generate-payload", bad).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], "invalid_length");
+    }
+}
+
+#[tokio::test]
+async fn payloads_rejects_zero_length() {
+    let (status, body) = get("/payloads?seed_len=0").await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["code"], "invalid_length");
+}
+
+#[tokio::test]
+async fn health_reports_version() {
+    let (status, body) = get("/health").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["status"], "ok");
+    assert_eq!(body["version"], env!("CARGO_PKG_VERSION"));
+}
+
+fn metric(metrics: &str, name: &str) -> u64 {
+    metrics
+        .lines()
+        .find_map(|line| line.strip_prefix(name)?.trim().parse().ok())
+        .unwrap_or_else(|| panic!("metric {} missing", name))
+}
+
+#[tokio::test]
+async fn get_random_increments_generation_counter() {
+    let app = create_routes(ApiConfig::default(), AppState::default());
+    let metrics_request = || Request::get("/metrics").body(Body::empty()).unwrap();
+
+    let (_, before) = send_raw(&app, metrics_request()).await;
+    assert_eq!(metric(&before, "nebula_vrf_randomness_generated_total"), 0);
+
+    let (status, _) = send_raw(&app, Request::get("/get-random").body(Body::empty()).unwrap()).await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (status, after) = send_raw(&app, metrics_request()).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(metric(&after, "nebula_vrf_randomness_generated_total"), 1);
+    assert_eq!(metric(&after, "nebula_vrf_verifications_total"), 0);
+}
+
+fn from_client(uri: &str, addr: &str) -> Request<Body> {
+    let mut request = Request::get(uri).body(Body::empty()).unwrap();
+    request
+        .extensions_mut()
+        .insert(ConnectInfo(addr.parse::<SocketAddr>().unwrap()));
+    request
+}
+
+#[tokio::test]
+async fn rate_limit_rejects_request_over_burst() {
+    const BURST: u32 = 3;
+    let app = create_routes(
+        ApiConfig::default().rate_limit(RateLimitConfig { requests_per_second: 0.1, burst: BURST }),
+        AppState::default(),
+    );
+
+    for _ in 0..BURST {
+        let response = app.clone().oneshot(from_client("/get-random", "10.0.0.1:1000")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    let response = app.clone().oneshot(from_client("/get-random", "10.0.0.1:1001")).await.unwrap();
+    assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+    let retry_after: u64 = response.headers()[header::RETRY_AFTER].to_str().unwrap().parse().unwrap();
+    assert!(retry_after >= 1);
+    let bytes = response.into_body().collect().await.unwrap().to_bytes();
+    let body: Value = serde_json::from_slice(&bytes).unwrap();
+    assert_eq!(body["code"], "rate_limited");
+
+    // Other clients and the health probe are unaffected.
+    let (status, _) = send_raw(&app, from_client("/get-random", "10.0.0.2:1000")).await;
+    assert_eq!(status, StatusCode::OK);
+    let (status, _) = send_raw(&app, from_client("/health", "10.0.0.1:1000")).await;
+    assert_eq!(status, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn oversized_body_is_rejected_with_413() {
+    let app = create_routes(ApiConfig::default().max_body_bytes(1024), AppState::default());
+    let body = json!({ "seed": "00".repeat(1024) }).to_string();
+
+    let request = Request::post("/commit")
+        .header("content-type", "application/json")
+        .body(Body::from(body))
+        .unwrap();
+    let (status, _) = send_raw(&app, request).await;
+    assert_eq!(status, StatusCode::PAYLOAD_TOO_LARGE);
+
+    let request = Request::post("/commit")
+        .header("content-type", "application/json")
+        .body(Body::from(json!({ "seed": "00" }).to_string()))
+        .unwrap();
+    let (status, _) = send_raw(&app, request).await;
+    assert_eq!(status, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn oversized_fields_are_rejected_with_413() {
+    let app = create_routes(ApiConfig::default().max_body_bytes(4 * 1024 * 1024), AppState::default());
+    let seed = "00".repeat(1024 * 1024 + 1);
+
+    for (uri, body) in [
+        ("/commit", json!({ "seed": seed })),
+        ("/verify-commit", json!({ "seed": seed, "commitment": "00".repeat(32) })),
+        ("/verify-random", json!({ "seed": seed, "output": "00", "proof": "00", "public_key": "00" })),
+    ] {
+        let request = Request::post(uri)
+            .header("content-type", "application/json")
+            .body(Body::from(body.to_string()))
+            .unwrap();
+        let (status, body) = send_raw(&app, request).await;
+        assert_eq!(status, StatusCode::PAYLOAD_TOO_LARGE, "{} must reject the seed", uri);
+        let body: Value = serde_json::from_str(&body).unwrap();
+        assert_eq!(body["code"], "field_too_large");
+        assert!(body["message"].as_str().unwrap().contains("`seed`"));
+    }
+
+    let (_, random) = get("/get-random?proof=true").await;
+    let (status, body) = post("/verify-random", json!({
+        "seed": random["seed"],
+        "output": random["randomness"],
+        "proof": random["proof"],
+        "public_key": format!("{}00", random["public_key"].as_str().unwrap().repeat(2)),
+    })).await;
+    assert_eq!(status, StatusCode::PAYLOAD_TOO_LARGE);
+    assert!(body["message"].as_str().unwrap().contains("`public_key`"));
+
+    let (status, body) = post("/commit", json!({ "seed": "AA==", "encoding": "base64" })).await;
+    assert_eq!(status, StatusCode::OK, "padding must not count towards the size: {}", body);
+}
+
+#[tokio::test]
+async fn cors_preflight_allows_only_configured_origins() {
+    let allowed = "https://app.example.com";
+    let app = create_routes(
+        ApiConfig::default().allow_origin(allowed.parse().unwrap()),
+        AppState::default(),
+    );
+    let preflight = |origin: &str| {
+        Request::options("/verify-random")
+            .header(header::ORIGIN, origin)
+            .header(header::ACCESS_CONTROL_REQUEST_METHOD, "POST")
+            .header(header::ACCESS_CONTROL_REQUEST_HEADERS, "content-type")
+            .body(Body::empty())
+            .unwrap()
+    };
+
+    let response = app.clone().oneshot(preflight(allowed)).await.unwrap();
+    assert!(response.status().is_success());
+    assert_eq!(response.headers()[header::ACCESS_CONTROL_ALLOW_ORIGIN], allowed);
+    let methods = response.headers()[header::ACCESS_CONTROL_ALLOW_METHODS].to_str().unwrap();
+    assert!(methods.contains("POST"));
+
+    let response = app.clone().oneshot(preflight("https://evil.example.com")).await.unwrap();
+    assert!(!response.headers().contains_key(header::ACCESS_CONTROL_ALLOW_ORIGIN));
+}
+
+/// Load test: run with `cargo test --features api -- --ignored --nocapture load_`.
+///
+/// Fires 100 concurrent `/get-random` calls and probes `/health` while they run.
+/// With crypto offloaded to the blocking pool, probes are not stuck behind
+/// signing work on the async workers.
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+#[ignore]
+async fn load_concurrent_get_random_p99() {
+    use std::time::{Duration, Instant};
+    const REQUESTS: usize = 100;
+    const PROBES: usize = 20;
+
+    fn p99(latencies: &mut [Duration]) -> Duration {
+        latencies.sort();
+        latencies[(latencies.len() * 99).div_ceil(100) - 1]
+    }
+
+    let app = create_routes(
+        ApiConfig::default().rate_limit(RateLimitConfig { requests_per_second: 0.0, burst: 0 }),
+        AppState::default(),
+    );
+    let mut tasks = tokio::task::JoinSet::new();
+    for _ in 0..REQUESTS {
+        let app = app.clone();
+        let start = Instant::now();
+        tasks.spawn(async move {
+            let request = Request::get("/get-random?proof=true").body(Body::empty()).unwrap();
+            let status = app.oneshot(request).await.unwrap().status();
+            (status, start.elapsed())
+        });
+    }
+
+    let mut probes = Vec::with_capacity(PROBES);
+    for _ in 0..PROBES {
+        let start = Instant::now();
+        let (status, _) = send_raw(&app, Request::get("/health").body(Body::empty()).unwrap()).await;
+        assert_eq!(status, StatusCode::OK);
+        probes.push(start.elapsed());
+        tokio::time::sleep(Duration::from_millis(1)).await;
+    }
+
+    let mut latencies = Vec::with_capacity(REQUESTS);
+    while let Some(joined) = tasks.join_next().await {
+        let (status, latency) = joined.unwrap();
+        assert_eq!(status, StatusCode::OK);
+        latencies.push(latency);
+    }
+    println!(
+        "get-random x{} p99 {:?}; /health p99 under load {:?}",
+        REQUESTS,
+        p99(&mut latencies),
+        p99(&mut probes),
+    );
+}
+
+#[tokio::test]
+async fn openapi_document_describes_endpoints() {
+    let (status, doc) = get("/openapi.json").await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(doc["openapi"].as_str().unwrap().starts_with("3."));
+
+    for path in ["/get-random", "/verify-random", "/commit", "/verify-commit", "/verify-reveal"] {
+        assert!(doc["paths"][path].is_object(), "missing path {}", path);
+    }
+    let params = doc["paths"]["/get-random"]["get"]["parameters"].as_array().unwrap();
+    let names: HashSet<&str> = params.iter().map(|p| p["name"].as_str().unwrap()).collect();
+    assert_eq!(names, HashSet::from(["seed", "proof", "commit", "encoding"]));
+
+    let schemas = &doc["components"]["schemas"];
+    let verify = &schemas["VerifyRandomRequest"]["properties"];
+    for field in ["seed", "output", "proof", "public_key", "encoding"] {
+        assert!(verify[field].is_object(), "VerifyRandomRequest missing {}", field);
+    }
+    assert!(schemas["ErrorResponse"]["properties"]["code"].is_object());
+}
+
+#[cfg(feature = "grpc")]
+#[tokio::test]
+async fn grpc_round_trips_proof() {
+    use super::grpc::pb::vrf_client::VrfClient;
+    use super::grpc::pb::{GetRandomRequest, VerifyRandomRequest};
+    use super::grpc::VrfService;
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(
+        tonic::transport::Server::builder()
+            .add_service(VrfService::default().into_server())
+            .serve_with_incoming(tokio_stream::wrappers::TcpListenerStream::new(listener)),
+    );
+
+    let mut client = VrfClient::connect(format!("http://{}", addr)).await.unwrap();
+    let random = client
+        .get_random(GetRandomRequest { seed: Some(vec![7; 32]), proof: true, commit: false })
+        .await
+        .unwrap()
+        .into_inner();
+    assert_eq!(random.seed, vec![7; 32]);
+
+    let mut verify = VerifyRandomRequest {
+        seed: random.seed,
+        output: random.randomness,
+        proof: random.proof.unwrap(),
+        public_key: random.public_key.unwrap(),
+    };
+    assert!(client.verify_random(verify.clone()).await.unwrap().into_inner().valid);
+
+    verify.seed[0] ^= 1;
+    assert!(!client.verify_random(verify).await.unwrap().into_inner().valid);
+
+    let status = client
+        .get_random(GetRandomRequest { seed: Some(vec![0; 31]), proof: false, commit: false })
+        .await
+        .unwrap_err();
+    assert_eq!(status.code(), tonic::Code::InvalidArgument);
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn serves_over_unix_socket_replacing_stale_file() {
+    use super::listen::{bind_unix, serve, Listen};
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::UnixStream;
+
+    let path = std::env::temp_dir().join(format!("nebula-vrf-test-{}.sock", std::process::id()));
+    // A socket file left behind by a server that crashed.
+    drop(std::os::unix::net::UnixListener::bind(&path).unwrap());
+
+    let (stop, stopped) = tokio::sync::oneshot::channel::<()>();
+    let listen = Listen::Unix(path.clone());
+    let app = create_routes(ApiConfig::default(), AppState::default());
+    let server = tokio::spawn(async move {
+        serve(&listen, app, async {
+            let _ = stopped.await;
+        })
+        .await
+    });
+
+    let mut stream = None;
+    for _ in 0..200 {
+        if let Ok(connected) = UnixStream::connect(&path).await {
+            stream = Some(connected);
+            break;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+    }
+    let mut stream = stream.expect("server must listen on the socket");
+    stream
+        .write_all(b"GET /health HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n")
+        .await
+        .unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).await.unwrap();
+    assert!(response.starts_with("HTTP/1.1 200 OK"), "{}", response);
+    assert!(response.contains(r#""status":"ok""#), "{}", response);
+
+    let err = bind_unix(&path).await.unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::AddrInUse, "a live socket must not be replaced");
+
+    stop.send(()).unwrap();
+    server.await.unwrap().unwrap();
+    assert!(!path.exists(), "the socket file must be removed on shutdown");
+}
+
+fn args(list: &[&str]) -> impl Iterator<Item = String> {
+    list.iter().map(|s| s.to_string()).collect::<Vec<_>>().into_iter()
+}
+
+#[test]
+fn bind_addr_prefers_flag_over_env() {
+    use super::resolve_bind_addr;
+
+    assert_eq!(resolve_bind_addr(args(&[]), None).unwrap(), "0.0.0.0:3000".parse().unwrap());
+    assert_eq!(
+        resolve_bind_addr(args(&[]), Some("127.0.0.1:4000".into())).unwrap(),
+        "127.0.0.1:4000".parse().unwrap()
+    );
+    assert_eq!(
+        resolve_bind_addr(args(&["--addr", "[::1]:5000"]), Some("127.0.0.1:4000".into())).unwrap(),
+        "[::1]:5000".parse().unwrap()
+    );
+    assert_eq!(
+        resolve_bind_addr(args(&["--addr=127.0.0.1:6000"]), None).unwrap(),
+        "127.0.0.1:6000".parse().unwrap()
+    );
+}
+
+#[cfg(unix)]
+#[test]
+fn bind_addr_accepts_unix_sockets() {
+    use super::resolve_bind_addr;
+    use super::listen::Listen;
+
+    assert_eq!(
+        resolve_bind_addr(args(&["--addr", "unix:/run/nebula-vrf.sock"]), None).unwrap(),
+        Listen::Unix("/run/nebula-vrf.sock".into())
+    );
+    assert_eq!(
+        resolve_bind_addr(args(&[]), Some("[::]:3000".into())).unwrap(),
+        Listen::Tcp("[::]:3000".parse().unwrap())
+    );
+    assert!(resolve_bind_addr(args(&["--addr=unix:"]), None).unwrap_err().contains("socket path"));
+}
+
+#[test]
+fn bind_addr_rejects_bad_input() {
+    use super::resolve_bind_addr;
+
+    let err = resolve_bind_addr(args(&[]), Some("localhost".into())).unwrap_err();
+    assert!(err.contains("NEBULA_BIND_ADDR") && err.contains("localhost"), "{}", err);
+    assert!(resolve_bind_addr(args(&["--addr"]), None).unwrap_err().contains("needs a value"));
+    assert!(resolve_bind_addr(args(&["--port", "1"]), None).unwrap_err().contains("--port"));
+}
@@ -0,0 +1,11 @@
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+
+    // The gRPC stubs are generated with a vendored protoc so no system install is needed.
+    #[cfg(feature = "grpc")]
+    {
+        let protoc = protoc_bin_vendored::protoc_bin_path().expect("vendored protoc is available");
+        std::env::set_var("PROTOC", protoc);
+        tonic_build::compile_protos("proto/nebula_vrf.proto").expect("failed to compile nebula_vrf.proto");
+    }
+}
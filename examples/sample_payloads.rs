@@ -6,24 +6,88 @@
 //! Usage:
 //!   cargo run --example sample_payloads
 //!   cargo run --example sample_payloads -- --seed-len 16 --salt-len 16
+//!   cargo run --example sample_payloads -- --salt-len=32
+//!   cargo run --example sample_payloads --features soroban   # ScVal XDR arguments
 
+use nebula_vrf::helpers::MAX_PAYLOAD_FIELD_LEN;
 use nebula_vrf::SamplePayload;
 use std::env;
+use std::process;
+
+const USAGE: &str = "usage: sample_payloads [--seed-len N] [--salt-len N]";
+
+/// Parses `--seed-len` and `--salt-len` in any order, as `--flag N` or `--flag=N`.
+fn parse_args(args: &[String]) -> Result<(usize, usize), String> {
+    let mut seed_len = 8;
+    let mut salt_len = 8;
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        let (flag, inline) = match arg.split_once('=') {
+            Some((flag, value)) => (flag, Some(value.to_string())),
+            None => (arg.as_str(), None),
+        };
+        let target = match flag {
+            "--seed-len" => &mut seed_len,
+            "--salt-len" => &mut salt_len,
+            _ => return Err(format!("unknown argument `{}`", arg)),
+        };
+        let value = match inline {
+            Some(value) => value,
+            None => iter.next().ok_or(format!("`{}` needs a value", flag))?.clone(),
+        };
+        *target = value
+            .parse::<usize>()
+            .ok()
+            .filter(|len| (1..=MAX_PAYLOAD_FIELD_LEN).contains(len))
+            .ok_or(format!(
+                "`{}` must be an integer from 1 to {}, got `{}`",
+                flag, MAX_PAYLOAD_FIELD_LEN, value
+            ))?;
+    }
+    Ok((seed_len, salt_len))
+}
+
+/// Prints the `commit()` and `reveal()` arguments as base64 field values.
+#[cfg(not(feature = "soroban"))]
+fn print_contract_args(payload: &SamplePayload) {
+    println!("\n=== STELLAR LAB VALUES ===\n");
+    println!("For commit():");
+    println!("  user: <YOUR_USER_ADDRESS>");
+    println!("  commitment: {}", payload.commitment_base64());
+    println!("  pubkey: {}", payload.pubkey_base64());
+
+    println!("\nFor reveal():");
+    println!("  user: <YOUR_USER_ADDRESS>");
+    println!("  seed: {}", payload.seed_base64());
+    println!("  salt: {}", payload.salt_base64());
+    println!("  signature: {}", payload.signature_base64());
+}
+
+/// Prints the `commit()` and `reveal()` arguments as base64 `ScVal` XDR.
+#[cfg(feature = "soroban")]
+fn print_contract_args(payload: &SamplePayload) {
+    use stellar_xdr::curr::{Limits, ScVal, WriteXdr};
+    let xdr = |value: ScVal| value.to_xdr_base64(Limits::none()).expect("ScVal encodes to XDR");
+
+    println!("\n=== CONTRACT ARGUMENTS (ScVal XDR) ===\n");
+    println!("For commit():");
+    println!("  user: <YOUR_USER_ADDRESS>");
+    println!("  commitment: {}", xdr(payload.commitment_scval()));
+    println!("  pubkey: {}", xdr(payload.pubkey_scval()));
+
+    println!("\nFor reveal():");
+    println!("  user: <YOUR_USER_ADDRESS>");
+    println!("  seed: {}", xdr(payload.seed_scval()));
+    println!("  salt: {}", xdr(payload.salt_scval()));
+    println!("  signature: {}", xdr(payload.signature_scval()));
+}
 
 fn main() {
-    let args: Vec<String> = env::args().collect();
-    
-    let seed_len = if args.len() > 2 && args[1] == "--seed-len" {
-        args[2].parse().unwrap_or(8)
-    } else {
-        8
-    };
-    
-    let salt_len = if args.len() > 4 && args[3] == "--salt-len" {
-        args[4].parse().unwrap_or(8)
-    } else {
-        8
-    };
+    let args: Vec<String> = env::args().skip(1).collect();
+    let (seed_len, salt_len) = parse_args(&args).unwrap_or_else(|e| {
+        eprintln!("error: {}\n{}", e, USAGE);
+        process::exit(2);
+    });
 
     println!("=== NebulaVRF Sample Payload Generator ===\n");
     println!("Generating payload with seed_len={}, salt_len={}\n", seed_len, salt_len);
@@ -51,17 +115,7 @@ fn main() {
     println!("Pubkey (base64, {} bytes): {}", payload.pubkey.len(), payload.pubkey_base64());
     println!("Signature (base64, {} bytes): {}", payload.signature.len(), payload.signature_base64());
 
-    println!("\n=== STELLAR LAB VALUES ===\n");
-    println!("For commit():");
-    println!("  user: <YOUR_USER_ADDRESS>");
-    println!("  commitment: {}", payload.commitment_base64());
-    println!("  pubkey: {}", payload.pubkey_base64());
-    
-    println!("\nFor reveal():");
-    println!("  user: <YOUR_USER_ADDRESS>");
-    println!("  seed: {}", payload.seed_base64());
-    println!("  salt: {}", payload.salt_base64());
-    println!("  signature: {}", payload.signature_base64());
+    print_contract_args(&payload);
 
     println!("\n=== VERIFICATION ===\n");
     println!("Commitment matches: sha256(seed || salt)");
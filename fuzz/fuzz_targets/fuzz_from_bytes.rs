@@ -0,0 +1,13 @@
+//! Feeds arbitrary bytes into `VRFProof::from_bytes` and checks that anything
+//! it accepts round-trips through `to_bytes`.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use nebula_vrf::vrf::VRFProof;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(proof) = VRFProof::from_bytes(data) {
+        assert_eq!(proof.to_bytes().unwrap(), data);
+    }
+});
@@ -0,0 +1,25 @@
+//! Feeds arbitrary seed, signature, and public-key bytes into `verify_proof`.
+//!
+//! The first two bytes pick the signature and public-key lengths so the fuzzer
+//! reaches both the length checks and blst's point decoding.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use nebula_vrf::vrf::{verify_output, verify_proof};
+
+fuzz_target!(|data: &[u8]| {
+    let [sig_len, pk_len, rest @ ..] = data else {
+        return;
+    };
+    // Scale the length bytes to cover the compressed and uncompressed sizes (up to 192).
+    let sig_len = (usize::from(*sig_len) * 3 / 4).min(rest.len());
+    let (sig, rest) = rest.split_at(sig_len);
+    let pk_len = (usize::from(*pk_len) * 3 / 4).min(rest.len());
+    let (pk, seed) = rest.split_at(pk_len);
+
+    // Random bytes essentially never form a valid proof; the point is that
+    // every input produces a Result instead of a panic.
+    let _ = verify_proof(seed, sig, pk);
+    let _ = verify_output(seed, &[0u8; 32], sig, pk);
+});
@@ -0,0 +1,213 @@
+//! Command-line interface for offline NebulaVRF generation and verification.
+//!
+//! Usage:
+//!   vrf-cli gen    (--seed <hex> | --seed-file <path>) [--json]
+//!   vrf-cli verify --seed <hex> --output <hex> --proof <hex> --pubkey <hex> [--json]
+//!   vrf-cli commit --seed <hex> [--json]
+//!   vrf-cli reveal --seed <hex> --salt <hex> [--commitment <hex>] [--json]
+//!
+//! Pass `--seed -` to read the hex seed from stdin. Every command also accepts
+//! `--seed-file <path>` in place of `--seed`: the seed is then the SHA256 digest
+//! of the file's raw bytes, read in chunks so files of any size work.
+//! Exits with 0 on success or a valid result, 1 on an invalid result, and 2 on usage errors.
+
+use std::collections::HashMap;
+use std::env;
+use std::fs::File;
+use std::io::{self, Read};
+use std::process::ExitCode;
+
+use nebula_vrf::vrf::commit::{commit, commit_with_salt, verify_commit_with_salt};
+use nebula_vrf::utils::hash::VrfHasher;
+use nebula_vrf::vrf::{generate_random, verify_output};
+
+/// Chunk size for streaming `--seed-file` into the hasher.
+const SEED_FILE_CHUNK: usize = 64 * 1024;
+
+const USAGE: &str = "Usage:
+  vrf-cli gen    (--seed <hex> | --seed-file <path>) [--json]
+  vrf-cli verify --seed <hex> --output <hex> --proof <hex> --pubkey <hex> [--json]
+  vrf-cli commit --seed <hex> [--json]
+  vrf-cli reveal --seed <hex> --salt <hex> [--commitment <hex>] [--json]
+
+Pass `--seed -` to read the hex seed from stdin, or `--seed-file <path>` in place
+of `--seed` to use the SHA256 digest of a file's raw bytes as the seed.";
+
+/// Parsed command-line flags.
+struct Args {
+    flags: HashMap<String, String>,
+    json: bool,
+}
+
+impl Args {
+    fn parse(raw: &[String]) -> Result<Self, String> {
+        let mut flags = HashMap::new();
+        let mut json = false;
+        let mut iter = raw.iter();
+        while let Some(arg) = iter.next() {
+            match arg.strip_prefix("--") {
+                Some("json") => json = true,
+                Some(name) => {
+                    let value = iter
+                        .next()
+                        .ok_or_else(|| format!("Missing value for --{}", name))?;
+                    flags.insert(name.to_string(), value.clone());
+                }
+                None => return Err(format!("Unexpected argument: {}", arg)),
+            }
+        }
+        Ok(Args { flags, json })
+    }
+
+    fn value(&self, name: &str) -> Result<&str, String> {
+        self.flags
+            .get(name)
+            .map(String::as_str)
+            .ok_or_else(|| format!("Missing required flag --{}", name))
+    }
+
+    fn hex(&self, name: &str) -> Result<Vec<u8>, String> {
+        decode_hex(name, self.value(name)?)
+    }
+
+    fn seed(&self) -> Result<Vec<u8>, String> {
+        if let Some(path) = self.flags.get("seed-file") {
+            if self.flags.contains_key("seed") {
+                return Err("Pass either --seed or --seed-file, not both".to_string());
+            }
+            return hash_file(path).map(|digest| digest.to_vec());
+        }
+        let value = self.value("seed")?;
+        if value == "-" {
+            let mut input = String::new();
+            io::stdin()
+                .read_to_string(&mut input)
+                .map_err(|e| format!("Failed to read seed from stdin: {}", e))?;
+            decode_hex("seed", input.trim())
+        } else {
+            decode_hex("seed", value)
+        }
+    }
+}
+
+/// Streams a file into a `VrfHasher` and returns the digest, so the file is
+/// never held in memory whole.
+fn hash_file(path: &str) -> Result<[u8; 32], String> {
+    let read_error = |e: io::Error| format!("Failed to read --seed-file {}: {}", path, e);
+    let mut file = File::open(path).map_err(read_error)?;
+    let mut hasher = VrfHasher::new();
+    let mut chunk = vec![0u8; SEED_FILE_CHUNK];
+    loop {
+        match file.read(&mut chunk) {
+            Ok(0) => return Ok(hasher.finalize()),
+            Ok(n) => hasher.update(&chunk[..n]),
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(read_error(e)),
+        }
+    }
+}
+
+fn decode_hex(name: &str, value: &str) -> Result<Vec<u8>, String> {
+    hex::decode(value).map_err(|e| format!("--{} is not valid hex: {}", name, e))
+}
+
+/// Prints key/value pairs as plain lines or a flat JSON object.
+fn print_fields(fields: &[(&str, String)], json: bool) {
+    if json {
+        let body: Vec<String> = fields
+            .iter()
+            .map(|(key, value)| format!("\"{}\":{}", key, value))
+            .collect();
+        println!("{{{}}}", body.join(","));
+    } else {
+        for (key, value) in fields {
+            println!("{}: {}", key, value.trim_matches('"'));
+        }
+    }
+}
+
+fn quoted(value: impl AsRef<[u8]>) -> String {
+    format!("\"{}\"", hex::encode(value))
+}
+
+fn run(command: &str, args: &Args) -> Result<bool, String> {
+    match command {
+        "gen" => {
+            let seed = args.seed()?;
+            let vrf = generate_random(&seed).map_err(|e| e.to_string())?;
+            let mut fields = vec![
+                ("output", quoted(&vrf.output)),
+                ("proof", quoted(&vrf.proof)),
+                ("public_key", quoted(&vrf.public_key)),
+            ];
+            // A file seed is its digest; print it so the proof can be checked
+            // with `--seed` or through the API.
+            if args.flags.contains_key("seed-file") {
+                fields.insert(0, ("seed", quoted(&seed)));
+            }
+            print_fields(&fields, args.json);
+            Ok(true)
+        }
+        "verify" => {
+            let seed = args.seed()?;
+            let output = args.hex("output")?;
+            let proof = args.hex("proof")?;
+            let pubkey = args.hex("pubkey")?;
+            let result = verify_output(&seed, &output, &proof, &pubkey);
+            let mut fields = vec![("valid", result.is_ok().to_string())];
+            if let Err(e) = &result {
+                fields.push(("reason", format!("\"{}\"", e)));
+            }
+            print_fields(&fields, args.json);
+            Ok(result.is_ok())
+        }
+        "commit" => {
+            let seed = args.seed()?;
+            print_fields(&[("commitment", quoted(commit(&seed)))], args.json);
+            Ok(true)
+        }
+        "reveal" => {
+            let seed = args.seed()?;
+            let salt = args.hex("salt")?;
+            let vrf = generate_random(&seed).map_err(|e| e.to_string())?;
+            let mut fields = vec![
+                ("commitment", quoted(commit_with_salt(&seed, &salt))),
+                ("output", quoted(&vrf.output)),
+                ("proof", quoted(&vrf.proof)),
+                ("public_key", quoted(&vrf.public_key)),
+            ];
+            let valid = match args.flags.get("commitment") {
+                Some(expected) => {
+                    let expected: [u8; 32] = decode_hex("commitment", expected)?
+                        .try_into()
+                        .map_err(|_| "--commitment must be 32 bytes".to_string())?;
+                    let valid = verify_commit_with_salt(&seed, &salt, &expected);
+                    fields.push(("valid", valid.to_string()));
+                    valid
+                }
+                None => true,
+            };
+            print_fields(&fields, args.json);
+            Ok(valid)
+        }
+        _ => Err(format!("Unknown command: {}", command)),
+    }
+}
+
+fn main() -> ExitCode {
+    let raw: Vec<String> = env::args().skip(1).collect();
+    let Some((command, rest)) = raw.split_first() else {
+        eprintln!("{}", USAGE);
+        return ExitCode::from(2);
+    };
+
+    let result = Args::parse(rest).and_then(|args| run(command, &args));
+    match result {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::from(1),
+        Err(message) => {
+            eprintln!("error: {}\n\n{}", message, USAGE);
+            ExitCode::from(2)
+        }
+    }
+}
@@ -4,23 +4,96 @@
 //! that are compatible with the on-chain contract's expected format.
 
 use blst::min_pk::SecretKey;
+use rand::rngs::OsRng;
 use rand::RngCore;
 use base64::{Engine as _, engine::general_purpose};
+use zeroize::Zeroize;
 
-use crate::utils::hash::sha256;
+use crate::vrf::commit::commit_with_salt;
+#[cfg(feature = "rayon")]
+use crate::vrf::pool::{self, VrfPool};
 
-/// Domain separation tag used by the Soroban contract.
-/// This MUST match the DST in the contract: "NEBULA-VRF-V01-BLS12381G2"
-pub const SOROBAN_DST: &[u8] = b"NEBULA-VRF-V01-BLS12381G2";
+pub use crate::soroban::{
+    verify_soroban_payload, SOROBAN_DST, SOROBAN_G1_PUBKEY_SIZE, SOROBAN_G2_SIGNATURE_SIZE,
+};
 
-/// Soroban BLS12-381 format standards:
-/// - G1 Public Key: 96 bytes (uncompressed)
-/// - G2 Signature: 192 bytes (uncompressed)
-pub const SOROBAN_G1_PUBKEY_SIZE: usize = 96;
-pub const SOROBAN_G2_SIGNATURE_SIZE: usize = 192;
+/// Largest seed or salt, in bytes, accepted by `SamplePayload`.
+pub const MAX_PAYLOAD_FIELD_LEN: usize = 1024;
 
-/// Complete payload for commit and reveal operations.
+/// Salt length, in bytes, used by `SamplePayload::from_seed_deterministic`.
+pub const DETERMINISTIC_SALT_LEN: usize = 8;
+
+/// Checks that a seed or salt length is in `1..=MAX_PAYLOAD_FIELD_LEN`.
+fn check_field_len(field: &str, len: usize) -> Result<(), String> {
+    if len == 0 {
+        return Err(format!("{} must not be empty", field));
+    }
+    if len > MAX_PAYLOAD_FIELD_LEN {
+        return Err(format!(
+            "{} is {} bytes; at most {} bytes are allowed",
+            field, len, MAX_PAYLOAD_FIELD_LEN
+        ));
+    }
+    Ok(())
+}
+
+/// Source of the random bytes used for seeds and salts.
+///
+/// Production code uses `OsSeedSource`; tests can inject a `FixedSeedSource`
+/// to make seeds, salts, and the payloads built from them reproducible.
+pub trait SeedSource {
+    /// Fills `dest` with bytes from this source.
+    fn fill(&mut self, dest: &mut [u8]);
+}
+
+/// Seed source backed by the operating system's CSPRNG.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct OsSeedSource;
+
+impl SeedSource for OsSeedSource {
+    fn fill(&mut self, dest: &mut [u8]) {
+        OsRng.fill_bytes(dest);
+    }
+}
+
+/// Deterministic seed source for tests. Never use it for real randomness.
+///
+/// Yields the given bytes in order, starting over when they run out. An empty
+/// pattern yields zeros.
 #[derive(Debug, Clone)]
+pub struct FixedSeedSource {
+    bytes: Vec<u8>,
+    position: usize,
+}
+
+impl FixedSeedSource {
+    /// Creates a source that replays `bytes`.
+    pub fn new(bytes: impl Into<Vec<u8>>) -> Self {
+        FixedSeedSource { bytes: bytes.into(), position: 0 }
+    }
+}
+
+impl SeedSource for FixedSeedSource {
+    fn fill(&mut self, dest: &mut [u8]) {
+        if self.bytes.is_empty() {
+            dest.fill(0);
+            return;
+        }
+        for byte in dest {
+            *byte = self.bytes[self.position];
+            self.position = (self.position + 1) % self.bytes.len();
+        }
+    }
+}
+
+/// Complete payload for commit and reveal operations.
+///
+/// The secret key, seed, and salt are zeroized when the payload is dropped.
+/// `Debug` prints only the commitment, public key and signature.
+///
+/// Cloning copies the secret key, seed and salt. Each copy is zeroized when it
+/// is dropped, so keep clones short-lived.
+#[derive(Clone)]
 pub struct SamplePayload {
     /// Random seed bytes
     pub seed: Vec<u8>,
@@ -30,8 +103,8 @@ pub struct SamplePayload {
     pub commitment: [u8; 32],
     /// BLS public key (G1, 96 bytes)
     pub pubkey: Vec<u8>,
-    /// BLS secret key (for signing)
-    pub secret_key: SecretKey,
+    /// BLS secret key (for signing); read it with `secret_key()`
+    secret_key: SecretKey,
     /// BLS signature over the commitment message (G2, 192 bytes)
     pub signature: Vec<u8>,
 }
@@ -42,22 +115,100 @@ impl SamplePayload {
     /// # Arguments
     /// * `seed_len` - Length of seed bytes (default: 8)
     /// * `salt_len` - Length of salt bytes (default: 8)
+    ///
+    /// Both lengths must be between 1 and `MAX_PAYLOAD_FIELD_LEN`.
     pub fn generate(seed_len: usize, salt_len: usize) -> Result<Self, String> {
-        // Generate random seed and salt
-        let mut seed = vec![0u8; seed_len];
-        let mut salt = vec![0u8; salt_len];
-        rand::thread_rng().fill_bytes(&mut seed);
-        rand::thread_rng().fill_bytes(&mut salt);
+        Self::generate_with(&mut OsSeedSource, seed_len, salt_len)
+    }
+
+    /// Generate a payload with the seed and salt drawn, in that order, from `source`.
+    ///
+    /// # Arguments
+    /// * `source` - Where the seed and salt bytes come from.
+    /// * `seed_len` - Length of seed bytes
+    /// * `salt_len` - Length of salt bytes
+    pub fn generate_with(
+        source: &mut impl SeedSource,
+        seed_len: usize,
+        salt_len: usize,
+    ) -> Result<Self, String> {
+        // Checked before allocating so absurd lengths fail instead of exhausting memory.
+        check_field_len("Seed", seed_len)?;
+        check_field_len("Salt", salt_len)?;
+        let seed = generate_seed_with(source, seed_len);
+        let salt = generate_salt_with(source, salt_len);
+
+        Self::from_seed_salt(seed, salt)
+    }
+
+    /// Generate `count` payloads, each with its own random seed and salt.
+    ///
+    /// With the `rayon` feature the payloads are generated in parallel across
+    /// cores; otherwise they are generated one after another. Every seed and
+    /// salt is drawn from the OS CSPRNG, so no generator state is shared
+    /// between threads.
+    ///
+    /// # Arguments
+    /// * `count` - Number of payloads to generate
+    /// * `seed_len` - Length of seed bytes
+    /// * `salt_len` - Length of salt bytes
+    pub fn generate_many(count: usize, seed_len: usize, salt_len: usize) -> Result<Vec<Self>, String> {
+        #[cfg(feature = "rayon")]
+        {
+            Self::generate_many_in(count, seed_len, salt_len, None)
+        }
+        #[cfg(not(feature = "rayon"))]
+        {
+            (0..count).map(|_| Self::generate(seed_len, salt_len)).collect()
+        }
+    }
 
+    /// Generate `count` payloads in parallel on `pool`, or on rayon's global
+    /// pool when `pool` is `None`.
+    ///
+    /// # Arguments
+    /// * `count` - Number of payloads to generate
+    /// * `seed_len` - Length of seed bytes
+    /// * `salt_len` - Length of salt bytes
+    /// * `pool` - The pool to run on
+    #[cfg(feature = "rayon")]
+    pub fn generate_many_in(
+        count: usize,
+        seed_len: usize,
+        salt_len: usize,
+        pool: Option<&VrfPool>,
+    ) -> Result<Vec<Self>, String> {
+        use rayon::prelude::*;
+        pool::install(pool, || {
+            (0..count)
+                .into_par_iter()
+                .map(|_| Self::generate(seed_len, salt_len))
+                .collect()
+        })
+    }
+
+    /// Create a payload that is a pure function of the seed, for debugging.
+    ///
+    /// The salt is `sha256(seed || b"salt")[..DETERMINISTIC_SALT_LEN]`, so the
+    /// same seed always yields the same commitment, key, and signature. Anyone
+    /// who learns the seed can recompute the salt, so the salt adds no hiding;
+    /// use `generate` or `from_seed_salt` with a random salt for real commitments.
+    ///
+    /// The seed must be between 1 and `MAX_PAYLOAD_FIELD_LEN` bytes long.
+    pub fn from_seed_deterministic(seed: Vec<u8>) -> Result<Self, String> {
+        let salt = commit_with_salt(&seed, b"salt")[..DETERMINISTIC_SALT_LEN].to_vec();
         Self::from_seed_salt(seed, salt)
     }
 
     /// Create a test payload from existing seed and salt.
+    ///
+    /// Both must be between 1 and `MAX_PAYLOAD_FIELD_LEN` bytes long.
     pub fn from_seed_salt(seed: Vec<u8>, salt: Vec<u8>) -> Result<Self, String> {
+        check_field_len("Seed", seed.len())?;
+        check_field_len("Salt", salt.len())?;
+
         // Compute commitment: sha256(seed || salt)
-        let mut combined = seed.clone();
-        combined.extend_from_slice(&salt);
-        let commitment = sha256(&combined);
+        let commitment = commit_with_salt(&seed, &salt);
 
         // Generate BLS keypair from the commitment hash as IKM
         // We use min_pk mode: public keys in G1 (96 bytes), signatures in G2 (192 bytes)
@@ -82,12 +233,28 @@ impl SamplePayload {
         })
     }
 
+    /// Returns the BLS secret key that signed the commitment.
+    ///
+    /// The key stays owned by the payload, so it is still zeroized on drop.
+    pub fn secret_key(&self) -> &SecretKey {
+        &self.secret_key
+    }
+
+    /// Consume the payload and overwrite its secret material.
+    ///
+    /// Equivalent to dropping the payload; provided to make the intent explicit.
+    pub fn wipe(self) {
+        drop(self)
+    }
+
     /// Verify that the signature is valid for this payload.
-    /// Note: This uses low-level blst API since we have G1 pubkey and G2 signature.
+    ///
+    /// Checks the G2 signature against the G1 public key over `commitment`
+    /// with `SOROBAN_DST`, the same pairing check the contract performs.
+    /// See `verify_soroban_payload`.
     pub fn verify(&self) -> Result<(), String> {
-        // For now, skip verification as it requires low-level pairing API
-        // The contract will verify on-chain
-        Ok(())
+        verify_soroban_payload(&self.commitment, &self.pubkey, &self.signature)
+            .map_err(|e| format!("Payload would be rejected on-chain: {}", e))
     }
 
     /// Get seed as hex string
@@ -102,7 +269,7 @@ impl SamplePayload {
 
     /// Get commitment as hex string
     pub fn commitment_hex(&self) -> String {
-        hex::encode(&self.commitment)
+        hex::encode(self.commitment)
     }
 
     /// Get pubkey as hex string
@@ -127,7 +294,7 @@ impl SamplePayload {
 
     /// Get commitment as base64 string
     pub fn commitment_base64(&self) -> String {
-        general_purpose::STANDARD.encode(&self.commitment)
+        general_purpose::STANDARD.encode(self.commitment)
     }
 
     /// Get pubkey as base64 string
@@ -141,25 +308,51 @@ impl SamplePayload {
     }
 }
 
+impl std::fmt::Debug for SamplePayload {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SamplePayload")
+            .field("commitment", &hex::encode(self.commitment))
+            .field("pubkey", &hex::encode(&self.pubkey))
+            .field("signature", &hex::encode(&self.signature))
+            .finish_non_exhaustive()
+    }
+}
+
+impl Drop for SamplePayload {
+    fn drop(&mut self) {
+        self.secret_key.zeroize();
+        self.seed.zeroize();
+        self.salt.zeroize();
+    }
+}
+
 /// Generate a random seed of the specified length.
 pub fn generate_seed(len: usize) -> Vec<u8> {
+    generate_seed_with(&mut OsSeedSource, len)
+}
+
+/// Generate a seed of the specified length from `source`.
+pub fn generate_seed_with(source: &mut impl SeedSource, len: usize) -> Vec<u8> {
     let mut seed = vec![0u8; len];
-    rand::thread_rng().fill_bytes(&mut seed);
+    source.fill(&mut seed);
     seed
 }
 
 /// Generate a random salt of the specified length.
 pub fn generate_salt(len: usize) -> Vec<u8> {
+    generate_salt_with(&mut OsSeedSource, len)
+}
+
+/// Generate a salt of the specified length from `source`.
+pub fn generate_salt_with(source: &mut impl SeedSource, len: usize) -> Vec<u8> {
     let mut salt = vec![0u8; len];
-    rand::thread_rng().fill_bytes(&mut salt);
+    source.fill(&mut salt);
     salt
 }
 
 /// Compute commitment hash from seed and salt.
 /// This matches the contract's computation: sha256(seed || salt)
 pub fn compute_commitment(seed: &[u8], salt: &[u8]) -> [u8; 32] {
-    let mut combined = seed.to_vec();
-    combined.extend_from_slice(salt);
-    sha256(&combined)
+    commit_with_salt(seed, salt)
 }
 
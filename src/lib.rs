@@ -1,5 +1,20 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+#[cfg(all(feature = "min-sig", feature = "min-pk"))]
+compile_error!(
+    "features `min-sig` and `min-pk` are mutually exclusive; \
+     use `default-features = false, features = [\"std\", \"min-pk\"]` for min-pk"
+);
+
+pub mod soroban;
 pub mod utils;
 pub mod vrf;
+#[cfg(feature = "std")]
 pub mod helpers;
+#[cfg(feature = "wasm")]
+pub mod wasm;
 
-pub use helpers::SamplePayload;
\ No newline at end of file
+#[cfg(feature = "std")]
+pub use helpers::SamplePayload;
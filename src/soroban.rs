@@ -0,0 +1,121 @@
+//! Verification of Soroban-format payloads.
+//!
+//! The NebulaVRF testnet contract uses BLS12-381 in min_pk mode (public keys
+//! in G1, signatures in G2) with its own DST, unlike the `min-sig` default of
+//! `vrf::verify_proof`. This module runs the contract's pairing check off-chain
+//! so a payload can be confirmed before it is submitted. It works regardless of
+//! which scheme feature the VRF itself is built with.
+//!
+//! With the `soroban` feature, proofs and payload fields also convert to the
+//! `ScVal` arguments the contract's `commit` and `reveal` functions take.
+
+use blst::min_pk::{PublicKey, Signature};
+use blst::BLST_ERROR;
+
+use crate::vrf::VRFError;
+#[cfg(feature = "soroban")]
+use crate::{vrf::VRFProof, SamplePayload};
+#[cfg(feature = "soroban")]
+use stellar_xdr::curr::{ScBytes, ScVal};
+
+/// Domain separation tag used by the Soroban contract.
+/// This MUST match the DST in the contract: "NEBULA-VRF-V01-BLS12381G2"
+pub const SOROBAN_DST: &[u8] = b"NEBULA-VRF-V01-BLS12381G2";
+
+/// Soroban BLS12-381 format standards:
+/// - G1 Public Key: 96 bytes (uncompressed)
+/// - G2 Signature: 192 bytes (uncompressed)
+pub const SOROBAN_G1_PUBKEY_SIZE: usize = 96;
+pub const SOROBAN_G2_SIGNATURE_SIZE: usize = 192;
+
+/// Verifies a payload the way the Soroban contract does on reveal.
+///
+/// # Arguments
+/// * `commitment` - The signed message, `sha256(seed || salt)`.
+/// * `pubkey_96` - The uncompressed G1 public key.
+/// * `signature_192` - The uncompressed G2 signature.
+///
+/// # Returns
+/// * `Ok(())` if the contract would accept the signature.
+/// * `Err(VRFError::InvalidLength)` if a point has the wrong size.
+/// * `Err(VRFError::InvalidPublicKey)` / `Err(VRFError::InvalidSignature)` if a point does not decode.
+/// * `Err(VRFError::IdentityPoint)`, `Err(VRFError::PublicKeyNotInSubgroup)`, or `Err(VRFError::SignatureNotInSubgroup)` for invalid points.
+/// * `Err(VRFError::VerificationFailed)` if the pairing check fails.
+pub fn verify_soroban_payload(
+    commitment: &[u8; 32],
+    pubkey_96: &[u8],
+    signature_192: &[u8],
+) -> Result<(), VRFError> {
+    if pubkey_96.len() != SOROBAN_G1_PUBKEY_SIZE {
+        return Err(VRFError::InvalidLength { expected: SOROBAN_G1_PUBKEY_SIZE, got: pubkey_96.len() });
+    }
+    if signature_192.len() != SOROBAN_G2_SIGNATURE_SIZE {
+        return Err(VRFError::InvalidLength {
+            expected: SOROBAN_G2_SIGNATURE_SIZE,
+            got: signature_192.len(),
+        });
+    }
+
+    let pubkey = PublicKey::deserialize(pubkey_96).map_err(|_| VRFError::InvalidPublicKey)?;
+    pubkey.validate().map_err(|e| match e {
+        BLST_ERROR::BLST_PK_IS_INFINITY => VRFError::IdentityPoint,
+        BLST_ERROR::BLST_POINT_NOT_IN_GROUP => VRFError::PublicKeyNotInSubgroup,
+        _ => VRFError::InvalidPublicKey,
+    })?;
+    let signature = Signature::deserialize(signature_192).map_err(|_| VRFError::InvalidSignature)?;
+    signature.validate(true).map_err(|e| match e {
+        BLST_ERROR::BLST_PK_IS_INFINITY => VRFError::IdentityPoint,
+        BLST_ERROR::BLST_POINT_NOT_IN_GROUP => VRFError::SignatureNotInSubgroup,
+        _ => VRFError::InvalidSignature,
+    })?;
+
+    // Both points were validated above.
+    match signature.verify(false, commitment, SOROBAN_DST, &[], &pubkey, false) {
+        BLST_ERROR::BLST_SUCCESS => Ok(()),
+        _ => Err(VRFError::VerificationFailed),
+    }
+}
+
+/// Wraps bytes as an `ScVal::Bytes`. A contract's `BytesN<N>` arguments use
+/// the same XDR encoding, so this also serves fixed-size fields.
+#[cfg(feature = "soroban")]
+fn bytes_scval(bytes: &[u8]) -> ScVal {
+    // XDR byte strings only reject lengths above u32::MAX.
+    ScVal::Bytes(ScBytes::try_from(bytes.to_vec()).expect("field fits in an XDR byte string"))
+}
+
+#[cfg(feature = "soroban")]
+impl VRFProof {
+    /// Returns the proof (signature) as an `ScVal::Bytes` contract argument.
+    pub fn to_xdr_scval(&self) -> ScVal {
+        bytes_scval(&self.proof)
+    }
+}
+
+#[cfg(feature = "soroban")]
+impl SamplePayload {
+    /// Returns the seed as the `Bytes` argument to `reveal`.
+    pub fn seed_scval(&self) -> ScVal {
+        bytes_scval(&self.seed)
+    }
+
+    /// Returns the salt as the `Bytes` argument to `reveal`.
+    pub fn salt_scval(&self) -> ScVal {
+        bytes_scval(&self.salt)
+    }
+
+    /// Returns the commitment as the `BytesN<32>` argument to `commit`.
+    pub fn commitment_scval(&self) -> ScVal {
+        bytes_scval(&self.commitment)
+    }
+
+    /// Returns the public key as the `BytesN<96>` argument to `commit`.
+    pub fn pubkey_scval(&self) -> ScVal {
+        bytes_scval(&self.pubkey)
+    }
+
+    /// Returns the signature as the `BytesN<192>` argument to `reveal`.
+    pub fn signature_scval(&self) -> ScVal {
+        bytes_scval(&self.signature)
+    }
+}
@@ -0,0 +1,74 @@
+//! Multibase text encodings for proofs, keys, and outputs.
+//!
+//! A multibase string starts with a one-character code naming its base (`f`
+//! for base16, `b` for base32, `z` for base58btc, and so on), so IPFS and
+//! libp2p tooling can decode it without being told the base.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use crate::vrf::VRFError;
+
+/// A multibase encoding supported by `encode` and `decode`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Base {
+    /// Lowercase hex, prefix `f`.
+    Base16,
+    /// Lowercase RFC 4648 base32 without padding, prefix `b`.
+    Base32,
+    /// Bitcoin-alphabet base58, prefix `z`.
+    Base58Btc,
+    /// Standard base64 without padding, prefix `m`.
+    Base64,
+    /// URL-safe base64 without padding, prefix `u`.
+    Base64Url,
+}
+
+impl Base {
+    /// Every supported base.
+    pub const ALL: [Base; 5] = [Base::Base16, Base::Base32, Base::Base58Btc, Base::Base64, Base::Base64Url];
+
+    /// Returns the multibase prefix character.
+    pub fn code(self) -> char {
+        self.to_multibase().code()
+    }
+
+    fn to_multibase(self) -> multibase::Base {
+        match self {
+            Base::Base16 => multibase::Base::Base16Lower,
+            Base::Base32 => multibase::Base::Base32Lower,
+            Base::Base58Btc => multibase::Base::Base58Btc,
+            Base::Base64 => multibase::Base::Base64,
+            Base::Base64Url => multibase::Base::Base64Url,
+        }
+    }
+
+    fn from_multibase(base: multibase::Base) -> Option<Base> {
+        Base::ALL.into_iter().find(|b| b.to_multibase() == base)
+    }
+}
+
+/// Encodes bytes as a multibase string in the given base.
+///
+/// # Arguments
+/// * `bytes` - The bytes to encode, e.g. a proof or public key.
+/// * `base` - The base to encode in.
+///
+/// # Returns
+/// * `String` - The base's prefix character followed by the encoded bytes.
+pub fn encode(bytes: &[u8], base: Base) -> String {
+    multibase::encode(base.to_multibase(), bytes)
+}
+
+/// Decodes a multibase string, detecting the base from its prefix.
+///
+/// # Arguments
+/// * `text` - A multibase string produced by `encode` or another multibase implementation.
+///
+/// # Returns
+/// * `Ok((Base, Vec<u8>))` with the detected base and the decoded bytes.
+/// * `Err(VRFError::DeserializationError)` if the prefix is unknown or unsupported, or the body does not decode.
+pub fn decode(text: &str) -> Result<(Base, Vec<u8>), VRFError> {
+    let (base, bytes) = multibase::decode(text).map_err(|_| VRFError::DeserializationError)?;
+    let base = Base::from_multibase(base).ok_or(VRFError::DeserializationError)?;
+    Ok((base, bytes))
+}
@@ -1,5 +1,9 @@
+use alloc::vec::Vec;
 use sha2::{Digest, Sha256};
 
+/// Domain tag prefixed to the hash in `combine_contributions`.
+const COMBINE_DOMAIN: &[u8] = b"NEBULA-VRF-COMBINE-V01";
+
 /// Hashes arbitrary byte input into a 32-byte array using SHA256.
 ///
 /// # Arguments
@@ -10,3 +14,57 @@ use sha2::{Digest, Sha256};
 pub fn sha256(input: &[u8]) -> [u8; 32] {
     Sha256::digest(input).into()
 }
+
+/// Mixes several contributors' inputs into one 32-byte seed for `generate_random`.
+///
+/// Contributions are sorted before hashing, so the seed depends only on the
+/// set of inputs, not on the order they arrived in. A contributor who sees the
+/// others' inputs before submitting therefore cannot grind over positions; they
+/// can still grind over their own input, so pair this with commit-reveal.
+/// Each contribution is length-prefixed, so no two distinct lists of inputs
+/// share an encoding.
+///
+/// # Arguments
+/// * `contribs` - One byte string per contributor.
+///
+/// # Returns
+/// * `[u8; 32]` - The combined seed.
+pub fn combine_contributions(contribs: &[&[u8]]) -> [u8; 32] {
+    let mut sorted: Vec<&[u8]> = contribs.to_vec();
+    sorted.sort_unstable();
+
+    let mut hasher = Sha256::new();
+    hasher.update(COMBINE_DOMAIN);
+    hasher.update((sorted.len() as u64).to_be_bytes());
+    for contrib in sorted {
+        hasher.update((contrib.len() as u64).to_be_bytes());
+        hasher.update(contrib);
+    }
+    hasher.finalize().into()
+}
+
+/// Incremental SHA256 hasher for seeds too large to hold in memory.
+///
+/// Feed the seed in chunks with `update`, then pass the hasher to
+/// `generate_random_from_hasher` or call `finalize` for the digest.
+#[derive(Clone, Default)]
+pub struct VrfHasher {
+    inner: Sha256,
+}
+
+impl VrfHasher {
+    /// Creates an empty hasher.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Absorbs the next chunk of seed bytes.
+    pub fn update(&mut self, chunk: &[u8]) {
+        self.inner.update(chunk);
+    }
+
+    /// Returns the SHA256 digest of all absorbed bytes.
+    pub fn finalize(self) -> [u8; 32] {
+        self.inner.finalize().into()
+    }
+}
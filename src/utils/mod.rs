@@ -1 +1,2 @@
+pub mod encoding;
 pub mod hash;
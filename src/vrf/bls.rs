@@ -1,7 +1,71 @@
-use blst::min_sig::{SecretKey, PublicKey, Signature};
+use alloc::vec;
+use alloc::collections::BTreeSet;
+use alloc::vec::Vec;
+#[cfg(feature = "min-pk")]
+use blst::min_pk::{AggregateSignature, SecretKey, PublicKey, Signature};
+#[cfg(not(feature = "min-pk"))]
+use blst::min_sig::{AggregateSignature, SecretKey, PublicKey, Signature};
 use blst::BLST_ERROR;
-use crate::utils::hash::sha256;
-use super::types::{VRFError, VRFProof};
+#[cfg(feature = "std")]
+use blst::blst_scalar;
+#[cfg(feature = "std")]
+use rand::rngs::OsRng;
+#[cfg(feature = "std")]
+use rand::RngCore;
+use hkdf::Hkdf;
+use sha2::{Digest, Sha256};
+use zeroize::{Zeroize, Zeroizing};
+use crate::utils::hash::{sha256, VrfHasher};
+use super::types::{Encoding, MessageMode, VRFError, VRFProof};
+
+/// Default domain separation tag for hashing seeds to the signature group.
+///
+/// The same tag is used by both schemes so that existing min-sig proofs keep verifying.
+pub const VRF_DST: &[u8] = b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_NUL_";
+
+/// Size of a compressed VRF signature in bytes (G1 under min-sig, G2 under min-pk).
+#[cfg(not(feature = "min-pk"))]
+pub const VRF_SIGNATURE_SIZE: usize = 48;
+#[cfg(feature = "min-pk")]
+pub const VRF_SIGNATURE_SIZE: usize = 96;
+
+/// Size of a compressed VRF public key in bytes (G2 under min-sig, G1 under min-pk).
+#[cfg(not(feature = "min-pk"))]
+pub const VRF_PUBLIC_KEY_SIZE: usize = 96;
+#[cfg(feature = "min-pk")]
+pub const VRF_PUBLIC_KEY_SIZE: usize = 48;
+
+/// Size of an uncompressed VRF signature in bytes.
+pub const VRF_SIGNATURE_UNCOMPRESSED_SIZE: usize = 2 * VRF_SIGNATURE_SIZE;
+
+/// Size of an uncompressed VRF public key in bytes.
+pub const VRF_PUBLIC_KEY_UNCOMPRESSED_SIZE: usize = 2 * VRF_PUBLIC_KEY_SIZE;
+
+/// Number of random bits per scalar used to weight entries in batch verification.
+#[cfg(feature = "std")]
+const BATCH_RAND_BITS: usize = 64;
+
+/// Personalization tag for deriving `generate_random`'s key from the seed.
+///
+/// Used by default when the `personalized-ikm` feature is enabled, and by
+/// `generate_random_with_personalization` whenever it is passed explicitly.
+pub const VRF_IKM_TAG: &[u8] = b"NEBULA-VRF-IKM-v1";
+
+/// HKDF salt used by `derive_keypair` to separate derived keys from other uses of the master secret.
+const DERIVE_SALT: &[u8] = b"NEBULA-VRF-DERIVE-V01";
+
+/// Suite string mixed into the proof-to-hash derivation (RFC 9381, section 5.2).
+/// Names the signature group so the two schemes never share outputs.
+#[cfg(not(feature = "min-pk"))]
+const SUITE_STRING: &[u8] = b"NEBULA-VRF-BLS12381G1";
+#[cfg(feature = "min-pk")]
+const SUITE_STRING: &[u8] = b"NEBULA-VRF-BLS12381G2";
+
+/// Domain separator front byte for proof-to-hash (RFC 9381, section 5.2).
+const PROOF_TO_HASH_DOMAIN_SEPARATOR_FRONT: u8 = 0x03;
+
+/// Domain separator back byte for proof-to-hash (RFC 9381, section 5.2).
+const PROOF_TO_HASH_DOMAIN_SEPARATOR_BACK: u8 = 0x00;
 
 /// Generates a VRF proof and random output from a seed.
 ///
@@ -9,49 +73,1144 @@ use super::types::{VRFError, VRFProof};
 /// * `seed` - The input seed as a byte slice.
 ///
 /// # Returns
-/// * `Ok(VRFProof)` containing the output and public key if successful.
+/// * `Ok(VRFProof)` containing the output, proof and public key if successful.
 /// * `Err(VRFError)` if key generation or signing fails.
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all, fields(seed_len = seed.len())))]
 pub fn generate_random(seed: &[u8]) -> Result<VRFProof, VRFError> {
-    let dst = b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_NUL_";
+    generate_random_with_encoding(seed, Encoding::Compressed)
+}
+
+/// Generates a VRF proof and random output, rejecting seeds shorter than `min_len`.
+///
+/// `generate_random` derives the signing key from the seed itself, so anyone
+/// who can guess the seed can recompute the output. An empty or short seed
+/// leaves only a handful of candidates to try; requiring a minimum length
+/// (e.g. 32 bytes of fresh randomness) rules out that class of mistake.
+/// Length is only a proxy: a long but predictable seed is still predictable.
+///
+/// # Arguments
+/// * `seed` - The input seed as a byte slice.
+/// * `min_len` - The minimum accepted seed length in bytes.
+///
+/// # Returns
+/// * `Ok(VRFProof)` containing the output, proof and public key if successful.
+/// * `Err(VRFError::SeedTooShort)` if the seed is shorter than `min_len`.
+pub fn generate_random_checked(seed: &[u8], min_len: usize) -> Result<VRFProof, VRFError> {
+    if seed.len() < min_len {
+        return Err(VRFError::SeedTooShort { min: min_len, got: seed.len() });
+    }
+    generate_random(seed)
+}
+
+/// Generates a VRF proof and random output, encoding points with the given layout.
+///
+/// The output is identical for both encodings, since proof-to-hash normalizes
+/// the signature before hashing.
+///
+/// # Arguments
+/// * `seed` - The input seed as a byte slice.
+/// * `encoding` - Byte layout for the proof and public key.
+///
+/// # Returns
+/// * `Ok(VRFProof)` containing the output, proof and public key if successful.
+/// * `Err(VRFError)` if key generation or signing fails.
+pub fn generate_random_with_encoding(seed: &[u8], encoding: Encoding) -> Result<VRFProof, VRFError> {
+    let keypair = seed_keypair(seed)?;
+    Ok(keypair.prove_with_encoding(seed, encoding))
+}
+
+/// Generates a VRF proof and random output, choosing whether the seed is hashed first.
+///
+/// The signing key is derived from the seed in both modes, so only the proof
+/// and output depend on `mode`. Verifiers must pass the same seed and mode to
+/// `verify_proof_with_mode`.
+///
+/// # Arguments
+/// * `seed` - The input seed as a byte slice.
+/// * `mode` - Whether the message is the seed itself or its SHA256 digest.
+///
+/// # Returns
+/// * `Ok(VRFProof)` containing the output, proof and public key if successful.
+/// * `Err(VRFError)` if key generation or signing fails.
+pub fn generate_random_with_mode(seed: &[u8], mode: MessageMode) -> Result<VRFProof, VRFError> {
+    let keypair = seed_keypair(seed)?;
+    Ok(keypair.prove_with_mode(seed, mode))
+}
+
+/// Generates a VRF proof over the digest of a streamed seed.
+///
+/// The VRF input is the 32-byte SHA256 digest, not the original seed, so
+/// verifiers must pass that digest as the seed to `verify_proof`.
+///
+/// # Arguments
+/// * `hasher` - A hasher that has absorbed the full seed.
+///
+/// # Returns
+/// * `Ok(VRFProof)` containing the output, proof and public key if successful.
+/// * `Err(VRFError)` if key generation or signing fails.
+pub fn generate_random_from_hasher(hasher: VrfHasher) -> Result<VRFProof, VRFError> {
+    generate_random(&hasher.finalize())
+}
 
+/// Generates a VRF proof over a seed bound to an external beacon signature.
+///
+/// The effective seed is `sha256(seed || beacon_sig)` (see `bound_seed`), so
+/// the output cannot be computed before the beacon value exists. To convince
+/// an auditor that the randomness was unknown before time T:
+///
+/// 1. Commit to `seed` (e.g. with `commit_with_salt`) and publish the
+///    commitment before T.
+/// 2. Take `beacon_sig` from a public beacon round published at or after T,
+///    such as a drand round signature.
+/// 3. The verifier checks the beacon signature against the beacon's own
+///    public key and round schedule (outside this crate), checks that the
+///    round is later than the commitment, opens the commitment, and then
+///    calls `verify_proof_bound`.
+///
+/// The prover still chooses `seed` before seeing the beacon, and the beacon
+/// cannot be predicted, so neither party alone controls the output.
+///
+/// # Arguments
+/// * `seed` - The committed seed.
+/// * `beacon_sig` - The beacon round's signature bytes.
+///
+/// # Returns
+/// * `Ok(VRFProof)` over the bound seed if successful.
+/// * `Err(VRFError)` if key generation or signing fails.
+pub fn generate_random_bound(seed: &[u8], beacon_sig: &[u8]) -> Result<VRFProof, VRFError> {
+    generate_random(&bound_seed(seed, beacon_sig))
+}
+
+/// Returns the effective seed `sha256(seed || beacon_sig)` used by
+/// `generate_random_bound`.
+///
+/// The two inputs are concatenated without a separator, so `beacon_sig`
+/// should have a fixed length (as beacon signatures do).
+pub fn bound_seed(seed: &[u8], beacon_sig: &[u8]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(seed);
+    hasher.update(beacon_sig);
+    hasher.finalize().into()
+}
+
+/// Verifies a proof from `generate_random_bound` against the seed and beacon signature.
+///
+/// This only checks the VRF proof; the caller is responsible for verifying
+/// the beacon signature itself and its timing.
+///
+/// # Arguments
+/// * `seed` - The committed seed.
+/// * `beacon_sig` - The beacon round's signature bytes.
+/// * `signature_bytes` - The VRF proof (signature) as bytes.
+/// * `public_key_bytes` - The public key as bytes.
+///
+/// # Returns
+/// * `Ok(())` if the proof is valid over the bound seed.
+/// * `Err(VRFError::VerificationFailed)` if the proof, seed, or beacon does not match.
+pub fn verify_proof_bound(
+    seed: &[u8],
+    beacon_sig: &[u8],
+    signature_bytes: &[u8],
+    public_key_bytes: &[u8],
+) -> Result<(), VRFError> {
+    verify_proof(&bound_seed(seed, beacon_sig), signature_bytes, public_key_bytes)
+}
+
+/// Generates a VRF proof and random output under a custom domain separation tag.
+///
+/// Applications should use their own DST to prevent a signature produced for
+/// one protocol from being replayed in another. The same DST must be passed to
+/// `verify_proof_with_dst`.
+///
+/// # Arguments
+/// * `seed` - The input seed as a byte slice.
+/// * `dst` - The domain separation tag for hash-to-curve.
+///
+/// # Returns
+/// * `Ok(VRFProof)` containing the output, proof and public key if successful.
+/// * `Err(VRFError)` if key generation or signing fails.
+pub fn generate_random_with_dst(seed: &[u8], dst: &[u8]) -> Result<VRFProof, VRFError> {
+    let keypair = seed_keypair(seed)?;
+    Ok(keypair.prove_with_dst(seed, dst))
+}
+
+/// Generates a VRF proof and random output with a personalized key derivation.
+///
+/// The signing key's IKM is `sha256(len(tag) || tag || seed)`, with the tag
+/// length as a big-endian `u64`, instead of the bare `sha256(seed)` that
+/// `generate_random` uses by default. Any other system that hashes the same
+/// seed with plain SHA256 would derive the same key as the bare form; a tag
+/// naming the application keeps keys separate. Pass `VRF_IKM_TAG` for the
+/// crate's own tag.
+///
+/// The output depends on the tag, but verification does not: the proof
+/// carries its public key, so `verify_proof` is unchanged.
+///
+/// # Arguments
+/// * `seed` - The input seed as a byte slice.
+/// * `tag` - The personalization tag.
+///
+/// # Returns
+/// * `Ok(VRFProof)` containing the output, proof and public key if successful.
+/// * `Err(VRFError)` if key generation or signing fails.
+pub fn generate_random_with_personalization(seed: &[u8], tag: &[u8]) -> Result<VRFProof, VRFError> {
+    let keypair = VrfKeypair::from_ikm(&personalized_ikm(seed, tag))?;
+    Ok(keypair.prove(seed))
+}
+
+/// Derives the per-seed keypair used by the `generate_random` family.
+///
+/// Bare `sha256(seed)` by default, for compatibility with existing outputs;
+/// the `personalized-ikm` feature switches to `VRF_IKM_TAG`.
+fn seed_keypair(seed: &[u8]) -> Result<VrfKeypair, VRFError> {
+    #[cfg(feature = "personalized-ikm")]
+    let ikm = personalized_ikm(seed, VRF_IKM_TAG);
+    #[cfg(not(feature = "personalized-ikm"))]
     let ikm = sha256(seed);
-    let sk = SecretKey::key_gen(&ikm, &[]).map_err(|_| VRFError::DeserializationError)?;
-    let signature = sk.sign(seed, dst, &[]);
-    let pk = sk.sk_to_pk();
+    VrfKeypair::from_ikm(&ikm)
+}
+
+/// Returns `sha256(len(tag) || tag || seed)`, with the length as a big-endian `u64`
+/// so that no two (tag, seed) pairs share a preimage.
+fn personalized_ikm(seed: &[u8], tag: &[u8]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update((tag.len() as u64).to_be_bytes());
+    hasher.update(tag);
+    hasher.update(seed);
+    hasher.finalize().into()
+}
+
+/// A long-lived VRF signing identity.
+///
+/// Unlike `generate_random`, which derives a fresh key from each seed, a
+/// keypair signs many seeds under one public key that can be published ahead
+/// of time.
+///
+/// The secret key is zeroized when the keypair is dropped. `Debug` prints only
+/// the public key; read the secret with `expose_secret`.
+pub struct VrfKeypair {
+    secret_key: SecretKey,
+    public_key: PublicKey,
+}
 
-    Ok(VRFProof {
-        output: signature.to_bytes().to_vec(),
-        public_key: pk.to_bytes().to_vec(),
-    })
+impl core::fmt::Debug for VrfKeypair {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("VrfKeypair")
+            .field("public_key", &hex::encode(self.public_key.compress()))
+            .finish_non_exhaustive()
+    }
+}
+
+impl VrfKeypair {
+    /// Generates a new keypair from operating system randomness.
+    #[cfg(feature = "std")]
+    pub fn generate() -> VrfKeypair {
+        let mut ikm = [0u8; 32];
+        OsRng.fill_bytes(&mut ikm);
+        // key_gen only rejects IKM shorter than 32 bytes.
+        let keypair = Self::from_ikm(&ikm).expect("32-byte IKM is always accepted");
+        ikm.zeroize();
+        keypair
+    }
+
+    /// Derives a keypair deterministically from input keying material.
+    ///
+    /// # Arguments
+    /// * `ikm` - At least 32 bytes of secret keying material.
+    ///
+    /// # Returns
+    /// * `Ok(VrfKeypair)` if key generation succeeds.
+    /// * `Err(VRFError::InvalidLength)` if the IKM is shorter than 32 bytes.
+    /// * `Err(VRFError::Transient)` if blst rejects valid-length IKM, which should not happen.
+    pub fn from_ikm(ikm: &[u8]) -> Result<VrfKeypair, VRFError> {
+        if ikm.len() < 32 {
+            return Err(VRFError::InvalidLength { expected: 32, got: ikm.len() });
+        }
+        let secret_key = SecretKey::key_gen(ikm, &[]).map_err(|_| VRFError::Transient)?;
+        let public_key = secret_key.sk_to_pk();
+        Ok(VrfKeypair { secret_key, public_key })
+    }
+
+    /// Restores a keypair from a BIP-39 mnemonic and optional passphrase.
+    ///
+    /// The 64-byte BIP-39 seed derived from the phrase and passphrase is the
+    /// IKM for BLS key generation, so the same phrase and passphrase always
+    /// give the same key. The phrase must be a valid English mnemonic with a
+    /// correct checksum; an empty passphrase is allowed.
+    ///
+    /// # Arguments
+    /// * `phrase` - The mnemonic words, separated by spaces.
+    /// * `passphrase` - The BIP-39 passphrase, or `""` for none.
+    ///
+    /// # Returns
+    /// * `Ok(VrfKeypair)` if the mnemonic is valid.
+    /// * `Err(VRFError::DeserializationError)` if the phrase is not a valid mnemonic.
+    #[cfg(feature = "mnemonic")]
+    pub fn from_mnemonic(phrase: &str, passphrase: &str) -> Result<VrfKeypair, VRFError> {
+        let mnemonic = bip39::Mnemonic::parse_in(bip39::Language::English, phrase)
+            .map_err(|_| VRFError::DeserializationError)?;
+        let mut seed = mnemonic.to_seed(passphrase);
+        let keypair = Self::from_ikm(&seed);
+        seed.zeroize();
+        keypair
+    }
+
+    /// Generates a new keypair together with its 24-word BIP-39 backup phrase.
+    ///
+    /// BLS key generation is one-way, so a keypair built from other IKM cannot
+    /// be turned into a mnemonic afterwards; keys meant to be backed up this way
+    /// must be created here. `from_mnemonic(&phrase, passphrase)` restores the
+    /// same key.
+    ///
+    /// # Arguments
+    /// * `passphrase` - The BIP-39 passphrase, or `""` for none.
+    ///
+    /// # Returns
+    /// * The keypair and its mnemonic phrase.
+    #[cfg(feature = "mnemonic")]
+    pub fn generate_with_mnemonic(passphrase: &str) -> (VrfKeypair, String) {
+        let mut entropy = [0u8; 32];
+        OsRng.fill_bytes(&mut entropy);
+        let mnemonic = bip39::Mnemonic::from_entropy(&entropy).expect("32 bytes is a valid entropy length");
+        entropy.zeroize();
+        let phrase = mnemonic.to_string();
+        let keypair = Self::from_mnemonic(&phrase, passphrase).expect("a generated mnemonic is valid");
+        (keypair, phrase)
+    }
+
+    /// Returns the compressed public key (`VRF_PUBLIC_KEY_SIZE` bytes).
+    pub fn public_key(&self) -> Vec<u8> {
+        self.public_key.compress().to_vec()
+    }
+
+    /// Returns the 32-byte big-endian secret scalar, e.g. to write it to a
+    /// key store.
+    ///
+    /// Anyone holding these bytes can produce proofs under this public key.
+    /// The returned buffer is zeroized when dropped; do not log or copy it.
+    pub fn expose_secret(&self) -> Zeroizing<[u8; 32]> {
+        Zeroizing::new(self.secret_key.to_bytes())
+    }
+
+    /// Produces a VRF proof and output for a seed under this key.
+    pub fn prove(&self, seed: &[u8]) -> VRFProof {
+        self.prove_with_encoding(seed, Encoding::Compressed)
+    }
+
+    /// Produces a VRF proof and output, encoding points with the given layout.
+    pub fn prove_with_encoding(&self, seed: &[u8], encoding: Encoding) -> VRFProof {
+        self.sign(seed, VRF_DST, encoding)
+    }
+
+    /// Produces a VRF proof and output under a custom domain separation tag.
+    pub fn prove_with_dst(&self, seed: &[u8], dst: &[u8]) -> VRFProof {
+        self.sign(seed, dst, Encoding::Compressed)
+    }
+
+    /// Produces a VRF proof and output over the seed or its SHA256 digest.
+    pub fn prove_with_mode(&self, seed: &[u8], mode: MessageMode) -> VRFProof {
+        self.sign(&message_for(seed, mode), VRF_DST, Encoding::Compressed)
+    }
+
+    fn sign(&self, seed: &[u8], dst: &[u8], encoding: Encoding) -> VRFProof {
+        let signature = self.secret_key.sign(seed, dst, &[]);
+
+        let (proof, public_key) = match encoding {
+            Encoding::Compressed => (
+                signature.compress().to_vec(),
+                self.public_key.compress().to_vec(),
+            ),
+            Encoding::Uncompressed => (
+                signature.serialize().to_vec(),
+                self.public_key.serialize().to_vec(),
+            ),
+        };
+
+        VRFProof {
+            output: signature_to_hash(&signature).to_vec(),
+            proof,
+            public_key,
+        }
+    }
+}
+
+/// Derives a keypair from a master secret and a context such as a round index.
+///
+/// HKDF-SHA256 expands `master` with `context` into the 32-byte IKM for BLS
+/// key generation. The same master and context always yield the same key,
+/// while different contexts yield independent keys.
+///
+/// # Arguments
+/// * `master` - The master secret, ideally at least 32 bytes of entropy.
+/// * `context` - Application-defined label distinguishing the derived key.
+///
+/// # Returns
+/// * The derived `VrfKeypair`.
+pub fn derive_keypair(master: &[u8], context: &[u8]) -> VrfKeypair {
+    let mut ikm = [0u8; 32];
+    Hkdf::<Sha256>::new(Some(DERIVE_SALT), master)
+        .expand(context, &mut ikm)
+        .expect("32 bytes is a valid HKDF-SHA256 output length");
+    let keypair = VrfKeypair::from_ikm(&ikm).expect("32-byte IKM is always accepted");
+    ikm.zeroize();
+    keypair
+}
+
+/// Hashes a seed to a G2 point with the RFC 9380 `BLS12381G2_XMD:SHA-256_SSWU_RO_` suite.
+///
+/// This is the message point a `min-pk` proof signs: with `pk = sk·G1` and
+/// `proof = sk·H`, a valid proof satisfies `e(pk, H) == e(G1, proof)`. Under
+/// `min-sig` (the default) the VRF hashes to G1 instead; see `hash_seed_to_g1`.
+///
+/// # Arguments
+/// * `seed` - The input seed as a byte slice.
+/// * `dst` - The domain separation tag, e.g. `VRF_DST`.
+///
+/// # Returns
+/// * `Vec<u8>` - The point in uncompressed form (192 bytes).
+pub fn hash_seed_to_g2(seed: &[u8], dst: &[u8]) -> Vec<u8> {
+    let affine = hash_to_g2_affine(seed, dst);
+    let mut out = vec![0u8; 192];
+    // SAFETY: `out` holds the 192 bytes of an uncompressed G2 point.
+    unsafe { blst::blst_p2_affine_serialize(out.as_mut_ptr(), &affine) };
+    out
+}
+
+fn hash_to_g2_affine(seed: &[u8], dst: &[u8]) -> blst::blst_p2_affine {
+    let mut point = blst::blst_p2::default();
+    let mut affine = blst::blst_p2_affine::default();
+    // SAFETY: every pointer refers to a live local or slice of the stated
+    // length; a null augmentation pointer with length 0 means no augmentation.
+    unsafe {
+        blst::blst_hash_to_g2(
+            &mut point,
+            seed.as_ptr(),
+            seed.len(),
+            dst.as_ptr(),
+            dst.len(),
+            core::ptr::null(),
+            0,
+        );
+        blst::blst_p2_to_affine(&mut affine, &point);
+    }
+    affine
+}
+
+/// Hashes a seed to a G1 point with the RFC 9380 `BLS12381G1_XMD:SHA-256_SSWU_RO_` suite.
+///
+/// This is the message point a `min-sig` proof signs: with `pk = sk·G2` and
+/// `proof = sk·H`, a valid proof satisfies `e(H, pk) == e(proof, G2)`.
+///
+/// # Arguments
+/// * `seed` - The input seed as a byte slice.
+/// * `dst` - The domain separation tag, e.g. `VRF_DST`.
+///
+/// # Returns
+/// * `Vec<u8>` - The point in uncompressed form (96 bytes).
+pub fn hash_seed_to_g1(seed: &[u8], dst: &[u8]) -> Vec<u8> {
+    let affine = hash_to_g1_affine(seed, dst);
+    let mut out = vec![0u8; 96];
+    // SAFETY: `out` holds the 96 bytes of an uncompressed G1 point.
+    unsafe { blst::blst_p1_affine_serialize(out.as_mut_ptr(), &affine) };
+    out
+}
+
+fn hash_to_g1_affine(seed: &[u8], dst: &[u8]) -> blst::blst_p1_affine {
+    let mut point = blst::blst_p1::default();
+    let mut affine = blst::blst_p1_affine::default();
+    // SAFETY: as in `hash_seed_to_g2`.
+    unsafe {
+        blst::blst_hash_to_g1(
+            &mut point,
+            seed.as_ptr(),
+            seed.len(),
+            dst.as_ptr(),
+            dst.len(),
+            core::ptr::null(),
+            0,
+        );
+        blst::blst_p1_to_affine(&mut affine, &point);
+    }
+    affine
+}
+
+/// Derives the 32-byte VRF output from a proof (signature).
+///
+/// Follows the RFC 9381 proof-to-hash construction: the signature point is
+/// normalized to its canonical compressed encoding and hashed together with
+/// the suite string and domain separators.
+///
+/// # Arguments
+/// * `signature_bytes` - The VRF proof (signature) as bytes.
+///
+/// # Returns
+/// * `Ok([u8; 32])` containing the VRF output.
+/// * `Err(VRFError)` if the signature cannot be parsed.
+pub fn proof_to_hash(signature_bytes: &[u8]) -> Result<[u8; 32], VRFError> {
+    let sig = parse_signature(signature_bytes)?;
+    Ok(signature_to_hash(&sig))
+}
+
+fn signature_to_hash(sig: &Signature) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(SUITE_STRING);
+    hasher.update([PROOF_TO_HASH_DOMAIN_SEPARATOR_FRONT]);
+    hasher.update(sig.to_bytes());
+    hasher.update([PROOF_TO_HASH_DOMAIN_SEPARATOR_BACK]);
+    hasher.finalize().into()
 }
 
 /// Verifies a VRF proof given the seed, signature, and public key.
 ///
+/// The encoding of each input is detected from its length: compressed or
+/// uncompressed sizes for the signature and the public key (48/96 and 96/192
+/// bytes under min-sig; 96/192 and 48/96 bytes under min-pk).
+///
 /// # Arguments
 /// * `seed` - The input seed as a byte slice.
-/// * `signature_bytes` - The VRF output (signature) as bytes.
+/// * `signature_bytes` - The VRF proof (signature) as bytes.
 /// * `public_key_bytes` - The public key as bytes.
 ///
 /// # Returns
 /// * `Ok(())` if the proof is valid.
-/// * `Err(VRFError)` if verification fails.
+/// * `Err(VRFError::InvalidLength)` if the key or signature, checked in that
+///   order, is empty or not a compressed or uncompressed point size. Lengths
+///   are checked before any bytes reach blst.
+/// * `Err(VRFError::SignatureNotInSubgroup)` if the signature decodes but is not in the prime-order subgroup.
+/// * `Err(VRFError::VerificationFailed)` if the pairing check fails.
+/// * `Err(VRFError)` for other malformed inputs.
 pub fn verify_proof(
     seed: &[u8],
     signature_bytes: &[u8],
     public_key_bytes: &[u8],
 ) -> Result<(), VRFError> {
-    let dst = b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_NUL_";
+    verify_proof_with_dst(seed, signature_bytes, public_key_bytes, VRF_DST)
+}
+
+/// Verifies a VRF proof produced with `generate_random_with_mode`.
+///
+/// With `MessageMode::PreHashed` the seed is hashed with SHA256 before
+/// hash-to-curve, which matches contracts that verify over a message digest.
+///
+/// # Arguments
+/// * `seed` - The input seed as a byte slice.
+/// * `signature_bytes` - The VRF proof (signature) as bytes.
+/// * `public_key_bytes` - The public key as bytes.
+/// * `mode` - The message mode used when the proof was generated.
+///
+/// # Returns
+/// * `Ok(())` if the proof is valid.
+/// * `Err(VRFError::VerificationFailed)` if the proof or mode does not match.
+pub fn verify_proof_with_mode(
+    seed: &[u8],
+    signature_bytes: &[u8],
+    public_key_bytes: &[u8],
+    mode: MessageMode,
+) -> Result<(), VRFError> {
+    verify_proof_with_dst(&message_for(seed, mode), signature_bytes, public_key_bytes, VRF_DST)
+}
 
-    let pk = PublicKey::from_bytes(public_key_bytes)
-        .map_err(|_| VRFError::InvalidPublicKey)?;
+/// Returns the bytes that are hashed to the curve for `seed` under `mode`.
+fn message_for(seed: &[u8], mode: MessageMode) -> Vec<u8> {
+    match mode {
+        MessageMode::Raw => seed.to_vec(),
+        MessageMode::PreHashed => sha256(seed).to_vec(),
+    }
+}
 
-    let sig = Signature::from_bytes(signature_bytes)
-        .map_err(|_| VRFError::InvalidSignature)?;
+/// Verifies a VRF proof produced under a custom domain separation tag.
+///
+/// # Arguments
+/// * `seed` - The input seed as a byte slice.
+/// * `signature_bytes` - The VRF proof (signature) as bytes.
+/// * `public_key_bytes` - The public key as bytes.
+/// * `dst` - The domain separation tag used when the proof was generated.
+///
+/// # Returns
+/// * `Ok(())` if the proof is valid.
+/// * `Err(VRFError::VerificationFailed)` if the proof or DST does not match.
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "debug", skip_all, err(level = "debug"), fields(seed_len = seed.len()))
+)]
+pub fn verify_proof_with_dst(
+    seed: &[u8],
+    signature_bytes: &[u8],
+    public_key_bytes: &[u8],
+    dst: &[u8],
+) -> Result<(), VRFError> {
+    verify_parsed(seed, signature_bytes, public_key_bytes, dst).map(|_| ())
+}
+
+/// Verifies a VRF proof and returns its randomness on success.
+///
+/// The output is `proof_to_hash` of the verified signature, the same bytes
+/// as `VRFProof::randomness`, derived from the signature already parsed for
+/// verification instead of decoding it a second time.
+///
+/// # Arguments
+/// * `seed` - The input seed as a byte slice.
+/// * `signature_bytes` - The VRF proof (signature) as bytes.
+/// * `public_key_bytes` - The public key as bytes.
+///
+/// # Returns
+/// * `Ok([u8; 32])` - The proof's randomness, if the proof is valid.
+/// * `Err(VRFError)` - As for `verify_proof`.
+pub fn verify_and_extract(
+    seed: &[u8],
+    signature_bytes: &[u8],
+    public_key_bytes: &[u8],
+) -> Result<[u8; 32], VRFError> {
+    let sig = verify_parsed(seed, signature_bytes, public_key_bytes, VRF_DST)?;
+    Ok(signature_to_hash(&sig))
+}
+
+/// Parses and verifies a proof, returning the parsed signature on success.
+fn verify_parsed(
+    seed: &[u8],
+    signature_bytes: &[u8],
+    public_key_bytes: &[u8],
+    dst: &[u8],
+) -> Result<Signature, VRFError> {
+    let pk = parse_public_key(public_key_bytes)?;
+    let sig = parse_signature(signature_bytes)?;
+
+    // Both points were subgroup- and identity-checked while parsing.
+    match sig.verify(false, seed, dst, &[], &pk, false) {
+        BLST_ERROR::BLST_SUCCESS => Ok(sig),
+        _ => Err(VRFError::VerificationFailed),
+    }
+}
+
+/// Verifies a VRF proof without the subgroup, identity, or canonical-encoding
+/// checks that `verify_proof` runs.
+///
+/// **Not safe for untrusted input.** A signature or key outside the
+/// prime-order subgroup can make an invalid proof verify, and a non-canonical
+/// encoding gives the same proof a second byte string. Use this only where
+/// both values were already validated, e.g. keys checked with `Verifier::new`
+/// at registration and signatures checked with `verify_proof` before being
+/// stored, and then re-verified from that trusted storage. Points must still
+/// decode and lie on the curve, and a wrong signature is still rejected.
+///
+/// # Arguments
+/// * `seed` - The input seed as a byte slice.
+/// * `signature_bytes` - A previously validated VRF proof (signature).
+/// * `public_key_bytes` - A previously validated public key.
+///
+/// # Returns
+/// * `Ok(())` if the pairing check passes.
+/// * `Err(VRFError::InvalidLength)` if either input is not a point size.
+/// * `Err(VRFError::InvalidSignature)` / `Err(VRFError::InvalidPublicKey)` if a point does not decode.
+/// * `Err(VRFError::VerificationFailed)` if the pairing check fails.
+pub fn verify_proof_unchecked(
+    seed: &[u8],
+    signature_bytes: &[u8],
+    public_key_bytes: &[u8],
+) -> Result<(), VRFError> {
+    let pk = match public_key_bytes.len() {
+        VRF_PUBLIC_KEY_SIZE => PublicKey::uncompress(public_key_bytes),
+        VRF_PUBLIC_KEY_UNCOMPRESSED_SIZE => PublicKey::deserialize(public_key_bytes),
+        len => return Err(VRFError::InvalidLength { expected: VRF_PUBLIC_KEY_SIZE, got: len }),
+    }
+    .map_err(|e| point_error(e, VRFError::InvalidPublicKey))?;
+    let sig = match signature_bytes.len() {
+        VRF_SIGNATURE_SIZE => Signature::uncompress(signature_bytes),
+        VRF_SIGNATURE_UNCOMPRESSED_SIZE => Signature::deserialize(signature_bytes),
+        len => return Err(VRFError::InvalidLength { expected: VRF_SIGNATURE_SIZE, got: len }),
+    }
+    .map_err(|e| point_error(e, VRFError::InvalidSignature))?;
+
+    match sig.verify(false, seed, VRF_DST, &[], &pk, false) {
+        BLST_ERROR::BLST_SUCCESS => Ok(()),
+        _ => Err(VRFError::VerificationFailed),
+    }
+}
+
+/// Parses a signature, selecting the compressed or uncompressed parser from
+/// its length, and rejects non-canonical encodings (`VRFError::NonCanonical`),
+/// the identity, and points outside the prime-order subgroup
+/// (`VRFError::SignatureNotInSubgroup`).
+pub(super) fn parse_signature(signature_bytes: &[u8]) -> Result<Signature, VRFError> {
+    let parsed = match signature_bytes.len() {
+        VRF_SIGNATURE_SIZE => Signature::uncompress(signature_bytes),
+        VRF_SIGNATURE_UNCOMPRESSED_SIZE => Signature::deserialize(signature_bytes),
+        len => return Err(VRFError::InvalidLength { expected: VRF_SIGNATURE_SIZE, got: len }),
+    };
+    let sig = parsed.map_err(|e| point_error(e, VRFError::InvalidSignature))?;
+    let reencoded = if signature_bytes.len() == VRF_SIGNATURE_SIZE {
+        sig.compress().to_vec()
+    } else {
+        sig.serialize().to_vec()
+    };
+    if reencoded != signature_bytes {
+        return Err(VRFError::NonCanonical);
+    }
+    // blst's `verify(sig_groupcheck = true)` reports a failed group check as a
+    // plain BLST_VERIFY_FAIL, so the check runs here where it can be told apart
+    // from a pairing mismatch.
+    sig.validate(true)
+        .map_err(|e| point_error(e, VRFError::InvalidSignature))?;
+    Ok(sig)
+}
+
+/// Parses a public key, selecting the compressed or uncompressed parser from
+/// its length, and rejects non-canonical encodings, the identity, and points
+/// outside the prime-order subgroup (`VRFError::PublicKeyNotInSubgroup`).
+pub(super) fn parse_public_key(public_key_bytes: &[u8]) -> Result<PublicKey, VRFError> {
+    let parsed = match public_key_bytes.len() {
+        VRF_PUBLIC_KEY_SIZE => PublicKey::uncompress(public_key_bytes),
+        VRF_PUBLIC_KEY_UNCOMPRESSED_SIZE => PublicKey::deserialize(public_key_bytes),
+        len => return Err(VRFError::InvalidLength { expected: VRF_PUBLIC_KEY_SIZE, got: len }),
+    };
+    let pk = parsed.map_err(|e| point_error(e, VRFError::InvalidPublicKey))?;
+    let reencoded = if public_key_bytes.len() == VRF_PUBLIC_KEY_SIZE {
+        pk.compress().to_vec()
+    } else {
+        pk.serialize().to_vec()
+    };
+    if reencoded != public_key_bytes {
+        return Err(VRFError::NonCanonical);
+    }
+    pk.validate()
+        .map_err(|e| point_error(e, VRFError::InvalidPublicKey))?;
+    Ok(pk)
+}
+
+/// Maps a blst point decoding or validation error to a VRFError.
+///
+/// blst reports the identity for both keys and signatures as `BLST_PK_IS_INFINITY`.
+/// A failed subgroup check is reported for the point `fallback` names:
+/// `SignatureNotInSubgroup` for `InvalidSignature`, `PublicKeyNotInSubgroup` otherwise.
+fn point_error(err: BLST_ERROR, fallback: VRFError) -> VRFError {
+    match err {
+        BLST_ERROR::BLST_PK_IS_INFINITY => VRFError::IdentityPoint,
+        BLST_ERROR::BLST_POINT_NOT_IN_GROUP => match fallback {
+            VRFError::InvalidSignature => VRFError::SignatureNotInSubgroup,
+            _ => VRFError::PublicKeyNotInSubgroup,
+        },
+        _ => fallback,
+    }
+}
+
+/// Verifies a VRF proof against a known, published public key.
+///
+/// # Arguments
+/// * `public_key_bytes` - The signer's published public key.
+/// * `seed` - The input seed as a byte slice.
+/// * `signature_bytes` - The VRF proof (signature) as bytes.
+///
+/// # Returns
+/// * `Ok(())` if the proof is valid under the given key.
+/// * `Err(VRFError)` if verification fails.
+pub fn verify_with_key(
+    public_key_bytes: &[u8],
+    seed: &[u8],
+    signature_bytes: &[u8],
+) -> Result<(), VRFError> {
+    verify_proof(seed, signature_bytes, public_key_bytes)
+}
+
+/// Verifies proofs against one published public key, parsing it only once.
+///
+/// `verify_proof` decodes and subgroup-checks the public key on every call;
+/// a `Verifier` pays that cost at construction and reuses the parsed key for
+/// a stream of proofs from the same signer.
+pub struct Verifier {
+    public_key: PublicKey,
+}
+
+impl Verifier {
+    /// Parses and validates a public key for repeated verification.
+    ///
+    /// # Arguments
+    /// * `public_key_bytes` - The public key, compressed or uncompressed.
+    ///
+    /// # Returns
+    /// * `Ok(Verifier)` if the key is a valid subgroup point.
+    /// * `Err(VRFError)` if the key is malformed, the identity, or outside the subgroup.
+    pub fn new(public_key_bytes: &[u8]) -> Result<Verifier, VRFError> {
+        Ok(Verifier { public_key: parse_public_key(public_key_bytes)? })
+    }
+
+    /// Verifies a proof over `seed` under the cached key.
+    ///
+    /// # Arguments
+    /// * `seed` - The input seed as a byte slice.
+    /// * `signature_bytes` - The VRF proof (signature) as bytes.
+    ///
+    /// # Returns
+    /// * `Ok(())` if the proof is valid.
+    /// * `Err(VRFError)` if the proof is malformed or verification fails.
+    pub fn verify(&self, seed: &[u8], signature_bytes: &[u8]) -> Result<(), VRFError> {
+        let sig = parse_signature(signature_bytes)?;
+        match sig.verify(false, seed, VRF_DST, &[], &self.public_key, false) {
+            BLST_ERROR::BLST_SUCCESS => Ok(()),
+            _ => Err(VRFError::VerificationFailed),
+        }
+    }
+}
+
+/// Verifies proofs from many signers over one seed, hashing it to the curve only once.
+///
+/// `verify_proof` hashes the seed to the signature group on every call. When
+/// many signers prove over the same round seed, as in leader election, a
+/// `SeedContext` precomputes that message point and runs only the pairing
+/// check per proof. This is the fixed-seed counterpart to `Verifier`.
+pub struct SeedContext {
+    #[cfg(not(feature = "min-pk"))]
+    message: blst::blst_p1_affine,
+    #[cfg(feature = "min-pk")]
+    message: blst::blst_p2_affine,
+}
+
+impl SeedContext {
+    /// Hashes `seed` to the signature group under `dst` for repeated verification.
+    ///
+    /// # Arguments
+    /// * `seed` - The seed every signer proved over.
+    /// * `dst` - The domain separation tag, e.g. `VRF_DST`.
+    pub fn new(seed: &[u8], dst: &[u8]) -> SeedContext {
+        #[cfg(not(feature = "min-pk"))]
+        let message = hash_to_g1_affine(seed, dst);
+        #[cfg(feature = "min-pk")]
+        let message = hash_to_g2_affine(seed, dst);
+        SeedContext { message }
+    }
 
-    let result = sig.verify(true, seed, dst, &[], &pk, true);
-    if result == BLST_ERROR::BLST_SUCCESS {
+    /// Verifies a proof over the cached seed under `public_key_bytes`.
+    ///
+    /// # Arguments
+    /// * `signature_bytes` - The VRF proof (signature) as bytes.
+    /// * `public_key_bytes` - The signer's public key as bytes.
+    ///
+    /// # Returns
+    /// * `Ok(())` if the proof is valid.
+    /// * `Err(VRFError)` if either input is malformed or verification fails.
+    pub fn verify(&self, signature_bytes: &[u8], public_key_bytes: &[u8]) -> Result<(), VRFError> {
+        let pk = parse_public_key(public_key_bytes)?;
+        let sig = parse_signature(signature_bytes)?;
+
+        // Both points were subgroup- and identity-checked while parsing.
+        // SAFETY: blst returns a pointer to its static generator point.
+        #[cfg(not(feature = "min-pk"))]
+        let (lhs, rhs) = unsafe {
+            (
+                blst::blst_fp12::miller_loop((&pk).into(), &self.message),
+                blst::blst_fp12::miller_loop(&*blst::blst_p2_affine_generator(), (&sig).into()),
+            )
+        };
+        #[cfg(feature = "min-pk")]
+        let (lhs, rhs) = unsafe {
+            (
+                blst::blst_fp12::miller_loop(&self.message, (&pk).into()),
+                blst::blst_fp12::miller_loop((&sig).into(), &*blst::blst_p1_affine_generator()),
+            )
+        };
+
+        if blst::blst_fp12::finalverify(&lhs, &rhs) {
+            Ok(())
+        } else {
+            Err(VRFError::VerificationFailed)
+        }
+    }
+}
+
+/// Verifies a VRF proof and checks that the claimed output was derived from it.
+///
+/// # Arguments
+/// * `seed` - The input seed as a byte slice.
+/// * `output` - The claimed VRF output (32-byte hash).
+/// * `signature_bytes` - The VRF proof (signature) as bytes.
+/// * `public_key_bytes` - The public key as bytes.
+///
+/// # Returns
+/// * `Ok(())` if the proof is valid and the output matches `proof_to_hash`.
+/// * `Err(VRFError)` if verification fails.
+pub fn verify_output(
+    seed: &[u8],
+    output: &[u8],
+    signature_bytes: &[u8],
+    public_key_bytes: &[u8],
+) -> Result<(), VRFError> {
+    verify_proof(seed, signature_bytes, public_key_bytes)?;
+
+    if proof_to_hash(signature_bytes)?.as_slice() == output {
         Ok(())
     } else {
         Err(VRFError::VerificationFailed)
     }
 }
+
+/// Aggregates committee proofs over the same seed into one compressed signature.
+///
+/// Every member must sign the same seed under the same DST with their own
+/// keypair. The aggregate output is `proof_to_hash` of the returned signature,
+/// so no single member controls it unless they see all other proofs first;
+/// pair this with commit-reveal when that ordering matters.
+///
+/// # Arguments
+/// * `proofs` - The members' proofs.
+///
+/// # Returns
+/// * `Ok(Vec<u8>)` containing the compressed aggregate signature (`VRF_SIGNATURE_SIZE` bytes).
+/// * `Err(VRFError)` if `proofs` is empty or any proof fails to parse.
+pub fn aggregate_proofs(proofs: &[VRFProof]) -> Result<Vec<u8>, VRFError> {
+    let signatures = proofs
+        .iter()
+        .map(|p| parse_signature(&p.proof))
+        .collect::<Result<Vec<_>, _>>()?;
+    let refs: Vec<&Signature> = signatures.iter().collect();
+
+    // Points were already subgroup-checked by parse_signature.
+    let aggregate = AggregateSignature::aggregate(&refs, false)
+        .map_err(|_| VRFError::InvalidSignature)?;
+    Ok(aggregate.to_signature().compress().to_vec())
+}
+
+/// Verifies an aggregate proof with the fast-aggregate-verify pairing.
+///
+/// Aggregating public keys over a common message is vulnerable to rogue-key
+/// attacks: a member who picks their key as a function of the others' keys
+/// can forge the aggregate alone. Only pass keys whose owners have proven
+/// possession of the secret key, e.g. at committee registration. Duplicate
+/// keys are rejected.
+///
+/// # Arguments
+/// * `seed` - The seed every member signed.
+/// * `agg_sig` - The aggregate signature from `aggregate_proofs`.
+/// * `pubkeys` - The members' public keys, compressed or uncompressed.
+///
+/// # Returns
+/// * `Ok(())` if the aggregate is valid for all keys.
+/// * `Err(VRFError::DuplicateKey)` if a public key is declared more than once.
+/// * `Err(VRFError)` if a key is malformed or verification fails.
+pub fn verify_aggregate(seed: &[u8], agg_sig: &[u8], pubkeys: &[&[u8]]) -> Result<(), VRFError> {
+    if pubkeys.is_empty() {
+        return Err(VRFError::InvalidPublicKey);
+    }
+    let sig = parse_signature(agg_sig)?;
+    let keys = pubkeys
+        .iter()
+        .map(|pk| parse_public_key(pk))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut seen = BTreeSet::new();
+    if !keys.iter().all(|pk| seen.insert(pk.compress())) {
+        return Err(VRFError::DuplicateKey);
+    }
+
+    let refs: Vec<&PublicKey> = keys.iter().collect();
+    match sig.fast_aggregate_verify(false, seed, VRF_DST, &refs) {
+        BLST_ERROR::BLST_SUCCESS => Ok(()),
+        _ => Err(VRFError::VerificationFailed),
+    }
+}
+
+/// Verifies that every declared signer co-signed `seed`.
+///
+/// Unlike `verify_aggregate`, each signature is checked on its own, so a
+/// failure can be attributed to a specific party. Keys are compared as points,
+/// so the compressed and uncompressed encodings of one key count as the same.
+///
+/// # Arguments
+/// * `seed` - The seed every party signed.
+/// * `sigs` - One signature per party, aligned with `pubkeys`.
+/// * `pubkeys` - The parties' declared public keys.
+///
+/// # Returns
+/// * `Ok(())` if every signature is valid under its key and the keys are distinct.
+/// * `Err(VRFError::DuplicateKey)` if a public key is declared more than once.
+/// * `Err(VRFError::InvalidLength)` if `sigs` and `pubkeys` differ in length.
+/// * `Err(VRFError)` if any input is malformed or any signature fails to verify.
+pub fn verify_cosigned(seed: &[u8], sigs: &[&[u8]], pubkeys: &[&[u8]]) -> Result<(), VRFError> {
+    if pubkeys.is_empty() {
+        return Err(VRFError::InvalidPublicKey);
+    }
+    if sigs.len() != pubkeys.len() {
+        return Err(VRFError::InvalidLength { expected: pubkeys.len(), got: sigs.len() });
+    }
+    let keys = pubkeys
+        .iter()
+        .map(|pk| parse_public_key(pk))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut seen = BTreeSet::new();
+    if !keys.iter().all(|pk| seen.insert(pk.compress())) {
+        return Err(VRFError::DuplicateKey);
+    }
+
+    for (signature_bytes, pk) in sigs.iter().zip(&keys) {
+        let sig = parse_signature(signature_bytes)?;
+        if sig.verify(false, seed, VRF_DST, &[], pk, false) != BLST_ERROR::BLST_SUCCESS {
+            return Err(VRFError::VerificationFailed);
+        }
+    }
+    Ok(())
+}
+
+/// Checks whether two signatures over one seed prove that a signer equivocated.
+///
+/// Returns `true` only when both signatures verify against `public_key_bytes`
+/// and `seed` yet are different points. The signatures are compared as points,
+/// not bytes: the compressed and uncompressed encodings of one signature are
+/// both canonical, and anyone can re-encode a signature, so byte differences
+/// alone prove nothing about the signer. BLS signatures are unique, so an
+/// honest signer can never be caught; a `true` result means the signing key
+/// or the signing implementation has been subverted.
+///
+/// # Arguments
+/// * `seed` - The seed both signatures claim to sign.
+/// * `sig_a` - The first signature.
+/// * `sig_b` - The second signature.
+/// * `public_key_bytes` - The signer's public key.
+///
+/// # Returns
+/// * `Ok(true)` if both signatures are valid and distinct.
+/// * `Ok(false)` if either signature fails verification or both are the same point.
+/// * `Err(VRFError)` if a signature or the key is malformed.
+pub fn detect_equivocation(
+    seed: &[u8],
+    sig_a: &[u8],
+    sig_b: &[u8],
+    public_key_bytes: &[u8],
+) -> Result<bool, VRFError> {
+    let pk = parse_public_key(public_key_bytes)?;
+    let sig_a = parse_signature(sig_a)?;
+    let sig_b = parse_signature(sig_b)?;
+
+    let verifies = |sig: &Signature| {
+        sig.verify(false, seed, VRF_DST, &[], &pk, false) == BLST_ERROR::BLST_SUCCESS
+    };
+    Ok(verifies(&sig_a) && verifies(&sig_b) && sig_a != sig_b)
+}
+
+/// Verifies many VRF proofs with a single multi-pairing check.
+///
+/// Each entry is weighted by a fresh random scalar so that invalid proofs
+/// cannot cancel each other out. If the combined check fails, the batch is
+/// bisected to pinpoint the invalid entries. With the `rayon` feature the
+/// batch is split across rayon's global pool; see `verify_proofs_batch_in`.
+///
+/// # Arguments
+/// * `items` - `(seed, signature, public_key)` triples to verify.
+///
+/// # Returns
+/// * `Ok(Vec<bool>)` aligned with `items`; malformed entries are reported as `false`.
+/// * `Err(VRFError)` if the batch could not be processed.
+#[cfg(feature = "std")]
+pub fn verify_proofs_batch(items: &[(&[u8], &[u8], &[u8])]) -> Result<Vec<bool>, VRFError> {
+    #[cfg(feature = "rayon")]
+    {
+        verify_proofs_batch_in(items, None)
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        let (mut results, parsed) = parse_batch(items);
+        let mut valid = Vec::new();
+        verify_parsed_batch(&parsed, &mut valid);
+        for index in valid {
+            results[index] = true;
+        }
+        Ok(results)
+    }
+}
+
+/// Verifies many VRF proofs like `verify_proofs_batch`, splitting the batch
+/// into one chunk per worker of `pool`.
+///
+/// # Arguments
+/// * `items` - `(seed, signature, public_key)` triples to verify.
+/// * `pool` - The pool to run on, or `None` for rayon's global pool.
+///
+/// # Returns
+/// * `Ok(Vec<bool>)` aligned with `items`; malformed entries are reported as `false`.
+/// * `Err(VRFError)` if the batch could not be processed.
+#[cfg(feature = "rayon")]
+pub fn verify_proofs_batch_in(
+    items: &[(&[u8], &[u8], &[u8])],
+    pool: Option<&super::pool::VrfPool>,
+) -> Result<Vec<bool>, VRFError> {
+    use rayon::prelude::*;
+
+    let (mut results, parsed) = parse_batch(items);
+    let valid: Vec<usize> = super::pool::install(pool, || {
+        let chunk_size = parsed.len().div_ceil(rayon::current_num_threads()).max(1);
+        parsed
+            .par_chunks(chunk_size)
+            .flat_map_iter(|chunk| {
+                let mut valid = Vec::new();
+                verify_parsed_batch(chunk, &mut valid);
+                valid
+            })
+            .collect()
+    });
+    for index in valid {
+        results[index] = true;
+    }
+    Ok(results)
+}
+
+/// A batch entry that decoded: its position, seed, key, and signature.
+#[cfg(feature = "std")]
+type ParsedEntry<'a> = (usize, &'a [u8], PublicKey, Signature);
+
+/// Parses every entry of a batch, returning all-`false` results and the
+/// entries that decoded, tagged with their position.
+#[cfg(feature = "std")]
+fn parse_batch<'a>(
+    items: &[(&'a [u8], &[u8], &[u8])],
+) -> (Vec<bool>, Vec<ParsedEntry<'a>>) {
+    let results = vec![false; items.len()];
+    let mut parsed = Vec::with_capacity(items.len());
+
+    for (index, (seed, signature_bytes, public_key_bytes)) in items.iter().enumerate() {
+        let pk = match parse_public_key(public_key_bytes) {
+            Ok(pk) => pk,
+            Err(_) => continue,
+        };
+        let sig = match parse_signature(signature_bytes) {
+            Ok(sig) => sig,
+            Err(_) => continue,
+        };
+        parsed.push((index, *seed, pk, sig));
+    }
+    (results, parsed)
+}
+
+/// Verifies parsed entries, pushing the position of each valid one to `valid`.
+#[cfg(feature = "std")]
+fn verify_parsed_batch(entries: &[ParsedEntry], valid: &mut Vec<usize>) {
+    match entries {
+        [] => {}
+        [(index, seed, pk, sig)] => {
+            if sig.verify(false, seed, VRF_DST, &[], pk, false) == BLST_ERROR::BLST_SUCCESS {
+                valid.push(*index);
+            }
+        }
+        _ => {
+            let msgs: Vec<&[u8]> = entries.iter().map(|(_, seed, _, _)| *seed).collect();
+            let pks: Vec<&PublicKey> = entries.iter().map(|(_, _, pk, _)| pk).collect();
+            let sigs: Vec<&Signature> = entries.iter().map(|(_, _, _, sig)| sig).collect();
+            let rands: Vec<blst_scalar> = entries.iter().map(|_| random_scalar()).collect();
+
+            let result = Signature::verify_multiple_aggregate_signatures(
+                &msgs, VRF_DST, &pks, false, &sigs, false, &rands, BATCH_RAND_BITS,
+            );
+            if result == BLST_ERROR::BLST_SUCCESS {
+                valid.extend(entries.iter().map(|(index, _, _, _)| *index));
+            } else {
+                let (left, right) = entries.split_at(entries.len() / 2);
+                verify_parsed_batch(left, valid);
+                verify_parsed_batch(right, valid);
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+fn random_scalar() -> blst_scalar {
+    let mut scalar = blst_scalar::default();
+    OsRng.fill_bytes(&mut scalar.b[..BATCH_RAND_BITS / 8]);
+    scalar
+}
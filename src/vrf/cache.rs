@@ -0,0 +1,78 @@
+//! Bounded cache of verification results (`cache` feature).
+//!
+//! A gateway that re-verifies the same popular proofs pays for a pairing
+//! check each time. `VerificationCache` remembers the outcome of recent
+//! `verify_proof` calls so repeats cost three SHA256 digests and a lookup.
+
+use std::num::NonZeroUsize;
+use std::sync::Mutex;
+
+use lru::LruCache;
+
+use crate::utils::hash::sha256;
+use super::bls::verify_proof;
+
+/// Digests of the full seed, signature and public key bytes.
+///
+/// Each input is hashed separately, so no two distinct input triples share a
+/// key (short of a SHA256 collision), however their bytes are split.
+type CacheKey = ([u8; 32], [u8; 32], [u8; 32]);
+
+/// An LRU cache of `verify_proof` outcomes.
+///
+/// The cache holds at most `capacity` results and evicts the least recently
+/// used one when full. It is safe to share between threads; lookups take a
+/// short lock, and verification itself runs outside it.
+#[derive(Debug)]
+pub struct VerificationCache {
+    entries: Mutex<LruCache<CacheKey, bool>>,
+}
+
+impl VerificationCache {
+    /// Creates a cache holding up to `capacity` verification results.
+    pub fn new(capacity: NonZeroUsize) -> VerificationCache {
+        VerificationCache { entries: Mutex::new(LruCache::new(capacity)) }
+    }
+
+    /// Verifies a VRF proof, answering repeats of a previous input from the cache.
+    ///
+    /// Both valid and invalid outcomes are cached. A cached result is only
+    /// returned for the exact same seed, signature and public key bytes.
+    ///
+    /// # Arguments
+    /// * `seed` - The input seed as a byte slice.
+    /// * `signature_bytes` - The VRF proof (signature) as bytes.
+    /// * `public_key_bytes` - The public key as bytes.
+    ///
+    /// # Returns
+    /// * `true` if `verify_proof` accepts the proof, `false` otherwise.
+    pub fn cached_verify(&self, seed: &[u8], signature_bytes: &[u8], public_key_bytes: &[u8]) -> bool {
+        let key = (sha256(seed), sha256(signature_bytes), sha256(public_key_bytes));
+        if let Some(&valid) = self.lock().get(&key) {
+            return valid;
+        }
+        let valid = verify_proof(seed, signature_bytes, public_key_bytes).is_ok();
+        self.lock().put(key, valid);
+        valid
+    }
+
+    /// Returns the number of cached results.
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    /// Returns whether the cache is empty.
+    pub fn is_empty(&self) -> bool {
+        self.lock().is_empty()
+    }
+
+    /// Removes every cached result.
+    pub fn clear(&self) {
+        self.lock().clear();
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, LruCache<CacheKey, bool>> {
+        // The cache holds plain values, so a panic elsewhere cannot leave it inconsistent.
+        self.entries.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
@@ -1,6 +1,17 @@
+use hmac::{Hmac, Mac};
 use sha2::{Digest, Sha256};
+#[cfg(feature = "keccak")]
+use sha3::{Keccak256, Sha3_256};
+use subtle::ConstantTimeEq;
+use super::bls::verify_output;
+use super::scheme::VrfScheme;
+use super::types::VRFError;
 
-/// Generate a commit hash from the seed.
+/// Generate an unsalted commit hash from the seed.
+///
+/// Kept for backward compatibility. Prefer `commit_with_salt`, which matches
+/// the Soroban contract's `sha256(seed || salt)` scheme, or `commit_hmac` when
+/// the protocol may later append data to the committed preimage.
 ///
 /// # Arguments
 /// * `seed` - The input seed as a byte slice.
@@ -13,6 +24,9 @@ pub fn commit(seed: &[u8]) -> [u8; 32] {
 
 /// Verify that the given seed matches the commit hash.
 ///
+/// The comparison runs in constant time so that verification time does not
+/// reveal where a mismatching commitment first differs.
+///
 /// # Arguments
 /// * `seed` - The input seed as a byte slice.
 /// * `commitment` - The expected commitment hash.
@@ -20,5 +34,424 @@ pub fn commit(seed: &[u8]) -> [u8; 32] {
 /// # Returns
 /// * `bool` - True if the seed hashes to the commitment, false otherwise.
 pub fn verify_commit(seed: &[u8], commitment: &[u8; 32]) -> bool {
-    &commit(seed) == commitment
+    commit(seed).ct_eq(commitment).into()
+}
+
+/// Hash function used by `commit_with` and `verify_commit_with`.
+///
+/// `Keccak256` and `Sha3_256` need the `keccak` feature.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum CommitHash {
+    /// SHA-256, as used by `commit` and the Soroban contract (the default).
+    #[default]
+    Sha256,
+    /// Keccak-256, matching Solidity's `keccak256`.
+    #[cfg(feature = "keccak")]
+    Keccak256,
+    /// FIPS 202 SHA3-256. Note this differs from Keccak-256 in its padding.
+    #[cfg(feature = "keccak")]
+    Sha3_256,
+}
+
+/// Generate an unsalted commit hash from the seed with the given hash function.
+///
+/// # Arguments
+/// * `seed` - The input seed as a byte slice.
+/// * `algo` - The hash function to commit with.
+///
+/// # Returns
+/// * `[u8; 32]` - The hash of the seed.
+pub fn commit_with(seed: &[u8], algo: CommitHash) -> [u8; 32] {
+    match algo {
+        CommitHash::Sha256 => Sha256::digest(seed).into(),
+        #[cfg(feature = "keccak")]
+        CommitHash::Keccak256 => Keccak256::digest(seed).into(),
+        #[cfg(feature = "keccak")]
+        CommitHash::Sha3_256 => Sha3_256::digest(seed).into(),
+    }
+}
+
+/// Verify, in constant time, that the seed matches a commitment made with `algo`.
+///
+/// # Arguments
+/// * `seed` - The input seed as a byte slice.
+/// * `commitment` - The expected commitment hash.
+/// * `algo` - The hash function the commitment was made with.
+///
+/// # Returns
+/// * `bool` - True if the seed hashes to the commitment, false otherwise.
+pub fn verify_commit_with(seed: &[u8], commitment: &[u8; 32], algo: CommitHash) -> bool {
+    commit_with(seed, algo).ct_eq(commitment).into()
+}
+
+/// Generate a salted commit hash: `sha256(seed || salt)`.
+///
+/// This matches the commitment the Soroban contract computes on reveal.
+/// Plain SHA-256 is open to length extension: anyone who knows the commitment
+/// and the preimage length can compute `sha256(seed || salt || pad || suffix)`
+/// without knowing the seed. That is harmless for a one-shot reveal, but a
+/// protocol that hashes more data onto the preimage should use `commit_hmac`.
+///
+/// # Arguments
+/// * `seed` - The input seed as a byte slice.
+/// * `salt` - The salt as a byte slice.
+///
+/// # Returns
+/// * `[u8; 32]` - The SHA256 hash of the seed followed by the salt.
+pub fn commit_with_salt(seed: &[u8], salt: &[u8]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(seed);
+    hasher.update(salt);
+    hasher.finalize().into()
+}
+
+/// Verify that the given seed and salt match the salted commit hash.
+///
+/// # Arguments
+/// * `seed` - The input seed as a byte slice.
+/// * `salt` - The salt as a byte slice.
+/// * `commitment` - The expected commitment hash.
+///
+/// # Returns
+/// * `bool` - True if `sha256(seed || salt)` equals the commitment, false otherwise.
+pub fn verify_commit_with_salt(seed: &[u8], salt: &[u8], commitment: &[u8; 32]) -> bool {
+    commit_with_salt(seed, salt).ct_eq(commitment).into()
+}
+
+/// Version byte at the start of a `commit_v2` commitment.
+///
+/// Unversioned 32-byte `commit_with_salt` commitments are format 1, so the
+/// first versioned format is 2. A future change to the hash or layout gets a
+/// new version rather than silently producing incompatible commitments.
+pub const COMMITMENT_FORMAT_VERSION: u8 = 2;
+
+/// Generate a versioned salted commitment: `version || sha256(seed || salt)`.
+///
+/// The 32 bytes after the version are the `commit_with_salt` hash, so the
+/// Soroban contract's check still applies once the version byte is stripped
+/// with `parse_commitment_v2`.
+///
+/// # Arguments
+/// * `seed` - The input seed as a byte slice.
+/// * `salt` - The salt as a byte slice.
+///
+/// # Returns
+/// * `[u8; 33]` - `COMMITMENT_FORMAT_VERSION` followed by the salted hash.
+pub fn commit_v2(seed: &[u8], salt: &[u8]) -> [u8; 33] {
+    let mut commitment = [0u8; 33];
+    commitment[0] = COMMITMENT_FORMAT_VERSION;
+    commitment[1..].copy_from_slice(&commit_with_salt(seed, salt));
+    commitment
+}
+
+/// Parses a `commit_v2` commitment and returns its 32-byte hash.
+///
+/// # Arguments
+/// * `commitment` - The versioned commitment bytes.
+///
+/// # Returns
+/// * `Ok([u8; 32])` - The salted hash.
+/// * `Err(VRFError::InvalidLength)` if the commitment is not 33 bytes, e.g. an unversioned one.
+/// * `Err(VRFError::UnsupportedVersion)` if the version byte is not `COMMITMENT_FORMAT_VERSION`.
+pub fn parse_commitment_v2(commitment: &[u8]) -> Result<[u8; 32], VRFError> {
+    if commitment.len() != 33 {
+        return Err(VRFError::InvalidLength { expected: 33, got: commitment.len() });
+    }
+    if commitment[0] != COMMITMENT_FORMAT_VERSION {
+        return Err(VRFError::UnsupportedVersion { version: commitment[0] });
+    }
+    Ok(commitment[1..].try_into().expect("33-byte commitment has a 32-byte hash"))
+}
+
+/// Verify a seed and salt against a `commit_v2` commitment.
+///
+/// # Arguments
+/// * `seed` - The input seed as a byte slice.
+/// * `salt` - The salt as a byte slice.
+/// * `commitment` - The versioned commitment bytes.
+///
+/// # Returns
+/// * `Ok(bool)` - Whether the seed and salt open the commitment.
+/// * `Err(VRFError)` if the commitment does not parse, as for `parse_commitment_v2`.
+pub fn verify_commit_v2(seed: &[u8], salt: &[u8], commitment: &[u8]) -> Result<bool, VRFError> {
+    Ok(verify_commit_with_salt(seed, salt, &parse_commitment_v2(commitment)?))
+}
+
+/// Generate a commitment bound to a context: `sha256(context || seed)`.
+///
+/// Binding the commitment to its submitter and round stops a front-runner
+/// from copying someone else's commitment and later revealing it as their
+/// own: the copied value only opens under the original context. Use a
+/// fixed-length context encoding (or length-prefix variable fields) so that
+/// no two contexts can be concatenated into the same prefix.
+///
+/// For the Soroban `commit(user, commitment, pubkey)` call, a natural context
+/// is the 32-byte account ID of `user` followed by the round ID as a
+/// big-endian `u64`. The contract's reveal step recomputes `sha256(seed || salt)`,
+/// so context-bound commitments must be checked with `verify_commit_with_context`
+/// off-chain, or by a contract that recomputes `sha256(context || seed)`.
+///
+/// # Arguments
+/// * `seed` - The input seed as a byte slice.
+/// * `context` - The submitter and round the commitment is bound to.
+///
+/// # Returns
+/// * `[u8; 32]` - The SHA256 hash of the context followed by the seed.
+pub fn commit_with_context(seed: &[u8], context: &[u8]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(context);
+    hasher.update(seed);
+    hasher.finalize().into()
+}
+
+/// Verify that the given seed opens a context-bound commitment.
+///
+/// # Arguments
+/// * `seed` - The input seed as a byte slice.
+/// * `context` - The context the commitment was made under.
+/// * `commitment` - The expected commitment hash.
+///
+/// # Returns
+/// * `bool` - True if `sha256(context || seed)` equals the commitment, false otherwise.
+pub fn verify_commit_with_context(seed: &[u8], context: &[u8], commitment: &[u8; 32]) -> bool {
+    commit_with_context(seed, context).ct_eq(commitment).into()
+}
+
+/// Generate a keyed commitment: `HMAC-SHA256(key, seed)`.
+///
+/// Unlike the bare SHA-256 commitments, HMAC is not open to length extension,
+/// so a commitment cannot be extended to cover appended data without the key.
+/// Prefer it whenever commitments are checked off-chain and the protocol may
+/// hash further data onto the preimage. The Soroban contract recomputes
+/// `sha256(seed || salt)`, so on-chain reveals still need `commit_with_salt`.
+///
+/// # Arguments
+/// * `key` - The HMAC key, e.g. a per-round secret or the salt.
+/// * `seed` - The input seed as a byte slice.
+///
+/// # Returns
+/// * `[u8; 32]` - The HMAC-SHA256 tag of the seed under `key`.
+pub fn commit_hmac(key: &[u8], seed: &[u8]) -> [u8; 32] {
+    let mut mac = Hmac::<Sha256>::new_from_slice(key).expect("HMAC accepts keys of any length");
+    mac.update(seed);
+    mac.finalize().into_bytes().into()
+}
+
+/// Verify, in constant time, that the seed opens a keyed commitment.
+///
+/// # Arguments
+/// * `key` - The HMAC key the commitment was made with.
+/// * `seed` - The input seed as a byte slice.
+/// * `commitment` - The expected commitment.
+///
+/// # Returns
+/// * `bool` - True if `HMAC-SHA256(key, seed)` equals the commitment, false otherwise.
+pub fn verify_commit_hmac(key: &[u8], seed: &[u8], commitment: &[u8; 32]) -> bool {
+    commit_hmac(key, seed).ct_eq(commitment).into()
+}
+
+/// Domain tag prefixed to timed commitments so they never collide with the
+/// plain salted scheme.
+const TIMED_COMMIT_TAG: &[u8] = b"NEBULA-VRF-TIMED-COMMIT-V01";
+
+/// A salted commitment that must be opened by a deadline.
+///
+/// The deadline is part of the hash preimage, so changing `not_after` after
+/// the fact makes the commitment fail to open.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimedCommitment {
+    /// `sha256(tag || not_after || seed || salt)`, with `not_after` as a big-endian `u64`.
+    pub commitment: [u8; 32],
+    /// The last instant (in the caller's time unit, e.g. Unix seconds or ledger sequence) at which the reveal is accepted.
+    pub not_after: u64,
+}
+
+/// Generate a commitment that binds the seed, salt, and an open-by deadline.
+///
+/// # Arguments
+/// * `seed` - The input seed as a byte slice.
+/// * `salt` - The salt as a byte slice.
+/// * `not_after` - The reveal deadline, inclusive.
+///
+/// # Returns
+/// * `TimedCommitment` - The commitment and its deadline.
+pub fn commit_timed(seed: &[u8], salt: &[u8], not_after: u64) -> TimedCommitment {
+    TimedCommitment { commitment: timed_hash(seed, salt, not_after), not_after }
+}
+
+fn timed_hash(seed: &[u8], salt: &[u8], not_after: u64) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(TIMED_COMMIT_TAG);
+    hasher.update(not_after.to_be_bytes());
+    hasher.update(seed);
+    hasher.update(salt);
+    hasher.finalize().into()
+}
+
+/// Verify that a timed commitment is opened on time by the given seed and salt.
+///
+/// # Arguments
+/// * `tc` - The timed commitment.
+/// * `seed` - The revealed seed.
+/// * `salt` - The revealed salt.
+/// * `now` - The current time, in the same unit as `tc.not_after`.
+///
+/// # Returns
+/// * `Ok(())` if `now <= tc.not_after` and the seed and salt open the commitment.
+/// * `Err(VRFError::Expired)` if the deadline has passed.
+/// * `Err(VRFError::InvalidCommitment)` if the seed, salt, or deadline do not match.
+pub fn verify_timed_reveal(
+    tc: &TimedCommitment,
+    seed: &[u8],
+    salt: &[u8],
+    now: u64,
+) -> Result<(), VRFError> {
+    if now > tc.not_after {
+        return Err(VRFError::Expired { not_after: tc.not_after, now });
+    }
+    if !bool::from(timed_hash(seed, salt, tc.not_after).ct_eq(&tc.commitment)) {
+        return Err(VRFError::InvalidCommitment);
+    }
+    Ok(())
+}
+
+/// Verify a full reveal: the seed and salt open the commitment, and the VRF
+/// proof over the seed is valid and yields the claimed output.
+///
+/// This mirrors the on-chain reveal step, so callers cannot forget either check.
+/// The commitment is checked first, and every proof failure, including a
+/// malformed proof or key, is reported as `VerificationFailed`, so callers can
+/// tell a wrong seed or salt apart from a bad VRF signature.
+///
+/// # Arguments
+/// * `commitment` - The previously committed `sha256(seed || salt)`.
+/// * `seed` - The revealed seed.
+/// * `salt` - The revealed salt.
+/// * `output` - The claimed VRF output.
+/// * `proof` - The VRF proof (signature) over the seed.
+/// * `public_key` - The public key the proof verifies against.
+///
+/// # Returns
+/// * `Ok(())` if both checks pass.
+/// * `Err(VRFError::InvalidCommitment)` if the seed and salt do not match the commitment.
+/// * `Err(VRFError::VerificationFailed)` if the commitment matches but the proof, key, or output does not.
+pub fn verify_reveal(
+    commitment: &[u8; 32],
+    seed: &[u8],
+    salt: &[u8],
+    output: &[u8],
+    proof: &[u8],
+    public_key: &[u8],
+) -> Result<(), VRFError> {
+    if !verify_commit_with_salt(seed, salt, commitment) {
+        return Err(VRFError::InvalidCommitment);
+    }
+    verify_output(seed, output, proof, public_key).map_err(|_| VRFError::VerificationFailed)
+}
+
+/// Generate a salted commitment that also binds the signer's public key:
+/// `sha256(seed || salt || pubkey)`.
+///
+/// This mirrors the Soroban contract's `commit(user, commitment, pubkey)`,
+/// where the key is fixed at commit time. With a plain salted commitment a
+/// revealer could prove the same seed under whichever key suits them; here
+/// the commitment only opens with the committed key.
+///
+/// # Arguments
+/// * `seed` - The input seed as a byte slice.
+/// * `salt` - The salt as a byte slice.
+/// * `pubkey` - The VRF public key that will prove over the seed.
+///
+/// # Returns
+/// * `[u8; 32]` - The SHA256 hash of the seed, salt and public key.
+pub fn commit_bound(seed: &[u8], salt: &[u8], pubkey: &[u8]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(seed);
+    hasher.update(salt);
+    hasher.update(pubkey);
+    hasher.finalize().into()
+}
+
+/// Verify that the seed, salt and public key match a `commit_bound` commitment.
+///
+/// # Arguments
+/// * `seed` - The input seed as a byte slice.
+/// * `salt` - The salt as a byte slice.
+/// * `pubkey` - The committed public key.
+/// * `commitment` - The expected commitment hash.
+///
+/// # Returns
+/// * `bool` - True if `sha256(seed || salt || pubkey)` equals the commitment, false otherwise.
+pub fn verify_commit_bound(seed: &[u8], salt: &[u8], pubkey: &[u8], commitment: &[u8; 32]) -> bool {
+    commit_bound(seed, salt, pubkey).ct_eq(commitment).into()
+}
+
+/// Verify a full reveal against a `commit_bound` commitment.
+///
+/// Like `verify_reveal`, but the commitment also covers the public key, so a
+/// reveal that proves under a different key than the committed one fails the
+/// commitment check before any VRF work is done.
+///
+/// # Arguments
+/// * `commitment` - The previously committed `sha256(seed || salt || pubkey)`.
+/// * `seed` - The revealed seed.
+/// * `salt` - The revealed salt.
+/// * `output` - The claimed VRF output.
+/// * `proof` - The VRF proof (signature) over the seed.
+/// * `public_key` - The public key the proof verifies against.
+///
+/// # Returns
+/// * `Ok(())` if both checks pass.
+/// * `Err(VRFError::InvalidCommitment)` if the seed, salt, or public key do not match the commitment.
+/// * `Err(VRFError::VerificationFailed)` if the commitment matches but the proof or output does not.
+pub fn verify_reveal_bound(
+    commitment: &[u8; 32],
+    seed: &[u8],
+    salt: &[u8],
+    output: &[u8],
+    proof: &[u8],
+    public_key: &[u8],
+) -> Result<(), VRFError> {
+    if !verify_commit_bound(seed, salt, public_key, commitment) {
+        return Err(VRFError::InvalidCommitment);
+    }
+    verify_output(seed, output, proof, public_key).map_err(|_| VRFError::VerificationFailed)
+}
+
+/// Verify a full reveal under any `VrfScheme` backend.
+///
+/// Behaves like `verify_reveal`, which runs the same checks for `BlsVrf` on
+/// borrowed slices: the commitment is checked first, then the proof, and
+/// every proof-side failure is reported as `VerificationFailed`.
+///
+/// # Arguments
+/// * `commitment` - The previously committed `sha256(seed || salt)`.
+/// * `seed` - The revealed seed.
+/// * `salt` - The revealed salt.
+/// * `output` - The claimed VRF output.
+/// * `proof` - The backend's proof over the seed.
+/// * `public_key` - The backend's public key.
+///
+/// # Returns
+/// * `Ok(())` if both checks pass.
+/// * `Err(VRFError::InvalidCommitment)` if the seed and salt do not match the commitment.
+/// * `Err(VRFError::VerificationFailed)` if the commitment matches but the proof, key, or output does not.
+pub fn verify_reveal_with<S: VrfScheme>(
+    commitment: &[u8; 32],
+    seed: &[u8],
+    salt: &[u8],
+    output: &[u8],
+    proof: &S::Proof,
+    public_key: &S::PublicKey,
+) -> Result<(), VRFError> {
+    if !verify_commit_with_salt(seed, salt, commitment) {
+        return Err(VRFError::InvalidCommitment);
+    }
+    let derived = S::verify(public_key, seed, proof).map_err(|_| VRFError::VerificationFailed)?;
+    if bool::from(derived.as_ref().ct_eq(output)) {
+        Ok(())
+    } else {
+        Err(VRFError::VerificationFailed)
+    }
 }
@@ -0,0 +1,117 @@
+//! EIP-2537 point encoding, for verifying proofs with Ethereum's BLS12-381 precompiles.
+//!
+//! The precompiles take uncompressed points with every base field element
+//! left-padded from 48 to 64 big-endian bytes: 128 bytes for a G1 point and
+//! 256 bytes for a G2 point. They also order the two halves of an `Fp2`
+//! coordinate as `c0 || c1`, while blst (following the ZCash format) writes
+//! `c1 || c0`. The identity is all zeros, and is rejected here as everywhere
+//! else in the crate.
+
+use alloc::vec::Vec;
+
+use super::bls::{parse_public_key, parse_signature};
+use super::types::VRFError;
+
+/// Size of a base field element in blst's encoding.
+const FP_SIZE: usize = 48;
+
+/// Size of a padded base field element in the EIP-2537 encoding.
+const PADDED_FP_SIZE: usize = 64;
+
+/// Size of an EIP-2537 G1 point.
+pub const EIP2537_G1_POINT_SIZE: usize = 2 * PADDED_FP_SIZE;
+
+/// Size of an EIP-2537 G2 point.
+pub const EIP2537_G2_POINT_SIZE: usize = 4 * PADDED_FP_SIZE;
+
+/// Size of an EIP-2537 VRF signature (G1 under min-sig, G2 under min-pk).
+#[cfg(not(feature = "min-pk"))]
+pub const EIP2537_SIGNATURE_SIZE: usize = EIP2537_G1_POINT_SIZE;
+#[cfg(feature = "min-pk")]
+pub const EIP2537_SIGNATURE_SIZE: usize = EIP2537_G2_POINT_SIZE;
+
+/// Size of an EIP-2537 VRF public key (G2 under min-sig, G1 under min-pk).
+#[cfg(not(feature = "min-pk"))]
+pub const EIP2537_PUBLIC_KEY_SIZE: usize = EIP2537_G2_POINT_SIZE;
+#[cfg(feature = "min-pk")]
+pub const EIP2537_PUBLIC_KEY_SIZE: usize = EIP2537_G1_POINT_SIZE;
+
+/// Converts a public key and signature to their EIP-2537 encodings.
+///
+/// Either blst encoding is accepted, and both points are validated as in
+/// `verify_proof` first, so only well-formed points reach a contract.
+///
+/// # Arguments
+/// * `public_key_bytes` - The public key as bytes.
+/// * `signature_bytes` - The VRF proof (signature) as bytes.
+///
+/// # Returns
+/// * `Ok((public_key, signature))` - The padded big-endian encodings.
+/// * `Err(VRFError)` if either point fails to parse.
+pub fn to_eip2537(public_key_bytes: &[u8], signature_bytes: &[u8]) -> Result<(Vec<u8>, Vec<u8>), VRFError> {
+    let public_key = parse_public_key(public_key_bytes)?;
+    let signature = parse_signature(signature_bytes)?;
+    Ok((pad_point(&public_key.serialize()), pad_point(&signature.serialize())))
+}
+
+/// Parses an EIP-2537 public key and signature back to compressed blst bytes.
+///
+/// # Arguments
+/// * `public_key` - The EIP-2537 public key.
+/// * `signature` - The EIP-2537 signature.
+///
+/// # Returns
+/// * `Ok((public_key, signature))` - The compressed blst encodings, as `generate_random` emits.
+/// * `Err(VRFError::InvalidLength)` if a point has the wrong size for the selected scheme.
+/// * `Err(VRFError::NonCanonical)` if a padding byte is not zero.
+/// * `Err(VRFError::IdentityPoint)` if a point is the identity.
+/// * `Err(VRFError)` if a point fails any other check in `verify_proof`.
+pub fn from_eip2537(public_key: &[u8], signature: &[u8]) -> Result<(Vec<u8>, Vec<u8>), VRFError> {
+    let public_key = unpad_point(public_key, EIP2537_PUBLIC_KEY_SIZE)?;
+    let signature = unpad_point(signature, EIP2537_SIGNATURE_SIZE)?;
+    Ok((
+        parse_public_key(&public_key)?.compress().to_vec(),
+        parse_signature(&signature)?.compress().to_vec(),
+    ))
+}
+
+/// Pads each field element of a blst uncompressed point to 64 bytes,
+/// swapping the `Fp2` halves of a G2 point into `c0 || c1` order.
+fn pad_point(uncompressed: &[u8]) -> Vec<u8> {
+    let mut elements: Vec<&[u8]> = uncompressed.chunks(FP_SIZE).collect();
+    if elements.len() == 4 {
+        elements.swap(0, 1);
+        elements.swap(2, 3);
+    }
+    let mut padded = Vec::with_capacity(elements.len() * PADDED_FP_SIZE);
+    for element in elements {
+        padded.extend_from_slice(&[0u8; PADDED_FP_SIZE - FP_SIZE]);
+        padded.extend_from_slice(element);
+    }
+    padded
+}
+
+/// Reverses `pad_point`, checking the length, then that the padding is zero,
+/// then for the all-zero identity encoding.
+fn unpad_point(padded: &[u8], expected: usize) -> Result<Vec<u8>, VRFError> {
+    if padded.len() != expected {
+        return Err(VRFError::InvalidLength { expected, got: padded.len() });
+    }
+    let mut elements = Vec::with_capacity(padded.len() / PADDED_FP_SIZE);
+    for element in padded.chunks(PADDED_FP_SIZE) {
+        let (padding, value) = element.split_at(PADDED_FP_SIZE - FP_SIZE);
+        if padding.iter().any(|&b| b != 0) {
+            return Err(VRFError::NonCanonical);
+        }
+        elements.push(value);
+    }
+    // blst would read all zeros as an off-curve point, not the identity.
+    if elements.iter().all(|element| element.iter().all(|&b| b == 0)) {
+        return Err(VRFError::IdentityPoint);
+    }
+    if elements.len() == 4 {
+        elements.swap(0, 1);
+        elements.swap(2, 3);
+    }
+    Ok(elements.concat())
+}
@@ -0,0 +1,72 @@
+//! Leader election and committee ranking by lowest VRF output.
+//!
+//! Every participant proves over the same round seed and the participant whose
+//! output is the smallest big-endian integer wins. Outputs over different seeds
+//! are not comparable, and proofs must be verified (e.g. with `verify_output`)
+//! before they are ranked: this module only orders the outputs it is given.
+
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use super::types::VRFProof;
+
+/// Compares two VRF outputs as unsigned big-endian integers.
+///
+/// Leading zero bytes are ignored, so outputs of different lengths compare by
+/// numeric value. For the usual 32-byte outputs this is a byte-wise comparison.
+///
+/// # Arguments
+/// * `a` - The first output.
+/// * `b` - The second output.
+///
+/// # Returns
+/// * `Ordering` - How `a` compares to `b`.
+pub fn compare_outputs(a: &[u8], b: &[u8]) -> Ordering {
+    let (a, b) = (strip_leading_zeros(a), strip_leading_zeros(b));
+    a.len().cmp(&b.len()).then_with(|| a.cmp(b))
+}
+
+fn strip_leading_zeros(bytes: &[u8]) -> &[u8] {
+    let start = bytes.iter().position(|&byte| byte != 0).unwrap_or(bytes.len());
+    &bytes[start..]
+}
+
+/// Orders two participants by output, then by id.
+fn rank_order<I: Ord>((id_a, a): &(I, VRFProof), (id_b, b): &(I, VRFProof)) -> Ordering {
+    compare_outputs(&a.output, &b.output).then_with(|| id_a.cmp(id_b))
+}
+
+/// Returns the participant whose proof has the lowest output.
+///
+/// All proofs must be over the same round seed and already verified. Equal
+/// outputs, which are astronomically unlikely, are broken by the lower id.
+///
+/// # Arguments
+/// * `proofs` - Each participant's id and VRF proof.
+///
+/// # Returns
+/// * `Some(id)` of the winner.
+/// * `None` if `proofs` is empty.
+pub fn elect_leader<I: Ord + Clone>(proofs: &[(I, VRFProof)]) -> Option<I> {
+    proofs
+        .iter()
+        .min_by(|a, b| rank_order(a, b))
+        .map(|(id, _)| id.clone())
+}
+
+/// Returns every participant ordered from lowest to highest output.
+///
+/// This is the full ordering behind `elect_leader`, whose winner is the first
+/// entry, so the first `k` ids form a committee of size `k`. The same rules
+/// apply: all proofs over one round seed and already verified, and equal
+/// outputs broken by the lower id, so the order is deterministic.
+///
+/// # Arguments
+/// * `proofs` - Each participant's id and VRF proof.
+///
+/// # Returns
+/// * `Vec<id>` - The ids in ascending output order; empty if `proofs` is.
+pub fn rank_participants<I: Ord + Clone>(proofs: &[(I, VRFProof)]) -> Vec<I> {
+    let mut ranked: Vec<&(I, VRFProof)> = proofs.iter().collect();
+    ranked.sort_by(|a, b| rank_order(a, b));
+    ranked.into_iter().map(|(id, _)| id.clone()).collect()
+}
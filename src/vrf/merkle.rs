@@ -0,0 +1,148 @@
+//! Merkle-tree commitments to many seeds under a single root.
+//!
+//! Leaves are `sha256(0x00 || commit(seed))` and internal nodes are
+//! `sha256(0x01 || left || right)`, so a leaf can never be opened as an
+//! internal node or the other way round. When a layer has an odd number of
+//! nodes, the last node is paired with a copy of itself. As a consequence a
+//! list whose length is odd at some layer shares its root with the list that
+//! repeats that last seed, so `verify` takes the committed seed count and
+//! rejects indices and path lengths that do not fit it.
+
+use alloc::vec;
+use alloc::vec::Vec;
+use sha2::{Digest, Sha256};
+use subtle::ConstantTimeEq;
+use super::commit::commit;
+
+/// Authentication path for one leaf, ordered from the leaf layer upwards.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MerkleProof {
+    /// Sibling hashes needed to recompute the root.
+    pub siblings: Vec<[u8; 32]>,
+}
+
+/// A Merkle tree over a list of seeds.
+#[derive(Debug, Clone)]
+pub struct MerkleCommitment {
+    /// Every layer of the tree, from the leaves up to the single root.
+    layers: Vec<Vec<[u8; 32]>>,
+    /// Number of committed seeds.
+    leaf_count: usize,
+}
+
+/// Domain prefix for leaf hashes.
+const LEAF_PREFIX: u8 = 0x00;
+
+/// Domain prefix for internal node hashes.
+const NODE_PREFIX: u8 = 0x01;
+
+fn hash_leaf(seed: &[u8]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update([LEAF_PREFIX]);
+    hasher.update(commit(seed));
+    hasher.finalize().into()
+}
+
+fn hash_pair(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update([NODE_PREFIX]);
+    hasher.update(left);
+    hasher.update(right);
+    hasher.finalize().into()
+}
+
+impl MerkleCommitment {
+    /// Builds the tree over `seeds`.
+    ///
+    /// An empty list yields an all-zero root that no proof can open.
+    ///
+    /// # Arguments
+    /// * `seeds` - The seeds to commit to, in leaf order.
+    ///
+    /// # Returns
+    /// * `MerkleCommitment` - The tree, whose `root` is the value to publish.
+    pub fn build(seeds: &[&[u8]]) -> MerkleCommitment {
+        if seeds.is_empty() {
+            return MerkleCommitment { layers: vec![vec![[0u8; 32]]], leaf_count: 0 };
+        }
+
+        let mut layers = vec![seeds.iter().map(|seed| hash_leaf(seed)).collect::<Vec<_>>()];
+        while layers[layers.len() - 1].len() > 1 {
+            let next = layers[layers.len() - 1]
+                .chunks(2)
+                .map(|pair| hash_pair(&pair[0], pair.get(1).unwrap_or(&pair[0])))
+                .collect();
+            layers.push(next);
+        }
+        MerkleCommitment { layers, leaf_count: seeds.len() }
+    }
+
+    /// Returns the root hash committing to every seed.
+    pub fn root(&self) -> [u8; 32] {
+        self.layers[self.layers.len() - 1][0]
+    }
+
+    /// Returns the number of committed seeds.
+    pub fn len(&self) -> usize {
+        self.leaf_count
+    }
+
+    /// Returns true if no seeds were committed.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Produces the authentication path for the seed at `index`.
+    ///
+    /// # Panics
+    /// Panics if `index` is not less than the number of committed seeds.
+    pub fn open(&self, index: usize) -> MerkleProof {
+        assert!(index < self.len(), "leaf index {} out of range", index);
+
+        let mut position = index;
+        let siblings = self.layers[..self.layers.len() - 1]
+            .iter()
+            .map(|layer| {
+                let sibling = layer.get(position ^ 1).unwrap_or(&layer[position]);
+                position /= 2;
+                *sibling
+            })
+            .collect();
+        MerkleProof { siblings }
+    }
+}
+
+/// Returns the path length for a tree over `leaf_count` seeds, `ceil(log2(leaf_count))`.
+fn path_len(leaf_count: usize) -> usize {
+    (usize::BITS - (leaf_count - 1).leading_zeros()) as usize
+}
+
+/// Verifies that `seed` is the leaf at `index` of a tree over `leaf_count` seeds under `root`.
+///
+/// # Arguments
+/// * `root` - The published Merkle root.
+/// * `seed` - The revealed seed.
+/// * `proof` - The authentication path from `MerkleCommitment::open`.
+/// * `index` - The leaf position the seed claims.
+/// * `leaf_count` - The number of committed seeds, published with the root.
+///
+/// # Returns
+/// * `bool` - True if `index` is below `leaf_count`, the path has the length
+///   the tree implies, and it recomputes the root; false otherwise.
+pub fn verify(root: &[u8; 32], seed: &[u8], proof: &MerkleProof, index: usize, leaf_count: usize) -> bool {
+    if index >= leaf_count || proof.siblings.len() != path_len(leaf_count) {
+        return false;
+    }
+
+    let mut node = hash_leaf(seed);
+    let mut position = index;
+    for sibling in &proof.siblings {
+        node = if position & 1 == 0 {
+            hash_pair(&node, sibling)
+        } else {
+            hash_pair(sibling, &node)
+        };
+        position /= 2;
+    }
+    node.ct_eq(root).into()
+}
@@ -1,8 +1,37 @@
-//! VRF module: BLS-based VRF, commit-reveal, and error types.
+//! VRF module: BLS-based VRF, verification caching, commit-reveal, commit-reveal rounds, Merkle commitments, leader election, output derivation, output statistics, swappable VRF backends, typed inputs, EIP-2537 point encoding, and error types.
 
 pub mod bls;
+#[cfg(feature = "cache")]
+pub mod cache;
 pub mod commit;
+pub mod eip2537;
+pub mod election;
+pub mod merkle;
+pub mod output;
+#[cfg(feature = "rayon")]
+pub mod pool;
+pub mod round;
+pub mod sample;
+pub mod scheme;
+#[cfg(feature = "stats")]
+pub mod stats;
+pub mod typed;
 pub mod types;
 
-pub use bls::{generate_random, verify_proof};
-pub use types::{VRFProof, VRFError};
+pub use bls::{
+    aggregate_proofs, bound_seed, derive_keypair, detect_equivocation, generate_random, generate_random_checked,
+    generate_random_bound, generate_random_from_hasher, generate_random_with_dst, generate_random_with_mode, generate_random_with_personalization,
+    generate_random_with_encoding, hash_seed_to_g1, hash_seed_to_g2, proof_to_hash,
+    verify_aggregate, verify_and_extract, verify_cosigned, verify_output, verify_proof, verify_proof_bound, verify_proof_unchecked, verify_proof_with_dst, verify_proof_with_mode, verify_with_key, SeedContext, Verifier, VrfKeypair, VRF_DST, VRF_IKM_TAG,
+    VRF_PUBLIC_KEY_SIZE, VRF_PUBLIC_KEY_UNCOMPRESSED_SIZE, VRF_SIGNATURE_SIZE,
+    VRF_SIGNATURE_UNCOMPRESSED_SIZE,
+};
+#[cfg(feature = "std")]
+pub use bls::verify_proofs_batch;
+#[cfg(feature = "rayon")]
+pub use bls::verify_proofs_batch_in;
+#[cfg(feature = "rayon")]
+pub use pool::VrfPool;
+pub use output::{derive_child, derive_child_from_output, expand_output};
+pub use scheme::{BlsVrf, VrfScheme};
+pub use types::{Encoding, MessageMode, VRFProof, VRFError, VRF_PROOF_FORMAT_VERSION};
@@ -0,0 +1,72 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use sha3::digest::{ExtendableOutput, Update, XofReader};
+use hkdf::Hkdf;
+use sha2::Sha256;
+use sha3::Shake256;
+use super::types::{VRFError, VRFProof};
+
+/// Domain separation tag for expanding a VRF output with SHAKE256.
+const EXPAND_DST: &[u8] = b"NEBULA-VRF-EXPAND-V01";
+
+/// HKDF salt separating child derivation from other uses of the output.
+const CHILD_SALT: &[u8] = b"NEBULA-VRF-CHILD-V01";
+
+/// Expands the VRF output into an arbitrary number of bytes.
+///
+/// The 32-byte proof-derived output is fed through SHAKE256, so the result is
+/// still bound to the proof. The same proof always yields the same bytes, and
+/// a shorter expansion is a prefix of a longer one.
+///
+/// # Arguments
+/// * `proof` - The VRF proof whose output is expanded.
+/// * `length` - The number of bytes to produce.
+///
+/// # Returns
+/// * `Vec<u8>` - `length` deterministic pseudorandom bytes.
+pub fn expand_output(proof: &VRFProof, length: usize) -> Vec<u8> {
+    let mut expanded = vec![0u8; length];
+    output_stream(proof).read(&mut expanded);
+    expanded
+}
+
+/// Returns an unbounded reader over the expanded output of `proof`.
+pub(crate) fn output_stream(proof: &VRFProof) -> impl XofReader {
+    let mut hasher = Shake256::default();
+    hasher.update(EXPAND_DST);
+    hasher.update(&proof.output);
+    hasher.finalize_xof()
+}
+
+/// Derives a labelled child value from a parent proof's randomness.
+///
+/// Computes HKDF-SHA256 with the parent's `randomness()` as input keying
+/// material and `label` as the info string, so one root value can fan out to
+/// many sub-uses (e.g. `b"shuffle"`, `b"prize-draw/3"`). The same label always
+/// gives the same child, and different labels give independent children.
+///
+/// This is a key derivation, not a new VRF evaluation: there is no new
+/// signature, and anyone who knows the parent output can compute every child.
+/// Verifiers check the parent proof and then re-derive the child themselves.
+///
+/// # Arguments
+/// * `parent` - The proof whose randomness is the root value.
+/// * `label` - The sub-use this child is for.
+///
+/// # Returns
+/// * `Ok([u8; 32])` - The child value.
+/// * `Err(VRFError::InvalidLength)` if `parent.output` is not 32 bytes, as in `VRFProof::randomness`.
+pub fn derive_child(parent: &VRFProof, label: &[u8]) -> Result<[u8; 32], VRFError> {
+    Ok(derive_child_from_output(&parent.randomness()?, label))
+}
+
+/// Derives a labelled child value from a bare 32-byte VRF output.
+///
+/// Same as `derive_child`, for callers that only kept the output.
+pub fn derive_child_from_output(parent_output: &[u8; 32], label: &[u8]) -> [u8; 32] {
+    let mut child = [0u8; 32];
+    Hkdf::<Sha256>::new(Some(CHILD_SALT), parent_output)
+        .expand(label, &mut child)
+        .expect("32 bytes is a valid HKDF-SHA256 output length");
+    child
+}
@@ -0,0 +1,48 @@
+//! Bounded thread pool for parallel batch operations (`rayon` feature).
+
+use rayon::{ThreadPool, ThreadPoolBuildError, ThreadPoolBuilder};
+
+/// A dedicated rayon pool for batch verification and payload generation.
+///
+/// Batch APIs that take an `Option<&VrfPool>` run on rayon's global pool when
+/// given `None`. Passing a `VrfPool` instead caps how many cores the crate
+/// uses, e.g. when it is embedded in a server that shares the machine with
+/// other tenants.
+pub struct VrfPool {
+    inner: ThreadPool,
+}
+
+impl VrfPool {
+    /// Builds a pool with `num_threads` worker threads.
+    ///
+    /// # Arguments
+    /// * `num_threads` - Worker count; 0 lets rayon pick one per core.
+    ///
+    /// # Returns
+    /// * `Ok(VrfPool)` if the threads were spawned.
+    /// * `Err(ThreadPoolBuildError)` if the operating system refused to spawn them.
+    pub fn new(num_threads: usize) -> Result<VrfPool, ThreadPoolBuildError> {
+        let inner = ThreadPoolBuilder::new()
+            .num_threads(num_threads)
+            .thread_name(|i| format!("nebula-vrf-{}", i))
+            .build()?;
+        Ok(VrfPool { inner })
+    }
+
+    /// Returns the number of worker threads.
+    pub fn num_threads(&self) -> usize {
+        self.inner.current_num_threads()
+    }
+}
+
+/// Runs `f` inside `pool`, or on the caller's (normally the global) pool when `None`.
+pub(crate) fn install<R, F>(pool: Option<&VrfPool>, f: F) -> R
+where
+    F: FnOnce() -> R + Send,
+    R: Send,
+{
+    match pool {
+        Some(pool) => pool.inner.install(f),
+        None => f(),
+    }
+}
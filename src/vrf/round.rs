@@ -0,0 +1,205 @@
+//! Commit-reveal rounds.
+//!
+//! A `CommitRevealRound` enforces the lifecycle of the fairness protocol this
+//! crate is built around: participants commit to `sha256(seed || salt)` before
+//! a deadline, the round moves to its reveal phase, each committer opens their
+//! commitment, and the revealed seeds are XORed into the round's randomness.
+//! Because no seed is known before every commitment is fixed, no single
+//! participant can steer the result, as long as at least one seed is random.
+//!
+//! A committer who sees the other reveals first can still choose to withhold
+//! their own, so protocols should penalise missing reveals (e.g. by forfeiting
+//! a deposit). `finalize` uses only the seeds that were revealed.
+
+use alloc::collections::BTreeMap;
+use super::commit::verify_commit_with_salt;
+
+/// The phase a round is in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RoundPhase {
+    /// Accepting commitments until the deadline.
+    Commit,
+    /// Accepting reveals from committed participants.
+    Reveal,
+}
+
+/// Errors returned when a call does not fit the round's lifecycle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RoundError {
+    /// The call is not allowed in the round's current phase.
+    WrongPhase {
+        /// The phase the round is in.
+        phase: RoundPhase,
+    },
+    /// A commitment arrived after the commit deadline.
+    CommitAfterDeadline {
+        /// The round's commit deadline.
+        deadline: u64,
+        /// The time the commitment was submitted.
+        now: u64,
+    },
+    /// The participant has already committed in this round.
+    AlreadyCommitted,
+    /// The participant never committed, so their reveal cannot be checked.
+    NotCommitted,
+    /// The participant has already revealed in this round.
+    AlreadyRevealed,
+    /// The revealed seed and salt do not open the stored commitment.
+    InvalidReveal,
+    /// The round cannot be finalized because nobody revealed.
+    NoReveals,
+}
+
+impl core::fmt::Display for RoundError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            RoundError::WrongPhase { phase } => write!(f, "Not allowed in the {:?} phase", phase),
+            RoundError::CommitAfterDeadline { deadline, now } => {
+                write!(f, "Commit after deadline: deadline {}, committed at {}", deadline, now)
+            }
+            RoundError::AlreadyCommitted => write!(f, "Participant already committed"),
+            RoundError::NotCommitted => write!(f, "Participant never committed"),
+            RoundError::AlreadyRevealed => write!(f, "Participant already revealed"),
+            RoundError::InvalidReveal => write!(f, "Reveal does not match the commitment"),
+            RoundError::NoReveals => write!(f, "No seeds were revealed"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for RoundError {}
+
+/// One participant's commitment and, once opened, their revealed seed.
+#[derive(Debug, Clone)]
+struct Entry {
+    commitment: [u8; 32],
+    seed: Option<[u8; 32]>,
+}
+
+/// A single commit-reveal round keyed by participant identifier.
+///
+/// Times are in the caller's unit (e.g. Unix seconds or ledger sequence), as
+/// with `commit::commit_timed`.
+#[derive(Debug, Clone)]
+pub struct CommitRevealRound<U: Ord> {
+    deadline: u64,
+    phase: RoundPhase,
+    entries: BTreeMap<U, Entry>,
+}
+
+impl<U: Ord> CommitRevealRound<U> {
+    /// Opens a round that accepts commitments up to and including `deadline`.
+    pub fn new(deadline: u64) -> CommitRevealRound<U> {
+        CommitRevealRound { deadline, phase: RoundPhase::Commit, entries: BTreeMap::new() }
+    }
+
+    /// Returns the commit deadline.
+    pub fn deadline(&self) -> u64 {
+        self.deadline
+    }
+
+    /// Returns the current phase.
+    pub fn phase(&self) -> RoundPhase {
+        self.phase
+    }
+
+    /// Returns the number of participants who committed.
+    pub fn num_commits(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns the number of participants who revealed.
+    pub fn num_reveals(&self) -> usize {
+        self.entries.values().filter(|entry| entry.seed.is_some()).count()
+    }
+
+    /// Records a participant's `commit_with_salt(seed, salt)` commitment.
+    ///
+    /// # Arguments
+    /// * `user` - The participant's identifier.
+    /// * `commitment` - The participant's salted commitment.
+    /// * `now` - The current time, in the same unit as the deadline.
+    ///
+    /// # Returns
+    /// * `Ok(())` if the commitment was recorded.
+    /// * `Err(RoundError::WrongPhase)` if the round is already in its reveal phase.
+    /// * `Err(RoundError::CommitAfterDeadline)` if `now` is past the deadline.
+    /// * `Err(RoundError::AlreadyCommitted)` if `user` has already committed.
+    pub fn submit_commit(&mut self, user: U, commitment: [u8; 32], now: u64) -> Result<(), RoundError> {
+        if self.phase != RoundPhase::Commit {
+            return Err(RoundError::WrongPhase { phase: self.phase });
+        }
+        if now > self.deadline {
+            return Err(RoundError::CommitAfterDeadline { deadline: self.deadline, now });
+        }
+        if self.entries.contains_key(&user) {
+            return Err(RoundError::AlreadyCommitted);
+        }
+        self.entries.insert(user, Entry { commitment, seed: None });
+        Ok(())
+    }
+
+    /// Closes the commit phase and starts accepting reveals.
+    ///
+    /// This may be called before the deadline to end the commit phase early,
+    /// e.g. once every expected participant has committed.
+    ///
+    /// # Returns
+    /// * `Ok(())` if the round moved to its reveal phase.
+    /// * `Err(RoundError::WrongPhase)` if it was already there.
+    pub fn start_reveal(&mut self) -> Result<(), RoundError> {
+        if self.phase != RoundPhase::Commit {
+            return Err(RoundError::WrongPhase { phase: self.phase });
+        }
+        self.phase = RoundPhase::Reveal;
+        Ok(())
+    }
+
+    /// Opens a participant's commitment with their seed and salt.
+    ///
+    /// # Arguments
+    /// * `user` - The participant's identifier.
+    /// * `seed` - The revealed 32-byte seed.
+    /// * `salt` - The revealed salt.
+    ///
+    /// # Returns
+    /// * `Ok(())` if the reveal matches the stored commitment.
+    /// * `Err(RoundError::WrongPhase)` if the round is still in its commit phase.
+    /// * `Err(RoundError::NotCommitted)` if `user` never committed.
+    /// * `Err(RoundError::AlreadyRevealed)` if `user` has already revealed.
+    /// * `Err(RoundError::InvalidReveal)` if the seed and salt do not open the commitment.
+    pub fn submit_reveal(&mut self, user: &U, seed: &[u8; 32], salt: &[u8]) -> Result<(), RoundError> {
+        if self.phase != RoundPhase::Reveal {
+            return Err(RoundError::WrongPhase { phase: self.phase });
+        }
+        let entry = self.entries.get_mut(user).ok_or(RoundError::NotCommitted)?;
+        if entry.seed.is_some() {
+            return Err(RoundError::AlreadyRevealed);
+        }
+        if !verify_commit_with_salt(seed, salt, &entry.commitment) {
+            return Err(RoundError::InvalidReveal);
+        }
+        entry.seed = Some(*seed);
+        Ok(())
+    }
+
+    /// Ends the round and returns the XOR of every revealed seed.
+    ///
+    /// # Returns
+    /// * `Ok([u8; 32])` - The round's randomness.
+    /// * `Err(RoundError::WrongPhase)` if the round is still in its commit phase.
+    /// * `Err(RoundError::NoReveals)` if nobody revealed.
+    pub fn finalize(self) -> Result<[u8; 32], RoundError> {
+        if self.phase != RoundPhase::Reveal {
+            return Err(RoundError::WrongPhase { phase: self.phase });
+        }
+        let mut revealed = self.entries.values().filter_map(|entry| entry.seed).peekable();
+        if revealed.peek().is_none() {
+            return Err(RoundError::NoReveals);
+        }
+        Ok(revealed.fold([0u8; 32], |mut acc, seed| {
+            acc.iter_mut().zip(seed).for_each(|(a, s)| *a ^= s);
+            acc
+        }))
+    }
+}
@@ -0,0 +1,106 @@
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+use sha3::digest::XofReader;
+use super::output::output_stream;
+use super::types::VRFProof;
+
+/// Draws unbiased integers from the expanded output of a VRF proof.
+struct Sampler<R: XofReader> {
+    stream: R,
+}
+
+impl<R: XofReader> Sampler<R> {
+    fn next_u64(&mut self) -> u64 {
+        let mut bytes = [0u8; 8];
+        self.stream.read(&mut bytes);
+        u64::from_be_bytes(bytes)
+    }
+
+    /// Returns a uniform integer in `[0, n)` by rejection sampling.
+    ///
+    /// Values below `2^64 mod n` are rejected so the accepted range is an
+    /// exact multiple of `n` and the reduction introduces no modulo bias.
+    fn below(&mut self, n: u64) -> u64 {
+        let threshold = n.wrapping_neg() % n;
+        loop {
+            let value = self.next_u64();
+            if value >= threshold {
+                return value % n;
+            }
+        }
+    }
+}
+
+fn sampler(proof: &VRFProof) -> Sampler<impl XofReader> {
+    Sampler { stream: output_stream(proof) }
+}
+
+/// Derives a uniformly random integer in `[0, n)` from a VRF proof.
+///
+/// The result is deterministic for a given proof and `n`.
+///
+/// # Arguments
+/// * `proof` - The VRF proof whose output drives the draw.
+/// * `n` - The exclusive upper bound.
+///
+/// # Panics
+/// Panics if `n` is zero.
+pub fn random_below(proof: &VRFProof, n: u64) -> u64 {
+    assert!(n > 0, "random_below requires n > 0");
+    sampler(proof).below(n)
+}
+
+/// Derives a uniformly random permutation of `0..len` from a VRF proof.
+///
+/// Uses a Fisher-Yates shuffle driven by the proof's expanded output, so the
+/// result is deterministic for a given proof and `len`.
+///
+/// # Arguments
+/// * `proof` - The VRF proof whose output drives the shuffle.
+/// * `len` - The number of elements to permute.
+///
+/// # Returns
+/// * `Vec<usize>` - A permutation of `0..len`.
+pub fn random_permutation(proof: &VRFProof, len: usize) -> Vec<usize> {
+    let mut sampler = sampler(proof);
+    let mut permutation: Vec<usize> = (0..len).collect();
+    for i in (1..len).rev() {
+        let j = sampler.below(i as u64 + 1) as usize;
+        permutation.swap(i, j);
+    }
+    permutation
+}
+
+/// Draws `k` distinct indices from `0..n` using a VRF proof, e.g. raffle winners.
+///
+/// Runs a partial Fisher-Yates shuffle driven by the proof's expanded output:
+/// draw `i` picks uniformly among the `n - i` indices not yet drawn. Only the
+/// swapped positions are stored, so memory is `O(k)` even for very large `n`.
+/// The result is deterministic for a given proof, `n`, and `k`, and its first
+/// `k` entries match for any larger `k` drawn from the same proof and `n`.
+///
+/// # Arguments
+/// * `proof` - The VRF proof whose output drives the draw.
+/// * `n` - The number of entries.
+/// * `k` - The number of winners.
+///
+/// # Returns
+/// * `Some(Vec<usize>)` - `k` distinct indices in draw order.
+/// * `None` if `k > n`, since there are not enough entries.
+pub fn sample_without_replacement(proof: &VRFProof, n: usize, k: usize) -> Option<Vec<usize>> {
+    if k > n {
+        return None;
+    }
+    let mut sampler = sampler(proof);
+    // Positions that have been swapped away from their identity value.
+    let mut swapped: BTreeMap<usize, usize> = BTreeMap::new();
+    let mut winners = Vec::with_capacity(k);
+    for i in 0..k {
+        let j = i + sampler.below((n - i) as u64) as usize;
+        let at_i = swapped.get(&i).copied().unwrap_or(i);
+        let at_j = swapped.get(&j).copied().unwrap_or(j);
+        swapped.insert(j, at_i);
+        winners.push(at_j);
+    }
+    Some(winners)
+}
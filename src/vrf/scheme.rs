@@ -0,0 +1,85 @@
+//! Backend-agnostic VRF interface.
+//!
+//! `VrfScheme` captures what the commit-reveal flow needs from a VRF: prove
+//! over a seed, and verify a proof while recovering its output. `BlsVrf`
+//! implements it with the crate's BLS12-381 construction. Another backend,
+//! such as an RFC 9381 ECVRF over Ed25519 with its smaller proofs, can
+//! implement the same trait and reuse `commit::verify_reveal_with` unchanged.
+
+use alloc::vec::Vec;
+use super::bls::{proof_to_hash, verify_proof, VrfKeypair};
+use super::types::VRFError;
+
+/// A verifiable random function backend.
+///
+/// Implementors hold the prover's secret key; verification is an associated
+/// function so verifiers only need the public key.
+pub trait VrfScheme {
+    /// The encoded public key.
+    type PublicKey;
+    /// The encoded proof.
+    type Proof;
+    /// The VRF output, e.g. 32 bytes for `BlsVrf` or 64 for ECVRF-EDWARDS25519-SHA512.
+    type Output: AsRef<[u8]>;
+
+    /// Returns the prover's public key.
+    fn public_key(&self) -> Self::PublicKey;
+
+    /// Produces a proof over `seed`.
+    fn prove(&self, seed: &[u8]) -> Self::Proof;
+
+    /// Derives the output from a proof without checking it against a key or seed.
+    fn proof_to_hash(proof: &Self::Proof) -> Result<Self::Output, VRFError>;
+
+    /// Verifies a proof over `seed` under `public_key` and returns its output.
+    fn verify(
+        public_key: &Self::PublicKey,
+        seed: &[u8],
+        proof: &Self::Proof,
+    ) -> Result<Self::Output, VRFError>;
+}
+
+/// The BLS12-381 VRF used throughout this crate, behind `VrfScheme`.
+///
+/// Proofs and public keys are the compressed bytes produced by `VrfKeypair`
+/// (either encoding is accepted when verifying), and outputs are the 32-byte
+/// `proof_to_hash` digests, so they interoperate with `verify_proof`.
+pub struct BlsVrf {
+    keypair: VrfKeypair,
+}
+
+impl BlsVrf {
+    /// Wraps a keypair as a `VrfScheme` prover.
+    pub fn new(keypair: VrfKeypair) -> BlsVrf {
+        BlsVrf { keypair }
+    }
+}
+
+impl From<VrfKeypair> for BlsVrf {
+    fn from(keypair: VrfKeypair) -> BlsVrf {
+        BlsVrf::new(keypair)
+    }
+}
+
+impl VrfScheme for BlsVrf {
+    type PublicKey = Vec<u8>;
+    type Proof = Vec<u8>;
+    type Output = [u8; 32];
+
+    fn public_key(&self) -> Vec<u8> {
+        self.keypair.public_key()
+    }
+
+    fn prove(&self, seed: &[u8]) -> Vec<u8> {
+        self.keypair.prove(seed).proof
+    }
+
+    fn proof_to_hash(proof: &Vec<u8>) -> Result<[u8; 32], VRFError> {
+        proof_to_hash(proof)
+    }
+
+    fn verify(public_key: &Vec<u8>, seed: &[u8], proof: &Vec<u8>) -> Result<[u8; 32], VRFError> {
+        verify_proof(seed, proof, public_key)?;
+        proof_to_hash(proof)
+    }
+}
@@ -0,0 +1,205 @@
+//! Running uniformity checks on VRF outputs (`stats` feature).
+
+use statrs::distribution::{ChiSquared, ContinuousCDF, Normal};
+
+/// Parameters for an `EntropyMonitor`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MonitorConfig {
+    /// Number of chi-square bins: 2, 4, 16 or 256. Each byte is split into
+    /// symbols of `log2(bins)` bits, so every bit is counted whatever the width.
+    pub bins: usize,
+    /// Significance level: a test fails when its p-value is below `alpha`.
+    pub alpha: f64,
+    /// Bytes to observe before any test can fail. Until then the `passes_*`
+    /// methods report a pass, so a fresh monitor does not alert.
+    pub min_bytes: u64,
+}
+
+impl Default for MonitorConfig {
+    /// One bin per byte value, `alpha = 0.01`, and 1280 bytes (40 outputs),
+    /// which gives the chi-square test about five expected counts per bin.
+    fn default() -> Self {
+        MonitorConfig { bins: 256, alpha: 0.01, min_bytes: 1280 }
+    }
+}
+
+/// Streaming randomness tests over VRF outputs.
+///
+/// A beacon operator can feed every published output to `observe` and poll
+/// the p-values. Three independent tests catch different failure modes:
+///
+/// * `p_value` - chi-square test of the symbol distribution, for skewed values.
+/// * `runs_p_value` - Wald-Wolfowitz runs test over the bit stream, for bits
+///   that repeat or alternate too often even when every value is equally common.
+/// * `monobit_p_value` - frequency test of ones against zeros.
+///
+/// Healthy outputs give p-values spread evenly over `[0, 1]`; a value that
+/// stays near zero means the outputs are not random, e.g. because seeds or
+/// keys come from a degraded RNG. Any single reading below `alpha` is
+/// expected about `alpha` of the time per test, so alert on persistent
+/// failures rather than one sample.
+#[derive(Debug, Clone)]
+pub struct EntropyMonitor {
+    config: MonitorConfig,
+    symbol_bits: u32,
+    bins: Vec<u64>,
+    total: u64,
+    ones: u64,
+    runs: u64,
+    last_bit: Option<bool>,
+}
+
+impl Default for EntropyMonitor {
+    fn default() -> Self {
+        EntropyMonitor::with_config(MonitorConfig::default())
+    }
+}
+
+impl EntropyMonitor {
+    /// Creates a monitor with no observations and the default configuration.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a monitor with no observations and the given configuration.
+    ///
+    /// # Panics
+    /// If `config.bins` is not 2, 4, 16 or 256, or `config.alpha` is not in `(0, 1)`.
+    pub fn with_config(config: MonitorConfig) -> Self {
+        assert!(
+            matches!(config.bins, 2 | 4 | 16 | 256),
+            "bins must be 2, 4, 16 or 256, got {}",
+            config.bins
+        );
+        assert!(config.alpha > 0.0 && config.alpha < 1.0, "alpha must be in (0, 1), got {}", config.alpha);
+        EntropyMonitor {
+            config,
+            symbol_bits: config.bins.trailing_zeros(),
+            bins: vec![0; config.bins],
+            total: 0,
+            ones: 0,
+            runs: 0,
+            last_bit: None,
+        }
+    }
+
+    /// Returns the monitor's configuration.
+    pub fn config(&self) -> &MonitorConfig {
+        &self.config
+    }
+
+    /// Counts every byte of a VRF output.
+    ///
+    /// Outputs are treated as one continuous bit stream, most significant bit
+    /// first, so runs may span consecutive outputs.
+    pub fn observe(&mut self, output: &[u8]) {
+        let mask = ((1u16 << self.symbol_bits) - 1) as u8;
+        for &byte in output {
+            for shift in (0..8).step_by(self.symbol_bits as usize) {
+                self.bins[((byte >> shift) & mask) as usize] += 1;
+            }
+            for shift in (0..8).rev() {
+                let bit = (byte >> shift) & 1 == 1;
+                if self.last_bit != Some(bit) {
+                    self.runs += 1;
+                    self.last_bit = Some(bit);
+                }
+            }
+            self.ones += byte.count_ones() as u64;
+        }
+        self.total += output.len() as u64;
+    }
+
+    /// Returns the number of bytes observed so far.
+    pub fn observed_bytes(&self) -> u64 {
+        self.total
+    }
+
+    /// Returns the chi-square statistic of the symbol counts against a uniform
+    /// distribution, or 0 before any bytes are observed.
+    pub fn chi_square(&self) -> f64 {
+        if self.total == 0 {
+            return 0.0;
+        }
+        let symbols = self.total * (8 / self.symbol_bits) as u64;
+        let expected = symbols as f64 / self.bins.len() as f64;
+        self.bins
+            .iter()
+            .map(|&observed| {
+                let diff = observed as f64 - expected;
+                diff * diff / expected
+            })
+            .sum()
+    }
+
+    /// Returns the probability of a chi-square statistic at least this large
+    /// if the symbols were uniform (`bins - 1` degrees of freedom).
+    ///
+    /// Returns 1 before any bytes are observed. The test needs about five
+    /// expected counts per bin to be reliable.
+    pub fn p_value(&self) -> f64 {
+        if self.total == 0 {
+            return 1.0;
+        }
+        let distribution = ChiSquared::new((self.bins.len() - 1) as f64).expect("degrees of freedom are positive");
+        distribution.sf(self.chi_square())
+    }
+
+    /// Returns the two-sided p-value of the Wald-Wolfowitz runs test on the
+    /// observed bits: the chance of a run count at least this far from its
+    /// expectation, given the numbers of ones and zeros.
+    ///
+    /// Returns 1 before any bytes are observed, and 0 if every bit is equal.
+    pub fn runs_p_value(&self) -> f64 {
+        let n = (self.total * 8) as f64;
+        if n == 0.0 {
+            return 1.0;
+        }
+        let ones = self.ones as f64;
+        let zeros = n - ones;
+        if ones == 0.0 || zeros == 0.0 {
+            return 0.0;
+        }
+        let mean = 2.0 * ones * zeros / n + 1.0;
+        let variance = (mean - 1.0) * (mean - 2.0) / (n - 1.0);
+        two_sided_p((self.runs as f64 - mean) / variance.sqrt())
+    }
+
+    /// Returns the two-sided p-value of the monobit frequency test: the chance
+    /// of at least this large an excess of ones or zeros in fair coin flips.
+    ///
+    /// Returns 1 before any bytes are observed.
+    pub fn monobit_p_value(&self) -> f64 {
+        let n = (self.total * 8) as f64;
+        if n == 0.0 {
+            return 1.0;
+        }
+        let excess = 2.0 * self.ones as f64 - n;
+        two_sided_p(excess / n.sqrt())
+    }
+
+    /// Returns whether the chi-square test passes at the configured `alpha`.
+    pub fn passes_chi_square(&self) -> bool {
+        self.passes(self.p_value())
+    }
+
+    /// Returns whether the runs test passes at the configured `alpha`.
+    pub fn passes_runs(&self) -> bool {
+        self.passes(self.runs_p_value())
+    }
+
+    /// Returns whether the monobit test passes at the configured `alpha`.
+    pub fn passes_monobit(&self) -> bool {
+        self.passes(self.monobit_p_value())
+    }
+
+    fn passes(&self, p_value: f64) -> bool {
+        self.total < self.config.min_bytes || p_value >= self.config.alpha
+    }
+}
+
+/// Returns `P(|Z| >= |z|)` for a standard normal `Z`.
+fn two_sided_p(z: f64) -> f64 {
+    let normal = Normal::new(0.0, 1.0).expect("standard normal parameters are valid");
+    (2.0 * normal.sf(z.abs())).min(1.0)
+}
@@ -0,0 +1,121 @@
+//! Typed VRF inputs.
+//!
+//! The byte-slice API takes seeds, proofs and public keys as `&[u8]`, so
+//! swapping two arguments at a call site still compiles and only fails at
+//! runtime. The newtypes here make that a compile error: `Signature` and
+//! `PublicKey` are validated once on conversion, and `verify_proof` in this
+//! module only accepts them in the right positions. The functions in
+//! `vrf::bls` remain the `&[u8]` shims.
+
+use alloc::vec::Vec;
+use super::bls::{self, parse_public_key, parse_signature, VRF_PUBLIC_KEY_SIZE, VRF_SIGNATURE_SIZE};
+use super::types::{VRFError, VRFProof};
+
+/// A VRF input seed. Any byte string is a valid seed.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Seed(Vec<u8>);
+
+impl Seed {
+    /// Returns the seed bytes.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl From<Vec<u8>> for Seed {
+    fn from(bytes: Vec<u8>) -> Seed {
+        Seed(bytes)
+    }
+}
+
+impl From<&[u8]> for Seed {
+    fn from(bytes: &[u8]) -> Seed {
+        Seed(bytes.to_vec())
+    }
+}
+
+impl AsRef<[u8]> for Seed {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+/// A VRF proof (BLS signature), held in compressed form.
+///
+/// `try_from` accepts either encoding and applies the same checks as
+/// `verify_proof`: length, canonical encoding, identity and subgroup.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Signature([u8; VRF_SIGNATURE_SIZE]);
+
+impl Signature {
+    /// Returns the compressed signature bytes.
+    pub fn as_bytes(&self) -> &[u8; VRF_SIGNATURE_SIZE] {
+        &self.0
+    }
+}
+
+impl TryFrom<&[u8]> for Signature {
+    type Error = VRFError;
+
+    fn try_from(bytes: &[u8]) -> Result<Signature, VRFError> {
+        parse_signature(bytes).map(|sig| Signature(sig.compress()))
+    }
+}
+
+impl AsRef<[u8]> for Signature {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+/// A VRF public key, held in compressed form.
+///
+/// `try_from` accepts either encoding and applies the same checks as
+/// `verify_proof`: length, canonical encoding, identity and subgroup.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PublicKey([u8; VRF_PUBLIC_KEY_SIZE]);
+
+impl PublicKey {
+    /// Returns the compressed public key bytes.
+    pub fn as_bytes(&self) -> &[u8; VRF_PUBLIC_KEY_SIZE] {
+        &self.0
+    }
+}
+
+impl TryFrom<&[u8]> for PublicKey {
+    type Error = VRFError;
+
+    fn try_from(bytes: &[u8]) -> Result<PublicKey, VRFError> {
+        parse_public_key(bytes).map(|pk| PublicKey(pk.compress()))
+    }
+}
+
+impl AsRef<[u8]> for PublicKey {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+/// Generates a VRF proof and random output from a typed seed.
+///
+/// # Returns
+/// * `Ok((VRFProof, Signature, PublicKey))` - The proof and its typed parts.
+/// * `Err(VRFError)` if key generation or signing fails.
+pub fn generate_random(seed: &Seed) -> Result<(VRFProof, Signature, PublicKey), VRFError> {
+    let vrf = bls::generate_random(seed.as_bytes())?;
+    let signature = Signature::try_from(vrf.proof.as_slice())?;
+    let public_key = PublicKey::try_from(vrf.public_key.as_slice())?;
+    Ok((vrf, signature, public_key))
+}
+
+/// Verifies a VRF proof over a typed seed.
+///
+/// Both points were validated when they were converted, so only the pairing
+/// check runs here.
+///
+/// # Returns
+/// * `Ok(())` if the proof is valid.
+/// * `Err(VRFError::VerificationFailed)` if it is not.
+pub fn verify_proof(seed: &Seed, signature: &Signature, public_key: &PublicKey) -> Result<(), VRFError> {
+    bls::verify_proof_unchecked(seed.as_bytes(), signature.as_ref(), public_key.as_ref())
+}
@@ -1,12 +1,240 @@
-/// VRFProof includes the random output and the public key for verification.
-#[derive(Debug)]
+use alloc::vec::Vec;
+use crate::utils::hash::sha256;
+use super::eip2537::to_eip2537;
+use super::bls::{
+    VRF_PUBLIC_KEY_SIZE, VRF_PUBLIC_KEY_UNCOMPRESSED_SIZE, VRF_SIGNATURE_SIZE,
+    VRF_SIGNATURE_UNCOMPRESSED_SIZE,
+};
+
+/// Version byte written at the start of `VRFProof::to_bytes`.
+pub const VRF_PROOF_FORMAT_VERSION: u8 = 1;
+
+/// Size of the `VRFProof::to_bytes` header: version plus three big-endian `u16` lengths.
+const PROOF_HEADER_SIZE: usize = 1 + 3 * 2;
+
+/// Size of the VRF output in bytes.
+const OUTPUT_SIZE: usize = 32;
+
+/// VRFProof includes the random output, the proof it was derived from, and the
+/// public key for verification.
+///
+/// With the `serde` feature enabled, all fields serialize as hex strings.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct VRFProof {
-    /// The randomness (signature)
+    /// The randomness (proof-to-hash of the signature, 32 bytes)
+    #[cfg_attr(feature = "serde", serde(with = "hex::serde"))]
     pub output: Vec<u8>,
-    /// The proof (public key)
+    /// The proof (signature)
+    #[cfg_attr(feature = "serde", serde(with = "hex::serde"))]
+    pub proof: Vec<u8>,
+    /// The public key
+    #[cfg_attr(feature = "serde", serde(with = "hex::serde"))]
     pub public_key: Vec<u8>,
 }
 
+impl VRFProof {
+    /// Returns the proof's uniform 32-byte randomness.
+    ///
+    /// `output` is already the RFC 9381 proof-to-hash digest of the signature,
+    /// not the signature encoding itself, so it is returned as is. Use this
+    /// rather than `proof`, whose curve-point bytes are not uniform.
+    ///
+    /// # Returns
+    /// * `Ok([u8; 32])` - The randomness.
+    /// * `Err(VRFError::InvalidLength)` if `output` is not 32 bytes, e.g. in a
+    ///   hand-built or deserialized proof. Proofs from this crate and from
+    ///   `from_bytes` always have 32-byte outputs.
+    pub fn randomness(&self) -> Result<[u8; OUTPUT_SIZE], VRFError> {
+        self.output
+            .as_slice()
+            .try_into()
+            .map_err(|_| VRFError::InvalidLength { expected: OUTPUT_SIZE, got: self.output.len() })
+    }
+
+    /// Derives the `index`-th value of a counter-mode stream from this proof:
+    /// `sha256(randomness() || index.to_be_bytes())`.
+    ///
+    /// Each index gives a distinct, uniform 32-byte value tied to the single
+    /// proof, so one VRF call can feed several draws. The values are
+    /// independent of each other but not unlinkable: anyone who sees the
+    /// proof or its output can compute every index, and so can tell that two
+    /// values came from the same proof. For a byte stream of arbitrary length,
+    /// see `output::expand_output`.
+    ///
+    /// # Returns
+    /// * `Ok([u8; 32])` - The value at `index`.
+    /// * `Err(VRFError::InvalidLength)` if `output` is not 32 bytes, as in `randomness`.
+    pub fn output_at(&self, index: u64) -> Result<[u8; 32], VRFError> {
+        let mut input = [0u8; OUTPUT_SIZE + 8];
+        input[..OUTPUT_SIZE].copy_from_slice(&self.randomness()?);
+        input[OUTPUT_SIZE..].copy_from_slice(&index.to_be_bytes());
+        Ok(sha256(&input))
+    }
+
+    /// Reads the first 16 bytes of `randomness()` as a big-endian `u128`.
+    ///
+    /// Byte 0 of the output is the most significant byte; bytes 16..32 are
+    /// ignored. This matches `u128::from_be_bytes(output[..16])` and the
+    /// usual Solidity `uint128(bytes16(output))` reading.
+    ///
+    /// # Errors
+    /// Returns `VRFError::InvalidLength` if `output` is not 32 bytes, as `randomness` does.
+    pub fn as_u128_be(&self) -> Result<u128, VRFError> {
+        Ok(u128::from_be_bytes(self.randomness()?[..16].try_into().expect("16-byte prefix")))
+    }
+
+    /// Reads the first 16 bytes of `randomness()` as a little-endian `u128`.
+    ///
+    /// Byte 0 of the output is the least significant byte; bytes 16..32 are
+    /// ignored. This matches `u128::from_le_bytes(output[..16])`.
+    ///
+    /// # Errors
+    /// Returns `VRFError::InvalidLength` if `output` is not 32 bytes, as `randomness` does.
+    pub fn as_u128_le(&self) -> Result<u128, VRFError> {
+        Ok(u128::from_le_bytes(self.randomness()?[..16].try_into().expect("16-byte prefix")))
+    }
+
+    /// Reads all 32 bytes of `randomness()` as a big-endian unsigned integer.
+    ///
+    /// Byte 0 is the most significant byte, so the top 128 bits equal
+    /// `as_u128_be()`.
+    ///
+    /// # Errors
+    /// Returns `VRFError::InvalidLength` if `output` is not 32 bytes, as `randomness` does.
+    #[cfg(feature = "num-bigint")]
+    pub fn as_biguint(&self) -> Result<num_bigint::BigUint, VRFError> {
+        Ok(num_bigint::BigUint::from_bytes_be(&self.randomness()?))
+    }
+
+    /// Serializes the proof as a single blob.
+    ///
+    /// Layout: `version (1) || len(output) (2) || len(proof) (2) || len(public_key) (2)
+    /// || output || proof || public_key`, with lengths as big-endian `u16`.
+    ///
+    /// # Returns
+    /// * `Ok(Vec<u8>)` - The encoded proof.
+    /// * `Err(VRFError::InvalidLength)` if a field is longer than `u16::MAX` bytes,
+    ///   which only a hand-built proof can be.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, VRFError> {
+        let fields = [&self.output, &self.proof, &self.public_key];
+        let body_len: usize = fields.iter().map(|f| f.len()).sum();
+        let mut bytes = Vec::with_capacity(PROOF_HEADER_SIZE + body_len);
+        bytes.push(VRF_PROOF_FORMAT_VERSION);
+        for field in fields {
+            let len = u16::try_from(field.len())
+                .map_err(|_| VRFError::InvalidLength { expected: usize::from(u16::MAX), got: field.len() })?;
+            bytes.extend_from_slice(&len.to_be_bytes());
+        }
+        for field in fields {
+            bytes.extend_from_slice(field);
+        }
+        Ok(bytes)
+    }
+
+    /// Returns a content-addressed storage key: `sha256(to_bytes())`.
+    ///
+    /// The id covers the output, proof and public key bytes, not the seed,
+    /// so two proofs with equal fields share an id however they were built or
+    /// parsed. Because it hashes the bytes as given, the compressed and
+    /// uncompressed encodings of the same proof have different ids.
+    ///
+    /// # Errors
+    /// Returns `VRFError::InvalidLength` if `to_bytes` cannot encode the proof.
+    pub fn id(&self) -> Result<[u8; 32], VRFError> {
+        Ok(sha256(&self.to_bytes()?))
+    }
+
+    /// Returns the public key and proof in the EIP-2537 layout used by
+    /// Ethereum's BLS12-381 precompiles: uncompressed, with each field element
+    /// padded to 64 big-endian bytes (128 bytes per G1 point, 256 per G2 point).
+    ///
+    /// `eip2537::from_eip2537` converts them back to blst bytes.
+    ///
+    /// # Returns
+    /// * `Ok((public_key, proof))` - The EIP-2537 encodings.
+    /// * `Err(VRFError)` if either point fails to parse.
+    pub fn to_eip2537(&self) -> Result<(Vec<u8>, Vec<u8>), VRFError> {
+        to_eip2537(&self.public_key, &self.proof)
+    }
+
+    /// Parses a blob produced by `to_bytes`.
+    ///
+    /// Field lengths must match a known output and point size; points are not
+    /// decoded, so verification is still required.
+    ///
+    /// # Arguments
+    /// * `bytes` - The encoded proof.
+    ///
+    /// # Returns
+    /// * `Ok(VRFProof)` if the blob is well formed.
+    /// * `Err(VRFError::InvalidLength)` if it is truncated, has trailing bytes, or a field has the wrong size.
+    /// * `Err(VRFError::UnsupportedVersion)` if the version byte is not `VRF_PROOF_FORMAT_VERSION`.
+    pub fn from_bytes(bytes: &[u8]) -> Result<VRFProof, VRFError> {
+        if bytes.len() < PROOF_HEADER_SIZE {
+            return Err(VRFError::InvalidLength { expected: PROOF_HEADER_SIZE, got: bytes.len() });
+        }
+        if bytes[0] != VRF_PROOF_FORMAT_VERSION {
+            return Err(VRFError::UnsupportedVersion { version: bytes[0] });
+        }
+        let len_at = |i: usize| usize::from(u16::from_be_bytes([bytes[1 + 2 * i], bytes[2 + 2 * i]]));
+        let (output_len, proof_len, public_key_len) = (len_at(0), len_at(1), len_at(2));
+
+        let expected = PROOF_HEADER_SIZE + output_len + proof_len + public_key_len;
+        if bytes.len() != expected {
+            return Err(VRFError::InvalidLength { expected, got: bytes.len() });
+        }
+        if output_len != OUTPUT_SIZE {
+            return Err(VRFError::InvalidLength { expected: OUTPUT_SIZE, got: output_len });
+        }
+        if proof_len != VRF_SIGNATURE_SIZE && proof_len != VRF_SIGNATURE_UNCOMPRESSED_SIZE {
+            return Err(VRFError::InvalidLength { expected: VRF_SIGNATURE_SIZE, got: proof_len });
+        }
+        if public_key_len != VRF_PUBLIC_KEY_SIZE && public_key_len != VRF_PUBLIC_KEY_UNCOMPRESSED_SIZE {
+            return Err(VRFError::InvalidLength { expected: VRF_PUBLIC_KEY_SIZE, got: public_key_len });
+        }
+
+        let (output, rest) = bytes[PROOF_HEADER_SIZE..].split_at(output_len);
+        let (proof, public_key) = rest.split_at(proof_len);
+        Ok(VRFProof {
+            output: output.to_vec(),
+            proof: proof.to_vec(),
+            public_key: public_key.to_vec(),
+        })
+    }
+}
+
+/// Byte layout used for BLS points in a VRFProof.
+///
+/// | Encoding       | Signature (min-sig / min-pk) | Public key (min-sig / min-pk) |
+/// |----------------|------------------------------|-------------------------------|
+/// | `Compressed`   | 48 / 96 bytes                | 96 / 48 bytes                 |
+/// | `Uncompressed` | 96 / 192 bytes               | 192 / 96 bytes                |
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Encoding {
+    /// Compressed points (the default).
+    #[default]
+    Compressed,
+    /// Uncompressed points, as used by the Soroban host functions.
+    Uncompressed,
+}
+
+/// How the seed bytes become the message that is hashed to the curve.
+///
+/// `Raw` signs the seed exactly as given. `PreHashed` signs its SHA256
+/// digest instead, for interop with verifiers such as smart contracts that
+/// hash an arbitrary-length message down to 32 bytes before running
+/// hash-to-curve. The two modes produce different proofs and outputs for the
+/// same seed, so prover and verifier must agree on the mode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MessageMode {
+    /// The seed is the message (the default).
+    #[default]
+    Raw,
+    /// The message is `sha256(seed)`.
+    PreHashed,
+}
+
 /// Errors that can occur during VRF operations.
 #[derive(Debug)]
 pub enum VRFError {
@@ -20,18 +248,111 @@ pub enum VRFError {
     DeserializationError,
     /// Verification of the proof failed.
     VerificationFailed,
+    /// An input had the wrong number of bytes.
+    InvalidLength {
+        /// The expected length in bytes.
+        expected: usize,
+        /// The length that was provided.
+        got: usize,
+    },
+    /// A public key decoded correctly but is not in the prime-order subgroup.
+    ///
+    /// Subgroup failures are reported per point, so a caller can tell which
+    /// input was bad; see `SignatureNotInSubgroup` for the signature.
+    PublicKeyNotInSubgroup,
+    /// A public key or signature is the identity (point at infinity).
+    IdentityPoint,
+    /// A seed was shorter than the caller's required minimum.
+    SeedTooShort {
+        /// The minimum length in bytes.
+        min: usize,
+        /// The length that was provided.
+        got: usize,
+    },
+    /// A signature decoded correctly but is not in the prime-order subgroup.
+    ///
+    /// Reported instead of `VerificationFailed`, so an off-subgroup signature
+    /// can be told apart from a genuine pairing mismatch.
+    SignatureNotInSubgroup,
+    /// A timed commitment was revealed after its deadline.
+    Expired {
+        /// The commitment's deadline.
+        not_after: u64,
+        /// The time of the reveal.
+        now: u64,
+    },
+    /// A point decoded, but its bytes are not the canonical encoding of that
+    /// point (e.g. a flag bit set that the decoder ignores), so re-encoding it
+    /// would give different bytes.
+    NonCanonical,
+    /// The same public key was declared more than once where distinct
+    /// signers are required.
+    DuplicateKey,
+    /// A versioned blob (a `VRFProof::to_bytes` blob or a `commit_v2`
+    /// commitment) starts with a format version this build does not know.
+    UnsupportedVersion {
+        /// The version byte that was found.
+        version: u8,
+    },
+    /// An internal operation failed for reasons unrelated to the input, such
+    /// as blst rejecting freshly derived key material. Retrying may succeed.
+    Transient,
+}
+
+impl VRFError {
+    /// Whether retrying the same call may succeed.
+    ///
+    /// Only `Transient` is retryable. Every other variant describes the input
+    /// (malformed bytes, invalid points, failed verification, a short seed, an
+    /// expired commitment) and repeats on every retry.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            VRFError::Transient => true,
+            VRFError::InvalidSignature
+            | VRFError::InvalidPublicKey
+            | VRFError::InvalidCommitment
+            | VRFError::DeserializationError
+            | VRFError::VerificationFailed
+            | VRFError::InvalidLength { .. }
+            | VRFError::PublicKeyNotInSubgroup
+            | VRFError::IdentityPoint
+            | VRFError::SeedTooShort { .. }
+            | VRFError::SignatureNotInSubgroup
+            | VRFError::Expired { .. }
+            | VRFError::NonCanonical
+            | VRFError::DuplicateKey
+            | VRFError::UnsupportedVersion { .. } => false,
+        }
+    }
 }
 
-impl std::fmt::Display for VRFError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl core::fmt::Display for VRFError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             VRFError::InvalidSignature => write!(f, "Invalid signature"),
             VRFError::InvalidPublicKey => write!(f, "Invalid public key"),
             VRFError::InvalidCommitment => write!(f, "Invalid commitment"),
             VRFError::DeserializationError => write!(f, "Deserialization error"),
             VRFError::VerificationFailed => write!(f, "Verification failed"),
+            VRFError::InvalidLength { expected, got } => {
+                write!(f, "Invalid length: expected {} bytes, got {}", expected, got)
+            }
+            VRFError::PublicKeyNotInSubgroup => write!(f, "Public key is not in the prime-order subgroup"),
+            VRFError::IdentityPoint => write!(f, "Point is the identity (point at infinity)"),
+            VRFError::SeedTooShort { min, got } => {
+                write!(f, "Seed too short: expected at least {} bytes, got {}", min, got)
+            }
+            VRFError::SignatureNotInSubgroup => write!(f, "Signature is not in the prime-order subgroup"),
+            VRFError::Expired { not_after, now } => {
+                write!(f, "Commitment expired: deadline {}, revealed at {}", not_after, now)
+            }
+            VRFError::NonCanonical => write!(f, "Point encoding is not canonical"),
+            VRFError::DuplicateKey => write!(f, "Public key declared more than once"),
+            VRFError::UnsupportedVersion { version } => write!(f, "Unsupported format version {}", version),
+            VRFError::Transient => write!(f, "Transient internal failure; retry"),
         }
     }
 }
 
+#[cfg(feature = "std")]
 impl std::error::Error for VRFError {}
@@ -0,0 +1,60 @@
+//! WebAssembly bindings for browser-side verification.
+//!
+//! All inputs and outputs are hex strings. The functions never panic or throw:
+//! malformed input makes verification return `false` and `commit` return
+//! `undefined`.
+
+use wasm_bindgen::prelude::wasm_bindgen;
+
+use crate::vrf::commit;
+
+/// Verifies a VRF proof over a seed under a public key.
+///
+/// # Arguments
+/// * `seed_hex` - The seed as hex.
+/// * `proof_hex` - The VRF proof (signature) as hex.
+/// * `public_key_hex` - The public key as hex.
+///
+/// # Returns
+/// * `bool` - True only if every input decodes and the proof is valid.
+#[wasm_bindgen(js_name = verifyProof)]
+pub fn verify_proof(seed_hex: &str, proof_hex: &str, public_key_hex: &str) -> bool {
+    match (hex::decode(seed_hex), hex::decode(proof_hex), hex::decode(public_key_hex)) {
+        (Ok(seed), Ok(proof), Ok(public_key)) => {
+            crate::vrf::verify_proof(&seed, &proof, &public_key).is_ok()
+        }
+        _ => false,
+    }
+}
+
+/// Computes the unsalted commitment `sha256(seed)`.
+///
+/// # Arguments
+/// * `seed_hex` - The seed as hex.
+///
+/// # Returns
+/// * `Some(String)` - The commitment as hex (a string in JS).
+/// * `None` - If the seed is not valid hex (`undefined` in JS).
+#[wasm_bindgen]
+pub fn commit(seed_hex: &str) -> Option<String> {
+    hex::decode(seed_hex).ok().map(|seed| hex::encode(commit::commit(&seed)))
+}
+
+/// Checks a seed against an unsalted commitment.
+///
+/// # Arguments
+/// * `seed_hex` - The seed as hex.
+/// * `commitment_hex` - The 32-byte commitment as hex.
+///
+/// # Returns
+/// * `bool` - True only if both inputs decode and the seed matches.
+#[wasm_bindgen(js_name = verifyCommit)]
+pub fn verify_commit(seed_hex: &str, commitment_hex: &str) -> bool {
+    let Ok(seed) = hex::decode(seed_hex) else {
+        return false;
+    };
+    let Ok(commitment) = <[u8; 32]>::try_from(hex::decode(commitment_hex).unwrap_or_default()) else {
+        return false;
+    };
+    commit::verify_commit(&seed, &commitment)
+}
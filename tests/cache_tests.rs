@@ -0,0 +1,58 @@
+//! Tests for the verification result cache.
+#![cfg(feature = "cache")]
+
+use std::num::NonZeroUsize;
+
+use nebula_vrf::vrf::cache::VerificationCache;
+use nebula_vrf::vrf::generate_random;
+
+/// Test that a cache hit returns the same result as the first verification.
+#[test]
+fn test_cache_hit_returns_same_result() {
+    let cache = VerificationCache::new(NonZeroUsize::new(8).unwrap());
+    let vrf = generate_random(b"cached-seed").unwrap();
+
+    assert!(cache.cached_verify(b"cached-seed", &vrf.proof, &vrf.public_key));
+    assert_eq!(cache.len(), 1);
+    assert!(cache.cached_verify(b"cached-seed", &vrf.proof, &vrf.public_key));
+    assert_eq!(cache.len(), 1, "A repeat must be answered from the cache");
+
+    assert!(!cache.cached_verify(b"other-seed", &vrf.proof, &vrf.public_key));
+    assert!(!cache.cached_verify(b"other-seed", &vrf.proof, &vrf.public_key));
+    assert_eq!(cache.len(), 2, "Failures are cached too");
+}
+
+/// Test that a cached success is never reused for inputs sharing a prefix or split differently.
+#[test]
+fn test_cache_keys_cover_full_inputs() {
+    let cache = VerificationCache::new(NonZeroUsize::new(8).unwrap());
+    let vrf = generate_random(b"seed").unwrap();
+    assert!(cache.cached_verify(b"seed", &vrf.proof, &vrf.public_key));
+
+    let mut longer_seed = b"seed".to_vec();
+    longer_seed.push(0);
+    assert!(!cache.cached_verify(&longer_seed, &vrf.proof, &vrf.public_key));
+
+    let mut tampered = vrf.proof.clone();
+    *tampered.last_mut().unwrap() ^= 1;
+    assert!(!cache.cached_verify(b"seed", &tampered, &vrf.public_key));
+
+    // Moving a byte from the seed to the signature changes the key.
+    let mut shifted = vec![b'd'];
+    shifted.extend_from_slice(&vrf.proof);
+    assert!(!cache.cached_verify(b"see", &shifted, &vrf.public_key));
+}
+
+/// Test that the cache never grows past its capacity.
+#[test]
+fn test_cache_is_bounded() {
+    let cache = VerificationCache::new(NonZeroUsize::new(2).unwrap());
+    let vrf = generate_random(b"bounded").unwrap();
+    for seed in [&b"a"[..], b"b", b"c", b"bounded"] {
+        cache.cached_verify(seed, &vrf.proof, &vrf.public_key);
+    }
+    assert_eq!(cache.len(), 2);
+
+    cache.clear();
+    assert!(cache.is_empty());
+}
@@ -0,0 +1,103 @@
+//! Tests for the `vrf-cli` binary: output format and exit codes.
+#![cfg(feature = "std")]
+
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+const SEED: &str = "0123456789abcdef";
+
+fn cli(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_vrf-cli"))
+        .args(args)
+        .output()
+        .expect("failed to run vrf-cli")
+}
+
+fn json(output: &Output) -> serde_json::Value {
+    serde_json::from_slice(&output.stdout).expect("stdout is not JSON")
+}
+
+/// Test that a generated proof verifies and a tampered one exits with 1.
+#[test]
+fn test_gen_then_verify() {
+    let generated = cli(&["gen", "--seed", SEED, "--json"]);
+    assert!(generated.status.success());
+    let vrf = json(&generated);
+    let output = vrf["output"].as_str().unwrap();
+    let proof = vrf["proof"].as_str().unwrap();
+    let pubkey = vrf["public_key"].as_str().unwrap();
+
+    let verified = cli(&[
+        "verify", "--seed", SEED, "--output", output, "--proof", proof, "--pubkey", pubkey, "--json",
+    ]);
+    assert_eq!(verified.status.code(), Some(0));
+    assert_eq!(json(&verified)["valid"], true);
+
+    let rejected = cli(&[
+        "verify", "--seed", "ff", "--output", output, "--proof", proof, "--pubkey", pubkey,
+    ]);
+    assert_eq!(rejected.status.code(), Some(1));
+    assert!(String::from_utf8_lossy(&rejected.stdout).contains("valid: false"));
+}
+
+/// Test that `--seed -` reads the hex seed from stdin.
+#[test]
+fn test_seed_from_stdin() {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_vrf-cli"))
+        .args(["commit", "--seed", "-", "--json"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child.stdin.take().unwrap().write_all(format!("{}\n", SEED).as_bytes()).unwrap();
+    let from_stdin = child.wait_with_output().unwrap();
+
+    let from_arg = cli(&["commit", "--seed", SEED, "--json"]);
+    assert!(from_stdin.status.success());
+    assert_eq!(json(&from_stdin), json(&from_arg));
+}
+
+/// Test that reveal checks the salted commitment and reports usage errors.
+#[test]
+fn test_reveal_and_usage_errors() {
+    let revealed = cli(&["reveal", "--seed", SEED, "--salt", "aa", "--json"]);
+    let commitment = json(&revealed)["commitment"].as_str().unwrap().to_string();
+
+    let ok = cli(&["reveal", "--seed", SEED, "--salt", "aa", "--commitment", &commitment]);
+    assert_eq!(ok.status.code(), Some(0));
+
+    let mismatch = cli(&["reveal", "--seed", SEED, "--salt", "bb", "--commitment", &commitment]);
+    assert_eq!(mismatch.status.code(), Some(1));
+
+    assert_eq!(cli(&["gen", "--seed", "zz"]).status.code(), Some(2));
+    assert_eq!(cli(&["unknown"]).status.code(), Some(2));
+}
+
+/// Test that `--seed-file` hashes the file's raw bytes into the seed and the proof verifies.
+#[test]
+fn test_seed_file() {
+    let path = std::env::temp_dir().join(format!("vrf-cli-seed-{}.bin", std::process::id()));
+    let contents: Vec<u8> = (0..200_000u32).map(|i| (i % 251) as u8).collect();
+    std::fs::write(&path, &contents).unwrap();
+    let path_arg = path.to_str().unwrap();
+
+    let generated = cli(&["gen", "--seed-file", path_arg, "--json"]);
+    assert!(generated.status.success());
+    let vrf = json(&generated);
+    let seed = vrf["seed"].as_str().unwrap();
+    assert_eq!(seed, hex::encode(nebula_vrf::utils::hash::sha256(&contents)));
+
+    let (output, proof, pubkey) =
+        (vrf["output"].as_str().unwrap(), vrf["proof"].as_str().unwrap(), vrf["public_key"].as_str().unwrap());
+    for seed_args in [["--seed-file", path_arg], ["--seed", seed]] {
+        let mut args = vec!["verify", "--output", output, "--proof", proof, "--pubkey", pubkey];
+        args.extend(seed_args);
+        assert_eq!(cli(&args).status.code(), Some(0), "{:?}", seed_args);
+    }
+    std::fs::remove_file(&path).unwrap();
+
+    let missing = cli(&["gen", "--seed-file", path_arg]);
+    assert_eq!(missing.status.code(), Some(2));
+    assert!(String::from_utf8_lossy(&missing.stderr).contains("Failed to read --seed-file"));
+    assert_eq!(cli(&["gen", "--seed", SEED, "--seed-file", path_arg]).status.code(), Some(2));
+}
@@ -0,0 +1,92 @@
+//! Tests for the EIP-2537 point encoding in `vrf::eip2537`.
+
+use nebula_vrf::vrf::eip2537::{from_eip2537, to_eip2537, EIP2537_PUBLIC_KEY_SIZE, EIP2537_SIGNATURE_SIZE};
+use nebula_vrf::vrf::{generate_random, generate_random_with_encoding, verify_proof, Encoding, VRFError};
+
+/// The G1 generator in the EIP-2537 encoding, from the EIP: `x || y`.
+const G1_GENERATOR: &str = "\
+    0000000000000000000000000000000017f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb\
+    0000000000000000000000000000000008b3f481e3aaa0f1a09e30ed741d8ae4fcf5e095d5d00af600db18cb2c04b3edd03cc744a2888ae40caa232946c5e7e1";
+
+/// The G2 generator in the EIP-2537 encoding, from the EIP: `x.c0 || x.c1 || y.c0 || y.c1`.
+const G2_GENERATOR: &str = "\
+    00000000000000000000000000000000024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb8\
+    0000000000000000000000000000000013e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e\
+    000000000000000000000000000000000ce5d527727d6e118cc9cdc6da2e351aadfd9baa8cbdd3a76d429a695160d12c923ac9cc3baca289e193548608b82801\
+    000000000000000000000000000000000606c4a02ea734cc32acd2b02bc28b99cb3e287e85a763af267492ab572e99ab3f370d275cec1da1aaa9075ff05f79be";
+
+/// Returns the compressed blst encodings of the G1 and G2 generators, as the
+/// public keys of the secret key 1 under min-pk and min-sig.
+fn generators() -> (Vec<u8>, Vec<u8>) {
+    let mut one = [0u8; 32];
+    one[31] = 1;
+    let g1 = blst::min_pk::SecretKey::from_bytes(&one).unwrap().sk_to_pk().compress();
+    let g2 = blst::min_sig::SecretKey::from_bytes(&one).unwrap().sk_to_pk().compress();
+    (g1.to_vec(), g2.to_vec())
+}
+
+/// Test that a proof round-trips through the EIP-2537 layout back to blst bytes.
+#[test]
+fn test_eip2537_round_trip() {
+    let vrf = generate_random(b"eip2537-seed").unwrap();
+    let (public_key, signature) = vrf.to_eip2537().unwrap();
+    assert_eq!(public_key.len(), EIP2537_PUBLIC_KEY_SIZE);
+    assert_eq!(signature.len(), EIP2537_SIGNATURE_SIZE);
+    for element in public_key.chunks(64).chain(signature.chunks(64)) {
+        assert_eq!(element[..16], [0u8; 16], "Each field element must be left-padded with zeros");
+    }
+
+    let (restored_key, restored_sig) = from_eip2537(&public_key, &signature).unwrap();
+    assert_eq!(restored_key, vrf.public_key);
+    assert_eq!(restored_sig, vrf.proof);
+    assert!(verify_proof(b"eip2537-seed", &restored_sig, &restored_key).is_ok());
+
+    let uncompressed = generate_random_with_encoding(b"eip2537-seed", Encoding::Uncompressed).unwrap();
+    assert_eq!(uncompressed.to_eip2537().unwrap(), (public_key, signature), "Both blst encodings must convert alike");
+}
+
+/// Test both groups against the generators' published EIP-2537 encodings,
+/// including the `c0 || c1` order of G2 coordinates, and back to blst bytes.
+#[test]
+fn test_eip2537_generator_vectors() {
+    let (g1, g2) = generators();
+    // The generators are valid points, so either can stand in for a key or a signature.
+    #[cfg(not(feature = "min-pk"))]
+    let (public_key, signature, expected) = (g2, g1, (G2_GENERATOR, G1_GENERATOR));
+    #[cfg(feature = "min-pk")]
+    let (public_key, signature, expected) = (g1, g2, (G1_GENERATOR, G2_GENERATOR));
+
+    let (eip_key, eip_sig) = to_eip2537(&public_key, &signature).unwrap();
+    assert_eq!((hex::encode(&eip_key).as_str(), hex::encode(&eip_sig).as_str()), expected);
+
+    let expected_key = hex::decode(expected.0).unwrap();
+    let expected_sig = hex::decode(expected.1).unwrap();
+    assert_eq!(from_eip2537(&expected_key, &expected_sig).unwrap(), (public_key, signature));
+}
+
+/// Test that malformed EIP-2537 points are rejected.
+#[test]
+fn test_eip2537_rejects_malformed_points() {
+    let vrf = generate_random(b"eip2537-seed").unwrap();
+    let (public_key, signature) = vrf.to_eip2537().unwrap();
+
+    assert!(matches!(
+        from_eip2537(&public_key[1..], &signature),
+        Err(VRFError::InvalidLength { expected: EIP2537_PUBLIC_KEY_SIZE, .. })
+    ));
+
+    let mut padded = signature.clone();
+    padded[0] = 1;
+    assert!(matches!(from_eip2537(&public_key, &padded), Err(VRFError::NonCanonical)));
+
+    let identity = vec![0u8; EIP2537_SIGNATURE_SIZE];
+    assert!(matches!(from_eip2537(&public_key, &identity), Err(VRFError::IdentityPoint)));
+    // Padding is checked before the identity, so a zero point with bad padding is non-canonical.
+    let mut padded_identity = identity;
+    padded_identity[0] = 1;
+    assert!(matches!(from_eip2537(&public_key, &padded_identity), Err(VRFError::NonCanonical)));
+
+    let mut off_curve = signature;
+    off_curve[EIP2537_SIGNATURE_SIZE - 1] ^= 1;
+    assert!(from_eip2537(&public_key, &off_curve).is_err());
+}
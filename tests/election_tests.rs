@@ -0,0 +1,84 @@
+//! Tests for lowest-output leader election.
+
+use std::cmp::Ordering;
+
+use nebula_vrf::vrf::election::{compare_outputs, elect_leader, rank_participants};
+use nebula_vrf::vrf::{generate_random, VRFProof};
+
+fn with_output(output: &[u8]) -> VRFProof {
+    VRFProof { output: output.to_vec(), proof: Vec::new(), public_key: Vec::new() }
+}
+
+/// Test that outputs compare as big-endian integers.
+#[test]
+fn test_compare_outputs_big_endian() {
+    assert_eq!(compare_outputs(&[0x01, 0xff], &[0x02, 0x00]), Ordering::Less);
+    assert_eq!(compare_outputs(&[0x80, 0x00], &[0x7f, 0xff]), Ordering::Greater);
+    assert_eq!(compare_outputs(&[0xab; 32], &[0xab; 32]), Ordering::Equal);
+    // Leading zeros do not change the value.
+    assert_eq!(compare_outputs(&[0x00, 0x00, 0x05], &[0x05]), Ordering::Equal);
+    assert_eq!(compare_outputs(&[0x00, 0xff], &[0x01, 0x00]), Ordering::Less);
+    assert_eq!(compare_outputs(&[], &[0x00]), Ordering::Equal);
+}
+
+/// Test that the lowest output wins and ties go to the lower id.
+#[test]
+fn test_elect_leader_known_ordering() {
+    let mut low = [0xffu8; 32];
+    low[0] = 0x00;
+    let mut mid = [0x00u8; 32];
+    mid[0] = 0x01;
+    let high = [0xffu8; 32];
+
+    let proofs = [("carol", with_output(&high)), ("alice", with_output(&mid)), ("bob", with_output(&low))];
+    assert_eq!(elect_leader(&proofs), Some("bob"));
+
+    let tied = [(7u32, with_output(&mid)), (3u32, with_output(&mid)), (9u32, with_output(&high))];
+    assert_eq!(elect_leader(&tied), Some(3));
+
+    assert_eq!(elect_leader::<u32>(&[]), None);
+}
+
+/// Test election over real proofs for the same round seed.
+#[test]
+fn test_elect_leader_real_proofs() {
+    let seed = b"round-42";
+    let proofs: Vec<(usize, VRFProof)> =
+        (0..5).map(|id| (id, generate_random(seed).unwrap())).collect();
+
+    let winner = elect_leader(&proofs).unwrap();
+    let lowest = proofs.iter().map(|(_, p)| &p.output).min().unwrap();
+    assert_eq!(&proofs[winner].1.output, lowest);
+}
+
+/// Test that participants are ranked by ascending output with ties broken by id.
+#[test]
+fn test_rank_participants_exact_order() {
+    let output = |first: u8, last: u8| {
+        let mut bytes = [0x00u8; 32];
+        bytes[0] = first;
+        bytes[31] = last;
+        with_output(&bytes)
+    };
+    let proofs = [
+        ("dave", output(0x80, 0x00)),
+        ("erin", output(0x00, 0x02)),
+        ("carol", output(0x00, 0x01)),
+        ("bob", output(0x80, 0x00)),
+        ("alice", output(0xff, 0xff)),
+        ("frank", output(0x00, 0x01)),
+    ];
+
+    let ranked = rank_participants(&proofs);
+    assert_eq!(ranked, ["carol", "frank", "erin", "bob", "dave", "alice"]);
+    assert_eq!(ranked.first().copied(), elect_leader(&proofs));
+
+    // The order does not depend on the input order.
+    let mut reversed = proofs.to_vec();
+    reversed.reverse();
+    assert_eq!(rank_participants(&reversed), ranked);
+
+    // A top-3 committee.
+    assert_eq!(&ranked[..3], ["carol", "frank", "erin"]);
+    assert!(rank_participants::<u32>(&[]).is_empty());
+}
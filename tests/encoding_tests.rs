@@ -0,0 +1,37 @@
+//! Tests for the multibase encodings in `utils::encoding`.
+
+use nebula_vrf::utils::encoding::{decode, encode, Base};
+use nebula_vrf::vrf::{generate_random, VRFError};
+
+/// Test that proof fields round-trip through every base with the right prefix.
+#[test]
+fn test_multibase_round_trip() {
+    let vrf = generate_random(b"multibase-seed").unwrap();
+    for base in Base::ALL {
+        for bytes in [&vrf.output[..], &vrf.proof[..], &vrf.public_key[..], &[][..], &[0u8, 0, 1][..]] {
+            let text = encode(bytes, base);
+            assert!(text.starts_with(base.code()), "{:?} must use its prefix, got {}", base, text);
+            assert_eq!(decode(&text).unwrap(), (base, bytes.to_vec()), "{:?} must round-trip", base);
+        }
+    }
+}
+
+/// Test the prefixes and encodings against known multibase strings.
+#[test]
+fn test_multibase_known_encodings() {
+    assert_eq!(encode(b"yes mani !", Base::Base16), "f796573206d616e692021");
+    assert_eq!(encode(b"yes mani !", Base::Base32), "bpfsxgidnmfxgsibb");
+    assert_eq!(encode(b"yes mani !", Base::Base58Btc), "z7paNL19xttacUY");
+    assert_eq!(encode(b"yes mani !", Base::Base64), "meWVzIG1hbmkgIQ");
+    assert_eq!(encode(b"yes mani !", Base::Base64Url), "ueWVzIG1hbmkgIQ");
+}
+
+/// Test that unknown prefixes and corrupt bodies are rejected.
+#[test]
+fn test_multibase_rejects_invalid_input() {
+    assert!(matches!(decode(""), Err(VRFError::DeserializationError)));
+    assert!(matches!(decode("!abc"), Err(VRFError::DeserializationError)));
+    assert!(matches!(decode("z0OIl"), Err(VRFError::DeserializationError)));
+    // Valid multibase, but base2 is not one of the supported bases.
+    assert!(matches!(decode("001111001"), Err(VRFError::DeserializationError)));
+}
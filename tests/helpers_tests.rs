@@ -0,0 +1,193 @@
+//! Tests for the Soroban payload helpers.
+#![cfg(feature = "std")]
+
+use nebula_vrf::helpers::{
+    generate_salt_with, generate_seed_with, FixedSeedSource, SeedSource, DETERMINISTIC_SALT_LEN,
+    MAX_PAYLOAD_FIELD_LEN,
+};
+use nebula_vrf::soroban::verify_soroban_payload;
+use nebula_vrf::vrf::VRFError;
+use nebula_vrf::SamplePayload;
+use blst::min_pk::SecretKey;
+use std::collections::HashSet;
+use std::mem::{size_of, MaybeUninit};
+use std::ptr;
+
+/// Test that dropping a payload overwrites the secret key in place.
+#[test]
+fn test_secret_key_zeroized_on_drop() {
+    const KEY_SIZE: usize = 32;
+    assert_eq!(size_of::<SecretKey>(), KEY_SIZE);
+
+    let payload = SamplePayload::from_seed_salt(b"zeroize-seed".to_vec(), b"salt".to_vec()).unwrap();
+    let original = payload.secret_key().to_bytes();
+    assert_ne!(original, [0u8; KEY_SIZE]);
+
+    let mut slot = MaybeUninit::new(payload);
+    // SAFETY: the slot stays allocated after drop_in_place, and zeroize writes
+    // every byte of the key field, so reading it back reads initialized memory.
+    let after = unsafe {
+        let key_ptr = (*slot.as_ptr()).secret_key() as *const SecretKey as *const u8;
+        ptr::drop_in_place(slot.as_mut_ptr());
+        std::slice::from_raw_parts(key_ptr, KEY_SIZE).to_vec()
+    };
+
+    assert_ne!(after, original.to_vec(), "Secret key bytes must not survive drop");
+    assert_eq!(after, vec![0u8; KEY_SIZE]);
+}
+
+/// Test that wipe consumes a payload without disturbing clones.
+#[test]
+fn test_wipe_leaves_clone_intact() {
+    let payload = SamplePayload::generate(8, 8).unwrap();
+    let copy = payload.clone();
+    let key = copy.secret_key().to_bytes();
+
+    payload.wipe();
+
+    assert_eq!(copy.secret_key().to_bytes(), key);
+    assert_eq!(copy.seed.len(), 8);
+}
+
+/// Test that Debug output shows the public fields but not the secret key, seed or salt.
+#[test]
+fn test_payload_debug_hides_secrets() {
+    let payload = SamplePayload::from_seed_salt(b"debug-seed".to_vec(), b"debug-salt".to_vec()).unwrap();
+    let debug = format!("{:?}", payload);
+
+    assert!(debug.contains(&hex::encode(&payload.pubkey)), "{}", debug);
+    assert!(!debug.contains(&hex::encode(payload.secret_key().to_bytes())), "{}", debug);
+    assert!(!debug.contains("secret_key"), "{}", debug);
+    assert!(!debug.contains(&hex::encode(b"debug-seed")), "{}", debug);
+}
+
+/// Test that a freshly generated payload verifies.
+#[test]
+fn test_verify_accepts_valid_payload() {
+    let payload = SamplePayload::generate(8, 8).unwrap();
+    assert!(payload.verify().is_ok());
+}
+
+/// Test that tampering with the commitment or signature makes verify fail.
+#[test]
+fn test_verify_rejects_tampered_payload() {
+    let payload = SamplePayload::from_seed_salt(b"tamper-seed".to_vec(), b"salt".to_vec()).unwrap();
+
+    let mut bad_commitment = payload.clone();
+    bad_commitment.commitment[0] ^= 0x01;
+    assert!(bad_commitment.verify().is_err(), "Tampered commitment must not verify");
+
+    let mut bad_signature = payload.clone();
+    let last = bad_signature.signature.len() - 1;
+    bad_signature.signature[last] ^= 0x01;
+    assert!(bad_signature.verify().is_err(), "Tampered signature must not verify");
+
+    let other = SamplePayload::from_seed_salt(b"other-seed".to_vec(), b"salt".to_vec()).unwrap();
+    let mut wrong_key = payload.clone();
+    wrong_key.pubkey = other.pubkey.clone();
+    assert!(wrong_key.verify().is_err(), "Signature must not verify under another key");
+}
+
+/// Test that a fixed source makes seeds, salts, and payloads reproducible.
+#[test]
+fn test_fixed_seed_source_is_deterministic() {
+    let mut source = FixedSeedSource::new([1, 2, 3]);
+    assert_eq!(generate_seed_with(&mut source, 4), vec![1, 2, 3, 1]);
+    assert_eq!(generate_salt_with(&mut source, 3), vec![2, 3, 1]);
+
+    let mut zeros = FixedSeedSource::new(Vec::new());
+    let mut buf = [0xffu8; 4];
+    zeros.fill(&mut buf);
+    assert_eq!(buf, [0; 4]);
+
+    let a = SamplePayload::generate_with(&mut FixedSeedSource::new(*b"seedsalt"), 4, 4).unwrap();
+    let b = SamplePayload::generate_with(&mut FixedSeedSource::new(*b"seedsalt"), 4, 4).unwrap();
+    assert_eq!(a.seed, b"seed");
+    assert_eq!(a.salt, b"salt");
+    assert_eq!(a.pubkey, b.pubkey);
+    assert_eq!(a.signature, b.signature);
+
+    let expected = SamplePayload::from_seed_salt(b"seed".to_vec(), b"salt".to_vec()).unwrap();
+    assert_eq!(a.commitment, expected.commitment);
+}
+
+/// Test that a deterministic payload is a pure function of its seed.
+#[test]
+fn test_from_seed_deterministic_is_reproducible() {
+    let a = SamplePayload::from_seed_deterministic(b"debug-seed".to_vec()).unwrap();
+    let b = SamplePayload::from_seed_deterministic(b"debug-seed".to_vec()).unwrap();
+    assert_eq!(a.salt, b.salt);
+    assert_eq!(a.salt.len(), DETERMINISTIC_SALT_LEN);
+    assert_eq!(a.commitment, b.commitment);
+    assert_eq!(a.pubkey, b.pubkey);
+    assert_eq!(a.signature, b.signature);
+    assert!(a.verify().is_ok());
+
+    let other = SamplePayload::from_seed_deterministic(b"other-seed".to_vec()).unwrap();
+    assert_ne!(other.salt, a.salt);
+    assert_ne!(other.commitment, a.commitment);
+    assert!(SamplePayload::from_seed_deterministic(Vec::new()).is_err());
+}
+
+/// Test that batch generation yields independent payloads.
+#[test]
+fn test_generate_many_unique_commitments() {
+    const COUNT: usize = 500;
+    let payloads = SamplePayload::generate_many(COUNT, 16, 16).unwrap();
+    assert_eq!(payloads.len(), COUNT);
+
+    let commitments: HashSet<[u8; 32]> = payloads.iter().map(|p| p.commitment).collect();
+    assert_eq!(commitments.len(), COUNT, "Every payload must have its own seed and salt");
+    assert!(payloads.iter().take(5).all(|p| p.verify().is_ok()));
+}
+
+/// Test that a generated payload passes the contract's check and tampering fails it.
+#[test]
+fn test_verify_soroban_payload() {
+    let payload = SamplePayload::generate(8, 8).unwrap();
+    assert!(verify_soroban_payload(&payload.commitment, &payload.pubkey, &payload.signature).is_ok());
+
+    let mut commitment = payload.commitment;
+    commitment[0] ^= 0x01;
+    assert!(matches!(
+        verify_soroban_payload(&commitment, &payload.pubkey, &payload.signature),
+        Err(VRFError::VerificationFailed)
+    ));
+
+    let mut signature = payload.signature.clone();
+    let last = signature.len() - 1;
+    signature[last] ^= 0x01;
+    assert!(verify_soroban_payload(&payload.commitment, &payload.pubkey, &signature).is_err());
+
+    let other = SamplePayload::generate(8, 8).unwrap();
+    assert!(matches!(
+        verify_soroban_payload(&payload.commitment, &payload.pubkey, &other.signature),
+        Err(VRFError::VerificationFailed)
+    ));
+
+    assert!(matches!(
+        verify_soroban_payload(&payload.commitment, &payload.pubkey, &payload.signature[..96]),
+        Err(VRFError::InvalidLength { expected: 192, got: 96 })
+    ));
+}
+
+/// Test that empty and oversized seeds and salts are rejected with a named field.
+#[test]
+fn test_payload_length_bounds() {
+    let err = SamplePayload::generate(0, 8).unwrap_err();
+    assert!(err.contains("Seed") && err.contains("empty"), "{}", err);
+    let err = SamplePayload::generate(8, 0).unwrap_err();
+    assert!(err.contains("Salt") && err.contains("empty"), "{}", err);
+
+    // Rejected before any allocation, so this cannot exhaust memory.
+    let err = SamplePayload::generate(usize::MAX, 8).unwrap_err();
+    assert!(err.contains("Seed") && err.contains(&MAX_PAYLOAD_FIELD_LEN.to_string()), "{}", err);
+    assert!(SamplePayload::generate(8, MAX_PAYLOAD_FIELD_LEN + 1).is_err());
+
+    assert!(SamplePayload::generate(1, 1).is_ok());
+    assert!(SamplePayload::generate(MAX_PAYLOAD_FIELD_LEN, MAX_PAYLOAD_FIELD_LEN).is_ok());
+
+    assert!(SamplePayload::from_seed_salt(Vec::new(), b"salt".to_vec()).is_err());
+    assert!(SamplePayload::from_seed_salt(b"seed".to_vec(), Vec::new()).is_err());
+    assert!(SamplePayload::from_seed_salt(vec![1; MAX_PAYLOAD_FIELD_LEN + 1], b"salt".to_vec()).is_err());
+}
@@ -0,0 +1,180 @@
+//! Known-answer tests pinning the NebulaVRF wire format.
+//!
+//! The vectors below were produced by the current implementation. Any change to
+//! the DST, suite string, hash, or point encoding will make these tests fail.
+
+use nebula_vrf::vrf::commit::{commit_hmac, verify_commit_hmac};
+use nebula_vrf::vrf::{
+    derive_keypair, generate_random, generate_random_with_personalization, verify_output, VRF_IKM_TAG,
+};
+
+/// (seed_hex, expected_output_hex, expected_pubkey_hex) for the default min-sig scheme.
+#[cfg(all(not(feature = "min-pk"), not(feature = "personalized-ikm")))]
+const VECTORS: &[(&str, &str, &str)] = &[
+    (
+        "616263",
+        "17a6c9c6eaf247ceb6fcd7fdccd510c0f896b101747af34421346a6202f56b4b",
+        "90119ff0bccd3cb20e7e5b23584de4fc9690ce4676ce9355116bbff38a35bd6e9fa10925253cb798ae1634791837e3e419dd91c4e1b93f2c3dd6087cac6c6298ecfd77e97f4d294c4fdc43e63a13dcb6dcd3d37f18d153301fd46ac43d2e0f5d",
+    ),
+    (
+        "6e6562756c612d767266",
+        "88990efb9b35b89fa7e92ec6b0ef842cec5ba583cc9c2f315ce185a7401a134c",
+        "acadfed64c2a5216a1b93d553f95e3fd410634194a20d22dc4fed61aa2f766c4a3d0933c377e701f341beb8e3019965911ba0d0db54f984ba3768543550579ec686e3cd034315b0a92a8721993cb2c09e9a54365f2cee84197e9aa90971f0d6a",
+    ),
+    (
+        "0000000000000000000000000000000000000000000000000000000000000000",
+        "04496970bebd6f2d2dfabee9cc65d76e1b93fecfa545ba22b53422cd6ba260ca",
+        "91ad079d6ca808498c580d177344375038705d804e4e7b675366bffc7af630cc29ca303dfa4975a247f41657848ee7fc19356afd28d6956539823fa14c8faa01eb3e62120c2500fb37fb2b8edc3ab430de2a1e3171ad46ce37c22a29023aec25",
+    ),
+    (
+        "54686520717569636b2062726f776e20666f78206a756d7073206f76657220746865206c617a7920646f67",
+        "d532f134c75cc5c9db010f0fe01f5f06990b991d8efe6020558fb2bb5361b065",
+        "acf672bad5beb3797e7db0d59f32767a623a4b7d6548a4cb332f63f56a2d59487eed3d7b58ef5ba2a6581366dbcd418d078dfa5144a6984b9ac3ddc0c659ba824007c734bb57b58b329487288519ebd25a0e1e18db52c9d19e569a14a4ab54f7",
+    ),
+];
+
+/// (seed_hex, expected_output_hex, expected_pubkey_hex) for the min-pk scheme.
+#[cfg(all(feature = "min-pk", not(feature = "personalized-ikm")))]
+const VECTORS: &[(&str, &str, &str)] = &[
+    (
+        "616263",
+        "eb3e9d0a14c0b68cb6a4c12c4e07e0957cd9e84067429c97ef8489dd7f0fe4e8",
+        "8052ca0750f15c19bae2ff2ce044f690b95cfd9218573ff4005177c371846d375c8a2a50b8b6ff59030c2922452423f7",
+    ),
+    (
+        "6e6562756c612d767266",
+        "25a9ef059a93a2ae24d1ac9a7e1c336abc6f70705b7155ec97e63420f45b2725",
+        "96c8e8a4d41caacd10b4cece2e8bdef36e492870e1037d4823b88533aeaa18a9d980a8c86100869045682c57f86b496b",
+    ),
+    (
+        "0000000000000000000000000000000000000000000000000000000000000000",
+        "f3904096e392e530d42735ab2060ec526fee34230a00e2fa4023dda84f4a5d62",
+        "90c6510ba6042b6b97aa72467924e02e314d724607c809de919c6a5d506829f2e65c6db1347af3ef792579e3e1d5fcff",
+    ),
+    (
+        "54686520717569636b2062726f776e20666f78206a756d7073206f76657220746865206c617a7920646f67",
+        "e3db8c7e7a54a6e1d2fcef5e075d5a9bc58c61e74d45c3633f1e222118128ebb",
+        "95710512f19258a6816cdb08581b8ecc82f6d8e207bea8afb1c1ccf03fbb3f5ca3f88416ab49f469a60500257944e6c2",
+    ),
+];
+
+/// Public key of `derive_keypair(&[42; 32], b"round-1")` for the selected scheme.
+#[cfg(not(feature = "min-pk"))]
+const DERIVED_PUBKEY: &str = "832d03ea4ac655ab99115aac0ca4c2dc1d8924736619c4c949d3d45932be1b168a8d0b78613f0e5eefd95abf4ccf30220498459c7d6d0ae0c95717ecd649884b13cd5b7714eea917db0161d026589d92cd189be917036aaa1aa7975e8b1378f9";
+#[cfg(feature = "min-pk")]
+const DERIVED_PUBKEY: &str = "b8ed6127287d97f2672f445e0cf6a3a05d3240add7afd73a105111906c5ba9e9a5eca99dda140efb545281566028a608";
+
+/// (output_hex, pubkey_hex) of `generate_random_with_personalization(b"abc", VRF_IKM_TAG)`.
+#[cfg(not(feature = "min-pk"))]
+const PERSONALIZED_ABC: (&str, &str) = (
+    "c2129b896bc418f03b4e911f3a305c16017b2957b3ff8ab27f93dffc4b99c8ad",
+    "962b4178e88226c6150964156cc7fb33488cbb0af24df0e26112f0462c6844cc6960b636e3483099eee1d80a595fecf717390ae06fb28197bde6c352b0bbcafc40d77c898da47ab97610e8f6c006c2a87f73572b87cc4c08cc33b19234827e97",
+);
+#[cfg(feature = "min-pk")]
+const PERSONALIZED_ABC: (&str, &str) = (
+    "8b43637b105b4b5beaaf101c5cb778dc998865821035b73d5f278667ce281066",
+    "930094718c27b9ff9b98211530a6571d0e44c92c729bc5bd198733206cdc72cfd501fd3338573f2c7bb4f16f5576bf68",
+);
+
+/// Test that generate_random reproduces every known answer exactly.
+///
+/// The vectors use the bare `sha256(seed)` key derivation, so they do not apply
+/// under `personalized-ikm`.
+#[cfg(not(feature = "personalized-ikm"))]
+#[test]
+fn test_generate_random_matches_known_answers() {
+    for (seed_hex, output_hex, pubkey_hex) in VECTORS {
+        let seed = hex::decode(seed_hex).unwrap();
+        let vrf = generate_random(&seed).unwrap();
+        assert_eq!(hex::encode(&vrf.output), *output_hex, "output mismatch for seed {}", seed_hex);
+        assert_eq!(hex::encode(&vrf.public_key), *pubkey_hex, "public key mismatch for seed {}", seed_hex);
+    }
+}
+
+/// Test that the known answers verify against freshly generated proofs.
+#[cfg(not(feature = "personalized-ikm"))]
+#[test]
+fn test_known_answers_verify() {
+    for (seed_hex, output_hex, pubkey_hex) in VECTORS {
+        let seed = hex::decode(seed_hex).unwrap();
+        let output = hex::decode(output_hex).unwrap();
+        let pubkey = hex::decode(pubkey_hex).unwrap();
+        let vrf = generate_random(&seed).unwrap();
+        assert!(verify_output(&seed, &output, &vrf.proof, &pubkey).is_ok());
+    }
+}
+
+/// Test that the personalized key derivation reproduces its known answer and
+/// differs from the bare derivation.
+#[test]
+fn test_personalized_generation_matches_known_answer() {
+    let vrf = generate_random_with_personalization(b"abc", VRF_IKM_TAG).unwrap();
+    assert_eq!(hex::encode(&vrf.output), PERSONALIZED_ABC.0);
+    assert_eq!(hex::encode(&vrf.public_key), PERSONALIZED_ABC.1);
+    assert!(verify_output(b"abc", &vrf.output, &vrf.proof, &vrf.public_key).is_ok());
+
+    let other_tag = generate_random_with_personalization(b"abc", b"OTHER-APP-IKM").unwrap();
+    assert_ne!(other_tag.public_key, vrf.public_key);
+
+    // The default derivation is bare unless the feature opts in to the tag.
+    let default = generate_random(b"abc").unwrap();
+    assert_eq!(default.public_key == vrf.public_key, cfg!(feature = "personalized-ikm"));
+}
+
+/// Test that derive_keypair is stable across builds and processes.
+#[test]
+fn test_derive_keypair_matches_known_answer() {
+    let keypair = derive_keypair(&[42u8; 32], b"round-1");
+    assert_eq!(hex::encode(keypair.public_key()), DERIVED_PUBKEY);
+}
+
+/// (key_hex, data_hex, expected_tag_hex) from RFC 4231, test cases 1-4 and 6-7.
+/// Test case 5 checks a truncated tag and does not apply.
+const HMAC_SHA256_VECTORS: &[(&str, &str, &str)] = &[
+    (
+        "0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b",
+        "4869205468657265",
+        "b0344c61d8db38535ca8afceaf0bf12b881dc200c9833da726e9376c2e32cff7",
+    ),
+    (
+        "4a656665",
+        "7768617420646f2079612077616e7420666f72206e6f7468696e673f",
+        "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843",
+    ),
+    (
+        "aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+        "dddddddddddddddddddddddddddddddddddddddddddddddddddddddddddddddddddddddddddddddddddddddddddddddddddd",
+        "773ea91e36800e46854db8ebd09181a72959098b3ef8c122d9635514ced565fe",
+    ),
+    (
+        "0102030405060708090a0b0c0d0e0f10111213141516171819",
+        "cdcdcdcdcdcdcdcdcdcdcdcdcdcdcdcdcdcdcdcdcdcdcdcdcdcdcdcdcdcdcdcdcdcdcdcdcdcdcdcdcdcdcdcdcdcdcdcdcdcd",
+        "82558a389a443c0ea4cc819899f2083a85f0faa3e578f8077a2e3ff46729665b",
+    ),
+    (
+        "aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+        "54657374205573696e67204c6172676572205468616e20426c6f636b2d53697a65204b6579202d2048617368204b6579204669727374",
+        "60e431591ee0b67f0d8a26aacbf5b77f8e0bc6213728c5140546040f0ee37f54",
+    ),
+    (
+        "aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+        "5468697320697320612074657374207573696e672061206c6172676572207468616e20626c6f636b2d73697a65206b657920616e642061206c6172676572207468616e20626c6f636b2d73697a6520646174612e20546865206b6579206e6565647320746f20626520686173686564206265666f7265206265696e6720757365642062792074686520484d414320616c676f726974686d2e",
+        "9b09ffa71b942fcb27635fbcd5b0e944bfdc63644f0713938a7f51535c3a35e2",
+    ),
+];
+
+/// Test that commit_hmac matches the RFC 4231 HMAC-SHA256 vectors.
+#[test]
+fn test_commit_hmac_matches_rfc4231() {
+    for (key_hex, data_hex, tag_hex) in HMAC_SHA256_VECTORS {
+        let key = hex::decode(key_hex).unwrap();
+        let data = hex::decode(data_hex).unwrap();
+        let tag: [u8; 32] = hex::decode(tag_hex).unwrap().try_into().unwrap();
+        assert_eq!(commit_hmac(&key, &data), tag, "tag mismatch for key {}", key_hex);
+        assert!(verify_commit_hmac(&key, &data, &tag));
+
+        let mut wrong = tag;
+        wrong[31] ^= 0x01;
+        assert!(!verify_commit_hmac(&key, &data, &wrong));
+    }
+}
@@ -0,0 +1,120 @@
+//! Tests for Merkle-tree commitments over many seeds.
+
+use nebula_vrf::vrf::commit::commit;
+use nebula_vrf::vrf::merkle::{verify, MerkleCommitment, MerkleProof};
+
+fn seeds(count: usize) -> Vec<Vec<u8>> {
+    (0..count).map(|i| format!("seed-{}", i).into_bytes()).collect()
+}
+
+fn build(seeds: &[Vec<u8>]) -> MerkleCommitment {
+    let refs: Vec<&[u8]> = seeds.iter().map(Vec::as_slice).collect();
+    MerkleCommitment::build(&refs)
+}
+
+/// Test that every leaf opens against the root for even and odd tree sizes.
+#[test]
+fn test_every_leaf_verifies() {
+    for count in [1, 2, 3, 5, 8, 13] {
+        let seeds = seeds(count);
+        let tree = build(&seeds);
+        assert_eq!(tree.len(), count);
+        for (index, seed) in seeds.iter().enumerate() {
+            let proof = tree.open(index);
+            assert!(verify(&tree.root(), seed, &proof, index, count), "leaf {} of {} failed", index, count);
+        }
+    }
+}
+
+/// Test that a single-seed tree's root is the prefixed leaf hash, not the plain commitment.
+#[test]
+fn test_single_leaf_root_is_prefixed() {
+    let tree = MerkleCommitment::build(&[b"only-seed"]);
+    let mut leaf = vec![0x00];
+    leaf.extend_from_slice(&commit(b"only-seed"));
+    assert_eq!(tree.root(), commit(&leaf));
+    assert!(tree.open(0).siblings.is_empty());
+    assert!(verify(&tree.root(), b"only-seed", &tree.open(0), 0, 1));
+}
+
+/// Test that odd layers pair the last node with itself.
+#[test]
+fn test_odd_layer_duplicates_last_node() {
+    let three = build(&seeds(3));
+    let mut four = seeds(3);
+    four.push(four[2].clone());
+    assert_eq!(three.root(), build(&four).root());
+}
+
+/// Test that wrong seeds, indices, proofs, and roots are rejected.
+#[test]
+fn test_tampering_is_rejected() {
+    let seeds = seeds(6);
+    let tree = build(&seeds);
+    let proof = tree.open(2);
+
+    assert!(!verify(&tree.root(), b"not-a-seed", &proof, 2, 6));
+    assert!(!verify(&tree.root(), &seeds[2], &proof, 3, 6));
+    assert!(!verify(&tree.root(), &seeds[2], &proof, 2 + 8, 6));
+    assert!(!verify(&tree.root(), &seeds[2], &proof, 2, 0));
+
+    let mut bad_proof = proof.clone();
+    bad_proof.siblings[1][0] ^= 1;
+    assert!(!verify(&tree.root(), &seeds[2], &bad_proof, 2, 6));
+
+    let mut bad_root = tree.root();
+    bad_root[31] ^= 1;
+    assert!(!verify(&bad_root, &seeds[2], &proof, 2, 6));
+}
+
+/// Test that an internal node cannot be opened as a leaf by revealing its
+/// children as a 64-byte seed with the shorter path above it.
+#[test]
+fn test_internal_node_opening_is_rejected() {
+    let tree = build(&seeds(4));
+    let left = tree.open(1).siblings[0];
+    let right = tree.open(0).siblings[0];
+    let fake_seed = [left, right].concat();
+    let short_path = MerkleProof { siblings: tree.open(0).siblings[1..].to_vec() };
+
+    assert!(!verify(&tree.root(), &fake_seed, &short_path, 0, 4));
+    // Even with a leaf count that makes the path length fit, the leaf prefix differs.
+    assert!(!verify(&tree.root(), &fake_seed, &short_path, 0, 2));
+}
+
+/// Test that the duplicated last leaf of an odd tree cannot be opened past the
+/// committed seed count.
+#[test]
+fn test_duplicate_index_is_rejected() {
+    let seeds = seeds(3);
+    let tree = build(&seeds);
+    let proof = tree.open(2);
+    assert!(verify(&tree.root(), &seeds[2], &proof, 2, 3));
+    assert!(!verify(&tree.root(), &seeds[2], &proof, 3, 3));
+}
+
+/// Test that paths longer or shorter than the leaf count implies are rejected.
+#[test]
+fn test_path_length_must_match_leaf_count() {
+    let seeds = seeds(5);
+    let tree = build(&seeds);
+    let proof = tree.open(1);
+    assert!(verify(&tree.root(), &seeds[1], &proof, 1, 5));
+    assert!(!verify(&tree.root(), &seeds[1], &proof, 1, 4));
+    assert!(!verify(&tree.root(), &seeds[1], &proof, 1, 9));
+}
+
+/// Test that an empty tree has an all-zero root.
+#[test]
+fn test_empty_tree() {
+    let tree = MerkleCommitment::build(&[]);
+    assert!(tree.is_empty());
+    assert_eq!(tree.root(), [0u8; 32]);
+}
+
+/// Test that opening an index past the last seed panics.
+#[test]
+#[should_panic(expected = "out of range")]
+fn test_open_out_of_range_panics() {
+    build(&seeds(4)).open(4);
+}
@@ -0,0 +1,46 @@
+//! Tests for BIP-39 mnemonic key backups.
+#![cfg(feature = "mnemonic")]
+
+use nebula_vrf::vrf::types::VRFError;
+use nebula_vrf::vrf::{verify_proof, VrfKeypair};
+
+/// The first BIP-39 reference mnemonic, used with the reference passphrase `TREZOR`.
+const PHRASE: &str =
+    "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+/// Public key of `VrfKeypair::from_mnemonic(PHRASE, "TREZOR")` for the selected scheme.
+#[cfg(not(feature = "min-pk"))]
+const PHRASE_PUBKEY: &str = "a5e43d5ecb7b8c01ceb3b91f7413b628ef02c6859dc42a4354b21f9195531988a648655037faafd1bac2fd2d7d9466180baa3705a45a6c597853db51eaf431616057fd8049c6bee8764292f9a104200a45a63ceae9d3c368643ab9e5ff0f8810";
+#[cfg(feature = "min-pk")]
+const PHRASE_PUBKEY: &str = "a2c975348667926acf12f3eecb005044e08a7a9b7d95f30bd281b55445107367a2e5d0558be7943c8bd13f9a1a7036fb";
+
+/// Test that a known mnemonic and passphrase restore a stable public key.
+#[test]
+fn test_from_mnemonic_matches_known_answer() {
+    let keypair = VrfKeypair::from_mnemonic(PHRASE, "TREZOR").unwrap();
+    assert_eq!(hex::encode(keypair.public_key()), PHRASE_PUBKEY);
+
+    let other = VrfKeypair::from_mnemonic(PHRASE, "").unwrap();
+    assert_ne!(other.public_key(), keypair.public_key(), "The passphrase must change the key");
+}
+
+/// Test that a generated mnemonic restores the same signing key.
+#[test]
+fn test_generate_with_mnemonic_round_trip() {
+    let (keypair, phrase) = VrfKeypair::generate_with_mnemonic("backup-pass");
+    assert_eq!(phrase.split(' ').count(), 24);
+
+    let restored = VrfKeypair::from_mnemonic(&phrase, "backup-pass").unwrap();
+    assert_eq!(restored.public_key(), keypair.public_key());
+    let vrf = restored.prove(b"mnemonic-seed");
+    assert_eq!(vrf, keypair.prove(b"mnemonic-seed"));
+    assert!(verify_proof(b"mnemonic-seed", &vrf.proof, &keypair.public_key()).is_ok());
+}
+
+/// Test that phrases with a bad checksum or unknown words are rejected.
+#[test]
+fn test_from_mnemonic_rejects_invalid_phrases() {
+    let bad_checksum = PHRASE.replace("about", "abandon");
+    assert!(matches!(VrfKeypair::from_mnemonic(&bad_checksum, ""), Err(VRFError::DeserializationError)));
+    assert!(matches!(VrfKeypair::from_mnemonic("not a mnemonic", ""), Err(VRFError::DeserializationError)));
+}
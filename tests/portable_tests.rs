@@ -0,0 +1,28 @@
+//! Tests for the `portable` feature, which builds blst without its assembly backend.
+#![cfg(feature = "portable")]
+
+use nebula_vrf::vrf::types::VRFError;
+use nebula_vrf::vrf::{generate_random, proof_to_hash, verify_proof, VRF_PUBLIC_KEY_SIZE, VRF_SIGNATURE_SIZE};
+
+/// Test that generation and verification work on the portable backend.
+#[test]
+fn test_portable_backend_round_trip() {
+    let vrf = generate_random(b"portable-seed").unwrap();
+    assert_eq!(vrf.proof.len(), VRF_SIGNATURE_SIZE);
+    assert_eq!(vrf.public_key.len(), VRF_PUBLIC_KEY_SIZE);
+    assert_eq!(vrf.randomness().unwrap(), proof_to_hash(&vrf.proof).unwrap());
+
+    verify_proof(b"portable-seed", &vrf.proof, &vrf.public_key).unwrap();
+    assert!(matches!(
+        verify_proof(b"other-seed", &vrf.proof, &vrf.public_key),
+        Err(VRFError::VerificationFailed)
+    ));
+}
+
+/// Test that the portable backend reproduces the assembly backend's output for a known seed.
+#[cfg(all(not(feature = "min-pk"), not(feature = "personalized-ikm")))]
+#[test]
+fn test_portable_backend_matches_known_answer() {
+    let vrf = generate_random(b"abc").unwrap();
+    assert_eq!(hex::encode(vrf.output), "17a6c9c6eaf247ceb6fcd7fdccd510c0f896b101747af34421346a6202f56b4b");
+}
@@ -0,0 +1,80 @@
+//! Property-based tests for commit-reveal and VRF sign/verify round-trips.
+//!
+//! proptest shrinks failing inputs, so a counterexample is reported as the
+//! smallest seed and bit position that breaks the property.
+
+use nebula_vrf::vrf::commit::{commit, verify_commit};
+use nebula_vrf::vrf::{generate_random, verify_output, verify_proof};
+use proptest::prelude::*;
+
+const MAX_SEED_LEN: usize = 256;
+
+proptest! {
+    /// A seed always opens its own commitment.
+    #[test]
+    fn commit_round_trips(seed in prop::collection::vec(any::<u8>(), 0..MAX_SEED_LEN)) {
+        prop_assert!(verify_commit(&seed, &commit(&seed)));
+    }
+
+    /// A different seed never opens the commitment.
+    #[test]
+    fn commit_rejects_other_seed(
+        seed in prop::collection::vec(any::<u8>(), 0..MAX_SEED_LEN),
+        other in prop::collection::vec(any::<u8>(), 0..MAX_SEED_LEN),
+    ) {
+        prop_assume!(seed != other);
+        prop_assert!(!verify_commit(&other, &commit(&seed)));
+    }
+}
+
+proptest! {
+    // Each case performs several pairings, so keep the case count modest.
+    #![proptest_config(ProptestConfig::with_cases(64))]
+
+    /// Fresh proofs always verify.
+    #[test]
+    fn proof_round_trips(seed in prop::collection::vec(any::<u8>(), 0..MAX_SEED_LEN)) {
+        let vrf = generate_random(&seed).unwrap();
+        prop_assert!(verify_proof(&seed, &vrf.proof, &vrf.public_key).is_ok());
+        prop_assert!(verify_output(&seed, &vrf.output, &vrf.proof, &vrf.public_key).is_ok());
+    }
+
+    /// Flipping any single bit of the proof makes verification fail.
+    #[test]
+    fn proof_bit_flip_fails(
+        seed in prop::collection::vec(any::<u8>(), 0..MAX_SEED_LEN),
+        bit in any::<prop::sample::Index>(),
+    ) {
+        let vrf = generate_random(&seed).unwrap();
+        let mut proof = vrf.proof.clone();
+        let bit = bit.index(proof.len() * 8);
+        proof[bit / 8] ^= 1 << (bit % 8);
+        prop_assert!(verify_proof(&seed, &proof, &vrf.public_key).is_err());
+    }
+
+    /// Flipping any single bit of the claimed output makes output verification fail.
+    #[test]
+    fn output_bit_flip_fails(
+        seed in prop::collection::vec(any::<u8>(), 0..MAX_SEED_LEN),
+        bit in any::<prop::sample::Index>(),
+    ) {
+        let vrf = generate_random(&seed).unwrap();
+        let mut output = vrf.output.clone();
+        let bit = bit.index(output.len() * 8);
+        output[bit / 8] ^= 1 << (bit % 8);
+        prop_assert!(verify_output(&seed, &output, &vrf.proof, &vrf.public_key).is_err());
+    }
+
+    /// Flipping any single bit of the public key makes verification fail.
+    #[test]
+    fn public_key_bit_flip_fails(
+        seed in prop::collection::vec(any::<u8>(), 0..MAX_SEED_LEN),
+        bit in any::<prop::sample::Index>(),
+    ) {
+        let vrf = generate_random(&seed).unwrap();
+        let mut public_key = vrf.public_key.clone();
+        let bit = bit.index(public_key.len() * 8);
+        public_key[bit / 8] ^= 1 << (bit % 8);
+        prop_assert!(verify_proof(&seed, &vrf.proof, &public_key).is_err());
+    }
+}
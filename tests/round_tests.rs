@@ -0,0 +1,87 @@
+//! Tests for the commit-reveal round state machine.
+
+use nebula_vrf::vrf::commit::commit_with_salt;
+use nebula_vrf::vrf::round::{CommitRevealRound, RoundError, RoundPhase};
+
+const DEADLINE: u64 = 100;
+
+fn seed(byte: u8) -> [u8; 32] {
+    [byte; 32]
+}
+
+/// Opens a round in its reveal phase with "alice" and "bob" committed.
+fn committed_round() -> CommitRevealRound<&'static str> {
+    let mut round = CommitRevealRound::new(DEADLINE);
+    round.submit_commit("alice", commit_with_salt(&seed(0x0f), b"salt-a"), 10).unwrap();
+    round.submit_commit("bob", commit_with_salt(&seed(0xf0), b"salt-b"), DEADLINE).unwrap();
+    round.start_reveal().unwrap();
+    round
+}
+
+/// Test that a full round yields the XOR of every revealed seed.
+#[test]
+fn test_round_happy_path() {
+    let mut round = committed_round();
+    assert_eq!(round.phase(), RoundPhase::Reveal);
+    assert_eq!(round.num_commits(), 2);
+
+    round.submit_reveal(&"alice", &seed(0x0f), b"salt-a").unwrap();
+    round.submit_reveal(&"bob", &seed(0xf0), b"salt-b").unwrap();
+    assert_eq!(round.num_reveals(), 2);
+    assert_eq!(round.finalize().unwrap(), [0xff; 32]);
+}
+
+/// Test that commits are rejected after the deadline, twice per user, or once reveals start.
+#[test]
+fn test_round_rejects_bad_commits() {
+    let mut round = CommitRevealRound::new(DEADLINE);
+    let commitment = commit_with_salt(&seed(1), b"salt");
+
+    assert_eq!(
+        round.submit_commit("late", commitment, DEADLINE + 1),
+        Err(RoundError::CommitAfterDeadline { deadline: DEADLINE, now: DEADLINE + 1 })
+    );
+    round.submit_commit("alice", commitment, 0).unwrap();
+    assert_eq!(round.submit_commit("alice", commitment, 1), Err(RoundError::AlreadyCommitted));
+
+    round.start_reveal().unwrap();
+    assert_eq!(
+        round.submit_commit("bob", commitment, 1),
+        Err(RoundError::WrongPhase { phase: RoundPhase::Reveal })
+    );
+    assert_eq!(round.start_reveal(), Err(RoundError::WrongPhase { phase: RoundPhase::Reveal }));
+    assert_eq!(round.num_commits(), 1);
+}
+
+/// Test that reveals are rejected before the reveal phase, from non-committers, on mismatch, and twice.
+#[test]
+fn test_round_rejects_bad_reveals() {
+    let mut early = CommitRevealRound::new(DEADLINE);
+    early.submit_commit("alice", commit_with_salt(&seed(1), b"salt"), 0).unwrap();
+    assert_eq!(
+        early.submit_reveal(&"alice", &seed(1), b"salt"),
+        Err(RoundError::WrongPhase { phase: RoundPhase::Commit })
+    );
+
+    let mut round = committed_round();
+    assert_eq!(round.submit_reveal(&"mallory", &seed(0x0f), b"salt-a"), Err(RoundError::NotCommitted));
+    assert_eq!(round.submit_reveal(&"alice", &seed(0x0e), b"salt-a"), Err(RoundError::InvalidReveal));
+    assert_eq!(round.submit_reveal(&"alice", &seed(0x0f), b"salt-b"), Err(RoundError::InvalidReveal));
+
+    round.submit_reveal(&"alice", &seed(0x0f), b"salt-a").unwrap();
+    assert_eq!(round.submit_reveal(&"alice", &seed(0x0f), b"salt-a"), Err(RoundError::AlreadyRevealed));
+    assert_eq!(round.num_reveals(), 1);
+}
+
+/// Test that finalize requires the reveal phase and at least one reveal, and skips withheld seeds.
+#[test]
+fn test_round_finalize_rules() {
+    let round: CommitRevealRound<&str> = CommitRevealRound::new(DEADLINE);
+    assert_eq!(round.finalize(), Err(RoundError::WrongPhase { phase: RoundPhase::Commit }));
+
+    assert_eq!(committed_round().finalize(), Err(RoundError::NoReveals));
+
+    let mut round = committed_round();
+    round.submit_reveal(&"bob", &seed(0xf0), b"salt-b").unwrap();
+    assert_eq!(round.finalize().unwrap(), seed(0xf0));
+}
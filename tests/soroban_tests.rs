@@ -0,0 +1,34 @@
+//! Tests for the `ScVal` conversions of the `soroban` feature.
+
+#![cfg(feature = "soroban")]
+
+use nebula_vrf::vrf::generate_random;
+use nebula_vrf::SamplePayload;
+use stellar_xdr::curr::{Limits, ReadXdr, ScVal, WriteXdr};
+
+/// Encodes a value to XDR, decodes it again, and returns the wrapped bytes.
+fn xdr_round_trip(value: ScVal) -> Vec<u8> {
+    let xdr = value.to_xdr(Limits::none()).unwrap();
+    match ScVal::from_xdr(xdr, Limits::none()).unwrap() {
+        ScVal::Bytes(bytes) => bytes.into(),
+        other => panic!("Expected ScVal::Bytes, got {:?}", other),
+    }
+}
+
+/// Test that a proof's ScVal round-trips through XDR to the proof bytes.
+#[test]
+fn test_proof_scval_round_trip() {
+    let vrf = generate_random(b"soroban-xdr").unwrap();
+    assert_eq!(xdr_round_trip(vrf.to_xdr_scval()), vrf.proof);
+}
+
+/// Test that every payload field's ScVal round-trips through XDR.
+#[test]
+fn test_payload_scval_round_trip() {
+    let payload = SamplePayload::generate(16, 8).unwrap();
+    assert_eq!(xdr_round_trip(payload.seed_scval()), payload.seed);
+    assert_eq!(xdr_round_trip(payload.salt_scval()), payload.salt);
+    assert_eq!(xdr_round_trip(payload.commitment_scval()), payload.commitment);
+    assert_eq!(xdr_round_trip(payload.pubkey_scval()), payload.pubkey);
+    assert_eq!(xdr_round_trip(payload.signature_scval()), payload.signature);
+}
@@ -1,6 +1,10 @@
 //! Statistical tests for the uniformity of VRF output randomness.
 
 use nebula_vrf::vrf::generate_random;
+use nebula_vrf::vrf::sample::random_below;
+#[cfg(feature = "stats")]
+use nebula_vrf::vrf::stats::{EntropyMonitor, MonitorConfig};
+use nebula_vrf::vrf::VRFProof;
 use statrs::distribution::{ChiSquared, ContinuousCDF};
 use sha2::{Sha256, Digest};
 
@@ -10,12 +14,11 @@ fn test_chi_square_randomness_uniformity() {
     const BIN_COUNT: usize = 256;
     let mut bins = [0u64; BIN_COUNT];
 
-    // Collect byte frequencies from the hash of the VRF output
+    // Collect byte frequencies directly from the VRF randomness
     for i in 0..NUM_SAMPLES {
         let seed = format!("seed-{}", i);
         let vrf = generate_random(seed.as_bytes()).expect("generation failed");
-        let hash = Sha256::digest(&vrf.output);
-        for byte in hash.iter() {
+        for byte in vrf.randomness().unwrap().iter() {
             bins[*byte as usize] += 1;
         }
     }
@@ -42,4 +45,129 @@ fn test_chi_square_randomness_uniformity() {
         p_value > 0.01,
         "Chi-square test failed: p-value = {}, randomness not uniform", p_value
     );
-} 
\ No newline at end of file
+}
+
+#[cfg(feature = "stats")]
+#[test]
+fn test_entropy_monitor_passes_vrf_outputs() {
+    const NUM_SAMPLES: usize = 5000;
+    let mut monitor = EntropyMonitor::new();
+
+    // Same seeds as test_chi_square_randomness_uniformity
+    for i in 0..NUM_SAMPLES {
+        let seed = format!("seed-{}", i);
+        let vrf = generate_random(seed.as_bytes()).expect("generation failed");
+        monitor.observe(&vrf.randomness().unwrap());
+    }
+    assert_eq!(monitor.observed_bytes(), (NUM_SAMPLES * 32) as u64);
+
+    assert!(monitor.passes_chi_square(), "Chi-square test failed: p-value = {}", monitor.p_value());
+    assert!(monitor.passes_runs(), "Runs test failed: p-value = {}", monitor.runs_p_value());
+    assert!(monitor.passes_monobit(), "Monobit test failed: p-value = {}", monitor.monobit_p_value());
+}
+
+#[cfg(feature = "stats")]
+#[test]
+fn test_entropy_monitor_flags_skewed_outputs() {
+    let mut monitor = EntropyMonitor::new();
+    assert_eq!(monitor.p_value(), 1.0, "No observations must not look biased");
+
+    // A degraded source whose outputs never set the top bit of any byte.
+    for i in 0..5000u32 {
+        let mut output: [u8; 32] = Sha256::digest(i.to_be_bytes()).into();
+        output.iter_mut().for_each(|b| *b &= 0x7f);
+        monitor.observe(&output);
+    }
+    assert!(monitor.p_value() < 1e-6, "Skewed bytes must give a near-zero p-value, got {}", monitor.p_value());
+}
+
+/// Test that bits chained across bytes pass chi-square and monobit but fail the runs test.
+#[cfg(feature = "stats")]
+#[test]
+fn test_runs_test_catches_what_chi_square_misses() {
+    let mut monitor = EntropyMonitor::new();
+
+    // Each byte's top bit copies the previous byte's low bit. Byte values stay
+    // uniform, but the stream never changes bit at a byte boundary, so it has
+    // about 1/8 fewer runs than fair coin flips.
+    let mut last_low_bit = 0u8;
+    for i in 0..5000u32 {
+        let mut output: [u8; 32] = Sha256::digest(i.to_be_bytes()).into();
+        for byte in output.iter_mut() {
+            *byte = (*byte & 0x7f) | (last_low_bit << 7);
+            last_low_bit = *byte & 1;
+        }
+        monitor.observe(&output);
+    }
+
+    assert!(monitor.passes_chi_square(), "Chi-square p-value = {}", monitor.p_value());
+    assert!(monitor.passes_monobit(), "Monobit p-value = {}", monitor.monobit_p_value());
+    assert!(!monitor.passes_runs(), "Runs p-value = {}", monitor.runs_p_value());
+    assert!(monitor.runs_p_value() < 1e-6);
+}
+
+/// Test that the bin count, alpha and minimum sample size are configurable.
+#[cfg(feature = "stats")]
+#[test]
+fn test_entropy_monitor_config() {
+    let config = MonitorConfig { bins: 16, alpha: 0.001, min_bytes: 4096 };
+    let mut monitor = EntropyMonitor::with_config(config);
+    assert_eq!(monitor.config(), &config);
+
+    // Every low nibble is zero: half the bits, so all three tests see it.
+    for i in 0..100u32 {
+        let mut output: [u8; 32] = Sha256::digest(i.to_be_bytes()).into();
+        output.iter_mut().for_each(|b| *b &= 0xf0);
+        monitor.observe(&output);
+    }
+    assert!(monitor.p_value() < config.alpha);
+    assert!(monitor.passes_chi_square(), "Tests must not fail before min_bytes are observed");
+
+    for i in 100..200u32 {
+        let mut output: [u8; 32] = Sha256::digest(i.to_be_bytes()).into();
+        output.iter_mut().for_each(|b| *b &= 0xf0);
+        monitor.observe(&output);
+    }
+    assert!(!monitor.passes_chi_square());
+    assert!(!monitor.passes_monobit());
+}
+
+#[test]
+fn test_chi_square_random_below_uniformity() {
+    const NUM_SAMPLES: usize = 100_000;
+    const N: u64 = 7;
+    let mut bins = [0u64; N as usize];
+
+    // Only the output drives sampling, so synthetic proofs avoid 100k BLS signatures
+    for i in 0..NUM_SAMPLES {
+        let proof = VRFProof {
+            output: Sha256::digest(format!("sample-{}", i)).to_vec(),
+            proof: Vec::new(),
+            public_key: Vec::new(),
+        };
+        bins[random_below(&proof, N) as usize] += 1;
+    }
+
+    let expected = NUM_SAMPLES as f64 / N as f64;
+
+    // Compute Chi-Square statistic
+    let chi_square_stat: f64 = bins.iter()
+        .map(|&obs| {
+            let diff = obs as f64 - expected;
+            (diff * diff) / expected
+        })
+        .sum();
+
+    let df = N as f64 - 1.0;
+    let chi_dist = ChiSquared::new(df).unwrap();
+    let p_value = 1.0 - chi_dist.cdf(chi_square_stat);
+
+    println!("Chi² statistic: {}", chi_square_stat);
+    println!("p-value: {}", p_value);
+
+    // 99% confidence: p-value must be > 0.01 to accept null hypothesis
+    assert!(
+        p_value > 0.01,
+        "Chi-square test failed: p-value = {}, random_below not uniform", p_value
+    );
+}
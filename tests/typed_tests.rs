@@ -0,0 +1,41 @@
+//! Tests for the typed seed, signature and public key wrappers.
+
+use nebula_vrf::vrf::typed::{self, PublicKey, Seed, Signature};
+use nebula_vrf::vrf::types::{Encoding, VRFError};
+use nebula_vrf::vrf::{generate_random_with_encoding, verify_proof};
+
+/// Test that typed generation and verification agree with the byte-slice API.
+#[test]
+fn test_typed_round_trip() {
+    let seed = Seed::from(&b"typed-seed"[..]);
+    let (vrf, signature, public_key) = typed::generate_random(&seed).unwrap();
+    assert_eq!(signature.as_ref(), vrf.proof.as_slice());
+    assert_eq!(public_key.as_ref(), vrf.public_key.as_slice());
+
+    typed::verify_proof(&seed, &signature, &public_key).unwrap();
+    verify_proof(seed.as_bytes(), signature.as_ref(), public_key.as_ref()).unwrap();
+    assert!(matches!(
+        typed::verify_proof(&Seed::from(b"other-seed".to_vec()), &signature, &public_key),
+        Err(VRFError::VerificationFailed)
+    ));
+}
+
+/// Test that conversions validate their input and normalize to the compressed encoding.
+#[test]
+fn test_typed_conversions_validate() {
+    let vrf = generate_random_with_encoding(b"typed-seed", Encoding::Uncompressed).unwrap();
+    let signature = Signature::try_from(vrf.proof.as_slice()).unwrap();
+    let public_key = PublicKey::try_from(vrf.public_key.as_slice()).unwrap();
+    typed::verify_proof(&Seed::from(b"typed-seed".to_vec()), &signature, &public_key).unwrap();
+
+    assert!(matches!(Signature::try_from(&vrf.proof[1..]), Err(VRFError::InvalidLength { .. })));
+    assert!(matches!(PublicKey::try_from(&[0u8; 3][..]), Err(VRFError::InvalidLength { .. })));
+    // A public key is not a valid signature, even where the lengths coincide.
+    assert!(Signature::try_from(public_key.as_ref()).is_err());
+}
+
+/// Test that swapping typed arguments is a compile error.
+#[test]
+fn test_typed_arguments_cannot_be_swapped() {
+    trybuild::TestCases::new().compile_fail("tests/ui/*.rs");
+}
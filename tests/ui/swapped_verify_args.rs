@@ -0,0 +1,7 @@
+use nebula_vrf::vrf::typed::{verify_proof, PublicKey, Seed, Signature};
+
+fn check(seed: &Seed, signature: &Signature, public_key: &PublicKey) {
+    let _ = verify_proof(seed, public_key, signature);
+}
+
+fn main() {}
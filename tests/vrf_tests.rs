@@ -6,9 +6,26 @@
 //! - Determinism and uniqueness
 //! - Tamper/corruption detection
 //! - Edge cases (empty seeds, collisions)
+//! - Batch verification
+//! - Reusable keypairs
+#![cfg(feature = "std")]
 
-use nebula_vrf::vrf::{generate_random, verify_proof};
-use nebula_vrf::vrf::commit::{commit, verify_commit};
+use nebula_vrf::vrf::{
+    bound_seed, generate_random, generate_random_bound, generate_random_checked, generate_random_from_hasher, generate_random_with_dst,
+    generate_random_with_encoding, generate_random_with_mode, hash_seed_to_g1, hash_seed_to_g2, proof_to_hash, verify_proof_bound, verify_proof_unchecked, verify_proof_with_dst, verify_proof_with_mode, derive_child, derive_child_from_output, expand_output, Encoding, MessageMode, VRF_DST, verify_output, verify_proof, verify_proofs_batch,
+    verify_with_key, verify_and_extract, derive_keypair, aggregate_proofs, detect_equivocation, verify_aggregate, verify_cosigned, SeedContext, Verifier, VrfKeypair, VRF_PUBLIC_KEY_SIZE,
+    VRF_PUBLIC_KEY_UNCOMPRESSED_SIZE, VRF_SIGNATURE_SIZE, VRF_SIGNATURE_UNCOMPRESSED_SIZE,
+};
+use std::time::Instant;
+use nebula_vrf::vrf::commit::{
+    commit, commit_bound, commit_timed, commit_with, commit_with_context, verify_commit_with, CommitHash, commit_with_salt, verify_commit,
+    verify_commit_bound, verify_commit_with_context, verify_commit_with_salt, verify_reveal, verify_reveal_bound,
+    verify_timed_reveal,
+};
+use nebula_vrf::helpers::compute_commitment;
+use nebula_vrf::SamplePayload;
+use nebula_vrf::vrf::sample::{random_below, random_permutation, sample_without_replacement};
+use nebula_vrf::utils::hash::{combine_contributions, sha256, VrfHasher};
 use nebula_vrf::vrf::types::VRFError;
 
 /// Test that VRF output is valid, verifiable, and fails on tampering.
@@ -17,15 +34,16 @@ fn test_generate_and_verify_vrf() {
     let seed = b"secure-seed-xyz";
     let vrf = generate_random(seed).expect("generation failed");
 
-    // Output sizes: BLS signature (G1) and public key (G2)
-    assert_eq!(vrf.output.len(), 48);      // 48 bytes for BLS signature
-    assert_eq!(vrf.public_key.len(), 96);  // 96 bytes for BLS public key
+    // Output sizes: proof-to-hash output, compressed BLS signature and public key
+    assert_eq!(vrf.output.len(), 32);                    // 32 bytes for VRF output hash
+    assert_eq!(vrf.proof.len(), VRF_SIGNATURE_SIZE);     // BLS signature
+    assert_eq!(vrf.public_key.len(), VRF_PUBLIC_KEY_SIZE); // BLS public key
 
     // Proof should verify for correct seed
-    assert!(verify_proof(seed, &vrf.output, &vrf.public_key).is_ok());
+    assert!(verify_proof(seed, &vrf.proof, &vrf.public_key).is_ok());
 
     // Verification should fail for wrong seed
-    let bad = verify_proof(b"wrong-seed", &vrf.output, &vrf.public_key);
+    let bad = verify_proof(b"wrong-seed", &vrf.proof, &vrf.public_key);
     assert!(matches!(bad, Err(VRFError::VerificationFailed)));
 }
 
@@ -41,6 +59,186 @@ fn test_commit_reveal() {
     assert!(!verify_commit(b"tampered", &commitment));
 }
 
+/// Test that commitment comparison is correct regardless of where a mismatch occurs.
+#[test]
+fn test_commit_comparison_positions() {
+    let seed = b"constant-time-seed";
+    let commitment = commit(seed);
+    assert!(verify_commit(seed, &commitment));
+
+    for position in [0, 15, 31] {
+        let mut altered = commitment;
+        altered[position] ^= 0x80;
+        assert!(!verify_commit(seed, &altered), "Mismatch at byte {} must be detected", position);
+    }
+
+    assert!(!verify_commit(seed, &[0u8; 32]));
+}
+
+/// Test that salted commitments match the Soroban helper and detect tampering.
+#[test]
+fn test_salted_commit_matches_contract_helper() {
+    let seed = b"salted-seed";
+    let salt = b"pepper";
+
+    let commitment = commit_with_salt(seed, salt);
+    assert_eq!(commitment, compute_commitment(seed, salt));
+
+    let payload = SamplePayload::from_seed_salt(seed.to_vec(), salt.to_vec()).unwrap();
+    assert_eq!(commitment, payload.commitment);
+
+    assert!(verify_commit_with_salt(seed, salt, &commitment));
+    assert!(!verify_commit_with_salt(seed, b"other-salt", &commitment));
+    assert!(!verify_commit_with_salt(b"other-seed", salt, &commitment));
+    assert_ne!(commitment, commit(seed), "Salted and unsalted commitments must differ");
+}
+
+/// Test that a reveal checks both the commitment and the VRF proof.
+#[test]
+fn test_verify_reveal() {
+    let seed = b"reveal-seed";
+    let salt = b"reveal-salt";
+    let commitment = commit_with_salt(seed, salt);
+    let vrf = generate_random(seed).unwrap();
+
+    assert!(verify_reveal(&commitment, seed, salt, &vrf.output, &vrf.proof, &vrf.public_key).is_ok());
+
+    let result = verify_reveal(&commitment, seed, b"wrong-salt", &vrf.output, &vrf.proof, &vrf.public_key);
+    assert!(matches!(result, Err(VRFError::InvalidCommitment)));
+
+    let other = generate_random(b"other-seed").unwrap();
+    let result = verify_reveal(&commitment, seed, salt, &other.output, &other.proof, &other.public_key);
+    assert!(matches!(result, Err(VRFError::VerificationFailed)));
+
+    let truncated = verify_reveal(&commitment, seed, salt, &vrf.output[..31], &vrf.proof, &vrf.public_key);
+    assert!(matches!(truncated, Err(VRFError::VerificationFailed)));
+    let malformed = verify_reveal(&commitment, seed, salt, &vrf.output, &vrf.proof[1..], &vrf.public_key);
+    assert!(matches!(malformed, Err(VRFError::VerificationFailed)), "Malformed proofs must not leak their parse error");
+
+    // The slice-based check agrees with the generic one over `BlsVrf`.
+    let generic = nebula_vrf::vrf::commit::verify_reveal_with::<nebula_vrf::vrf::BlsVrf>(
+        &commitment, seed, salt, &vrf.output, &vrf.proof, &vrf.public_key,
+    );
+    assert!(generic.is_ok());
+}
+
+/// Test that a key-bound commitment rejects a reveal under a swapped public key.
+#[test]
+fn test_verify_reveal_bound_rejects_key_swap() {
+    let seed = b"bound-reveal-seed";
+    let salt = b"bound-reveal-salt";
+    let committed = VrfKeypair::from_ikm(&[1u8; 32]).unwrap();
+    let swapped = VrfKeypair::from_ikm(&[2u8; 32]).unwrap();
+    let commitment = commit_bound(seed, salt, &committed.public_key());
+    assert!(verify_commit_bound(seed, salt, &committed.public_key(), &commitment));
+    assert!(!verify_commit_bound(seed, salt, &swapped.public_key(), &commitment));
+
+    let honest = committed.prove(seed);
+    assert!(verify_reveal_bound(&commitment, seed, salt, &honest.output, &honest.proof, &honest.public_key).is_ok());
+
+    // A plain salted commitment accepts a proof under any key; the bound one does not.
+    let swap = swapped.prove(seed);
+    let plain = commit_with_salt(seed, salt);
+    assert!(verify_reveal(&plain, seed, salt, &swap.output, &swap.proof, &swap.public_key).is_ok());
+    assert!(matches!(
+        verify_reveal_bound(&commitment, seed, salt, &swap.output, &swap.proof, &swap.public_key),
+        Err(VRFError::InvalidCommitment)
+    ));
+
+    // The committed key with someone else's proof fails the VRF check instead.
+    assert!(matches!(
+        verify_reveal_bound(&commitment, seed, salt, &swap.output, &swap.proof, &honest.public_key),
+        Err(VRFError::VerificationFailed)
+    ));
+}
+
+/// Test that each reveal failure mode maps to its own error, commitment first.
+#[test]
+fn test_verify_reveal_failure_modes() {
+    let seed = b"reveal-seed";
+    let salt = b"reveal-salt";
+    let commitment = commit_with_salt(seed, salt);
+    let vrf = generate_random(seed).unwrap();
+    let reveal = |seed: &[u8], output: &[u8], proof: &[u8], public_key: &[u8]| {
+        verify_reveal(&commitment, seed, salt, output, proof, public_key)
+    };
+
+    // Wrong seed: the commitment check fails before the (also failing) proof check.
+    let other = generate_random(b"other-seed").unwrap();
+    assert!(matches!(
+        reveal(b"other-seed", &other.output, &other.proof, &other.public_key),
+        Err(VRFError::InvalidCommitment)
+    ));
+    assert!(matches!(
+        reveal(b"other-seed", &vrf.output, &vrf.proof, &vrf.public_key),
+        Err(VRFError::InvalidCommitment)
+    ));
+
+    // Right seed, bad VRF: every proof-side failure is VerificationFailed.
+    let mut corrupted = vrf.proof.clone();
+    corrupted[VRF_SIGNATURE_SIZE - 1] ^= 0x01;
+    let wrong_output = [0u8; 32];
+    for (output, proof, public_key) in [
+        (&vrf.output[..], &corrupted[..], &vrf.public_key[..]),
+        (&vrf.output[..], &vrf.proof[..3], &vrf.public_key[..]),
+        (&vrf.output[..], &vrf.proof[..], &other.public_key[..]),
+        (&wrong_output[..], &vrf.proof[..], &vrf.public_key[..]),
+    ] {
+        assert!(matches!(reveal(seed, output, proof, public_key), Err(VRFError::VerificationFailed)));
+    }
+}
+
+/// Test that output expansion is deterministic and prefix-consistent.
+#[test]
+fn test_expand_output() {
+    let vrf = generate_random(b"expand-seed").unwrap();
+
+    let long = expand_output(&vrf, 1000);
+    assert_eq!(long.len(), 1000);
+    assert_eq!(long, expand_output(&vrf, 1000), "Expansion must be deterministic");
+    assert_eq!(expand_output(&vrf, 64), long[..64].to_vec(), "Shorter expansions must be prefixes");
+    assert!(expand_output(&vrf, 0).is_empty());
+
+    let other = generate_random(b"other-expand-seed").unwrap();
+    assert_ne!(expand_output(&other, 64), long[..64].to_vec());
+}
+
+/// Test that child derivation is reproducible per label and independent across labels.
+#[test]
+fn test_derive_child() {
+    let vrf = generate_random(b"child-seed").unwrap();
+
+    let shuffle = derive_child(&vrf, b"shuffle").unwrap();
+    assert_eq!(shuffle, derive_child(&vrf, b"shuffle").unwrap(), "The same label must give the same child");
+    assert_eq!(shuffle, derive_child_from_output(&vrf.randomness().unwrap(), b"shuffle"));
+    assert_ne!(shuffle, vrf.randomness().unwrap(), "A child must not equal its parent output");
+
+    let draw = derive_child(&vrf, b"prize-draw/3").unwrap();
+    assert_ne!(shuffle, draw, "Different labels must give different children");
+    assert_ne!(derive_child(&vrf, b"").unwrap(), shuffle);
+
+    let other = generate_random(b"other-child-seed").unwrap();
+    assert_ne!(derive_child(&other, b"shuffle").unwrap(), shuffle, "Different parents must give different children");
+}
+
+/// Test that sampling is deterministic, in range, and permutations are complete.
+#[test]
+fn test_sampling_from_output() {
+    let vrf = generate_random(b"sample-seed").unwrap();
+
+    let draw = random_below(&vrf, 7);
+    assert!(draw < 7);
+    assert_eq!(draw, random_below(&vrf, 7), "Draws must be deterministic");
+    assert_eq!(random_below(&vrf, 1), 0);
+
+    let permutation = random_permutation(&vrf, 52);
+    assert_eq!(permutation, random_permutation(&vrf, 52));
+    let mut sorted = permutation.clone();
+    sorted.sort_unstable();
+    assert_eq!(sorted, (0..52).collect::<Vec<_>>());
+    assert!(random_permutation(&vrf, 0).is_empty());
+}
+
 /// Test that VRF is deterministic: same seed always yields same output/proof.
 #[test]
 fn test_vrf_determinism() {
@@ -50,7 +248,8 @@ fn test_vrf_determinism() {
     let vrf2 = generate_random(seed).expect("generation 2 failed");
 
     assert_eq!(vrf1.output, vrf2.output, "VRF output must be deterministic");
-    assert_eq!(vrf1.public_key, vrf2.public_key, "VRF proof must be deterministic");
+    assert_eq!(vrf1.proof, vrf2.proof, "VRF proof must be deterministic");
+    assert_eq!(vrf1.public_key, vrf2.public_key, "VRF public key must be deterministic");
 }
 
 /// Test that different seeds yield unique outputs (no collisions).
@@ -71,16 +270,562 @@ fn test_corrupted_signature_fails() {
     let seed = b"test-seed";
     let vrf = generate_random(seed).unwrap();
 
-    let mut corrupted = vrf.output.clone();
+    let mut corrupted = vrf.proof.clone();
     corrupted[0] ^= 0xff; // Flip a bit
 
     let result = verify_proof(seed, &corrupted, &vrf.public_key);
     assert!(
-        matches!(result, Err(VRFError::VerificationFailed) | Err(VRFError::InvalidSignature)),
+        matches!(
+            result,
+            Err(VRFError::VerificationFailed) | Err(VRFError::InvalidSignature) | Err(VRFError::SignatureNotInSubgroup)
+        ),
         "Corrupt signature must not verify"
     );
 }
 
+/// Test that an on-curve signature outside the prime-order subgroup is reported
+/// as `SignatureNotInSubgroup`, not as a decoding or pairing failure.
+#[test]
+fn test_off_subgroup_signature() {
+    let seed = b"subgroup-seed";
+    let vrf = generate_random(seed).unwrap();
+
+    // Compressed points with a small x coordinate: about half lie on the curve,
+    // and an on-curve point is almost never in the subgroup.
+    let result = (1..=u8::MAX)
+        .map(|x| {
+            let mut sig = vec![0u8; VRF_SIGNATURE_SIZE];
+            sig[0] = 0x80;
+            sig[VRF_SIGNATURE_SIZE - 1] = x;
+            verify_proof(seed, &sig, &vrf.public_key)
+        })
+        .find(|result| !matches!(result, Err(VRFError::InvalidSignature)))
+        .expect("some x coordinate is on the curve");
+    assert!(matches!(result, Err(VRFError::SignatureNotInSubgroup)), "got {:?}", result);
+}
+
+/// Test that an on-curve public key outside the prime-order subgroup is
+/// reported as `PublicKeyNotInSubgroup`, not as a signature failure.
+#[test]
+fn test_off_subgroup_public_key() {
+    let seed = b"subgroup-seed";
+    let vrf = generate_random(seed).unwrap();
+
+    let result = (1..=u8::MAX)
+        .map(|x| {
+            let mut pk = vec![0u8; VRF_PUBLIC_KEY_SIZE];
+            pk[0] = 0x80;
+            pk[VRF_PUBLIC_KEY_SIZE - 1] = x;
+            verify_proof(seed, &vrf.proof, &pk)
+        })
+        .find(|result| !matches!(result, Err(VRFError::InvalidPublicKey)))
+        .expect("some x coordinate is on the curve");
+    assert!(matches!(result, Err(VRFError::PublicKeyNotInSubgroup)), "got {:?}", result);
+}
+
+/// Test that the exposed hash-to-curve points are deterministic, in the
+/// subgroup, and equal to what blst signs internally.
+#[test]
+fn test_hash_seed_to_curve_points() {
+    let seed = b"hash-to-curve-seed";
+    let g2 = hash_seed_to_g2(seed, VRF_DST);
+    let g1 = hash_seed_to_g1(seed, VRF_DST);
+    assert_eq!(g2.len(), 192);
+    assert_eq!(g1.len(), 96);
+    assert_eq!(g2, hash_seed_to_g2(seed, VRF_DST));
+    assert_ne!(g2, hash_seed_to_g2(b"other-seed", VRF_DST));
+    assert_ne!(g2, hash_seed_to_g2(seed, b"OTHER-DST"));
+
+    let g2_point = blst::min_pk::Signature::deserialize(&g2).expect("on curve");
+    assert!(g2_point.subgroup_check());
+    let g1_point = blst::min_sig::Signature::deserialize(&g1).expect("on curve");
+    assert!(g1_point.subgroup_check());
+
+    // A signature under the secret key 1 is the message point itself.
+    let mut one = [0u8; 32];
+    one[31] = 1;
+    let sk = blst::min_pk::SecretKey::from_bytes(&one).unwrap();
+    assert_eq!(sk.sign(seed, VRF_DST, &[]).serialize().to_vec(), g2);
+    let sk = blst::min_sig::SecretKey::from_bytes(&one).unwrap();
+    assert_eq!(sk.sign(seed, VRF_DST, &[]).serialize().to_vec(), g1);
+}
+
+/// Test that a timed commitment opens up to and including its deadline.
+#[test]
+fn test_timed_reveal_on_time() {
+    let tc = commit_timed(b"lottery-seed", b"salt", 1_000);
+    assert_eq!(tc.not_after, 1_000);
+    assert!(verify_timed_reveal(&tc, b"lottery-seed", b"salt", 999).is_ok());
+    assert!(verify_timed_reveal(&tc, b"lottery-seed", b"salt", 1_000).is_ok());
+    assert!(matches!(
+        verify_timed_reveal(&tc, b"other-seed", b"salt", 999),
+        Err(VRFError::InvalidCommitment)
+    ));
+    assert_ne!(tc.commitment, commit_with_salt(b"lottery-seed", b"salt"));
+}
+
+/// Test that late reveals fail and the deadline cannot be moved after commit.
+#[test]
+fn test_timed_reveal_expired() {
+    let tc = commit_timed(b"lottery-seed", b"salt", 1_000);
+    assert!(matches!(
+        verify_timed_reveal(&tc, b"lottery-seed", b"salt", 1_001),
+        Err(VRFError::Expired { not_after: 1_000, now: 1_001 })
+    ));
+
+    let extended = nebula_vrf::vrf::commit::TimedCommitment { not_after: 2_000, ..tc };
+    assert!(matches!(
+        verify_timed_reveal(&extended, b"lottery-seed", b"salt", 1_001),
+        Err(VRFError::InvalidCommitment)
+    ));
+}
+
+/// Test that only transient failures are classified as retryable.
+#[test]
+fn test_error_retry_classification() {
+    assert!(VRFError::Transient.is_retryable());
+    for permanent in [
+        VRFError::InvalidSignature,
+        VRFError::InvalidPublicKey,
+        VRFError::InvalidCommitment,
+        VRFError::DeserializationError,
+        VRFError::VerificationFailed,
+        VRFError::InvalidLength { expected: 32, got: 0 },
+        VRFError::PublicKeyNotInSubgroup,
+        VRFError::IdentityPoint,
+        VRFError::SeedTooShort { min: 16, got: 0 },
+        VRFError::SignatureNotInSubgroup,
+        VRFError::Expired { not_after: 0, now: 1 },
+        VRFError::NonCanonical,
+        VRFError::UnsupportedVersion { version: 2 },
+        VRFError::DuplicateKey,
+    ] {
+        assert!(!permanent.is_retryable(), "{:?} must not be retryable", permanent);
+    }
+
+    // A short IKM is the caller's mistake, not a transient failure.
+    let err = VrfKeypair::from_ikm(&[1u8; 16]).err().unwrap();
+    assert!(matches!(err, VRFError::InvalidLength { expected: 32, got: 16 }));
+    assert!(!err.is_retryable());
+}
+
+/// Test that commit_with defaults to the SHA-256 commitment.
+#[test]
+fn test_commit_with_defaults_to_sha256() {
+    assert_eq!(commit_with(b"abc", CommitHash::Sha256), commit(b"abc"));
+    assert_eq!(CommitHash::default(), CommitHash::Sha256);
+    assert!(verify_commit_with(b"seed", &commit(b"seed"), CommitHash::default()));
+}
+
+/// Test commit_with against published Keccak-256 and SHA3-256 vectors.
+#[cfg(feature = "keccak")]
+#[test]
+fn test_commit_with_known_vectors() {
+    let hash = |seed: &[u8], algo| hex::encode(commit_with(seed, algo));
+
+    assert_eq!(hash(b"", CommitHash::Keccak256), "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470");
+    assert_eq!(hash(b"abc", CommitHash::Keccak256), "4e03657aea45a94fc7d47ba826c8d667c0d1e6e33a64a036ec44f58fa12d6c45");
+    // Solidity: keccak256("transfer(address,uint256)"), whose first four bytes are the ERC-20 selector.
+    assert_eq!(
+        hash(b"transfer(address,uint256)", CommitHash::Keccak256),
+        "a9059cbb2ab09eb219583f4a59a5d0623ade346d962bcd4e46b11da047c9049b"
+    );
+    assert_eq!(hash(b"abc", CommitHash::Sha3_256), "3a985da74fe225b2045c172d6bd390bd855f086e3e9d525b46bfe24511431532");
+
+    let commitment = commit_with(b"seed", CommitHash::Keccak256);
+    assert!(verify_commit_with(b"seed", &commitment, CommitHash::Keccak256));
+    assert!(!verify_commit_with(b"seed", &commitment, CommitHash::Sha3_256));
+    assert!(!verify_commit_with(b"seed2", &commitment, CommitHash::Keccak256));
+}
+
+/// Test that raffle draws are distinct, in range, and reproducible.
+#[test]
+fn test_sample_without_replacement() {
+    let vrf = generate_random(b"raffle-round-1").unwrap();
+
+    let winners = sample_without_replacement(&vrf, 100, 10).unwrap();
+    assert_eq!(winners.len(), 10);
+    assert!(winners.iter().all(|&w| w < 100));
+    let distinct: std::collections::HashSet<usize> = winners.iter().copied().collect();
+    assert_eq!(distinct.len(), 10, "Winners must be distinct");
+    assert_eq!(Some(winners.clone()), sample_without_replacement(&vrf, 100, 10), "Draws must be deterministic");
+
+    // Drawing more winners extends the same draw order.
+    assert_eq!(&sample_without_replacement(&vrf, 100, 20).unwrap()[..10], &winners[..]);
+
+    // k == n yields a full permutation; huge n stays cheap.
+    let mut all = sample_without_replacement(&vrf, 50, 50).unwrap();
+    all.sort_unstable();
+    assert_eq!(all, (0..50).collect::<Vec<_>>());
+    assert_eq!(sample_without_replacement(&vrf, usize::MAX, 3).unwrap().len(), 3);
+    assert_eq!(sample_without_replacement(&vrf, 5, 0), Some(Vec::new()));
+}
+
+/// Test that drawing more winners than entries is rejected without panicking.
+#[test]
+fn test_sample_without_replacement_rejects_k_above_n() {
+    let vrf = generate_random(b"raffle-round-1").unwrap();
+    assert_eq!(sample_without_replacement(&vrf, 3, 4), None);
+    assert_eq!(sample_without_replacement(&vrf, 0, 1), None);
+    assert_eq!(sample_without_replacement(&vrf, 0, 0), Some(Vec::new()));
+}
+
+/// Test that alternative encodings of a valid proof and key are rejected, so a
+/// proof has exactly one accepted byte string per encoding.
+#[test]
+fn test_non_canonical_encodings_rejected() {
+    let seed = b"canonical-seed";
+    let compressed = generate_random(seed).unwrap();
+    let keypair = VrfKeypair::generate();
+    let uncompressed = keypair.prove_with_encoding(seed, Encoding::Uncompressed);
+    assert!(verify_proof(seed, &compressed.proof, &compressed.public_key).is_ok());
+    assert!(verify_proof(seed, &uncompressed.proof, &uncompressed.public_key).is_ok());
+
+    let with_first_byte = |bytes: &[u8], f: fn(u8) -> u8| {
+        let mut bytes = bytes.to_vec();
+        bytes[0] = f(bytes[0]);
+        bytes
+    };
+    let rejected = |result: Result<(), VRFError>| {
+        matches!(
+            result,
+            Err(VRFError::NonCanonical) | Err(VRFError::InvalidSignature) | Err(VRFError::InvalidPublicKey)
+        )
+    };
+
+    // Flag bits that do not belong to the encoding: the sign bit or infinity bit on
+    // an uncompressed point, a cleared compression bit, or infinity on a compressed one.
+    for flip in [|b| b | 0x20, |b| b | 0x40, |b| b | 0x80] as [fn(u8) -> u8; 3] {
+        let sig = with_first_byte(&uncompressed.proof, flip);
+        assert!(rejected(verify_proof(seed, &sig, &uncompressed.public_key)));
+        let pk = with_first_byte(&uncompressed.public_key, flip);
+        assert!(rejected(verify_proof(seed, &uncompressed.proof, &pk)));
+    }
+    for flip in [|b| b & !0x80, |b| b | 0x40] as [fn(u8) -> u8; 2] {
+        let sig = with_first_byte(&compressed.proof, flip);
+        assert!(rejected(verify_proof(seed, &sig, &compressed.public_key)));
+        let pk = with_first_byte(&compressed.public_key, flip);
+        assert!(rejected(verify_proof(seed, &compressed.proof, &pk)));
+    }
+}
+
+/// Test that generate_random_checked enforces the minimum seed length.
+#[test]
+fn test_generate_random_checked_min_length() {
+    const MIN: usize = 16;
+
+    let result = generate_random_checked(b"", MIN);
+    assert!(matches!(result, Err(VRFError::SeedTooShort { min: MIN, got: 0 })));
+
+    let short = [7u8; MIN - 1];
+    let result = generate_random_checked(&short, MIN);
+    assert!(matches!(result, Err(VRFError::SeedTooShort { min: MIN, got }) if got == MIN - 1));
+    assert_eq!(
+        result.unwrap_err().to_string(),
+        "Seed too short: expected at least 16 bytes, got 15"
+    );
+
+    let exact = [7u8; MIN];
+    let vrf = generate_random_checked(&exact, MIN).expect("seed at the minimum must be accepted");
+    assert_eq!(vrf, generate_random(&exact).unwrap());
+
+    assert!(generate_random_checked(b"", 0).is_ok());
+}
+
+/// Test that a context-bound commitment only opens under its own submitter and round.
+#[test]
+fn test_commit_with_context_binds_submitter_and_round() {
+    fn context(user: [u8; 32], round: u64) -> Vec<u8> {
+        [&user[..], &round.to_be_bytes()].concat()
+    }
+    let seed = b"shared-seed";
+    let alice = context([1u8; 32], 7);
+    let commitment = commit_with_context(seed, &alice);
+
+    assert!(verify_commit_with_context(seed, &alice, &commitment));
+    assert!(!verify_commit_with_context(seed, &context([2u8; 32], 7), &commitment));
+    assert!(!verify_commit_with_context(seed, &context([1u8; 32], 8), &commitment));
+    assert!(!verify_commit_with_context(b"other-seed", &alice, &commitment));
+
+    // The same seed under different contexts yields unrelated commitments.
+    assert_ne!(commitment, commit_with_context(seed, &context([2u8; 32], 7)));
+    assert_ne!(commitment, commit(seed));
+}
+
+/// Test that the selected BLS scheme has the documented point sizes.
+#[test]
+fn test_scheme_sizes() {
+    #[cfg(not(feature = "min-pk"))]
+    let (sig, pk) = (48, 96);
+    #[cfg(feature = "min-pk")]
+    let (sig, pk) = (96, 48);
+
+    assert_eq!(VRF_SIGNATURE_SIZE, sig);
+    assert_eq!(VRF_PUBLIC_KEY_SIZE, pk);
+    assert_eq!(VRF_SIGNATURE_UNCOMPRESSED_SIZE, 2 * sig);
+    assert_eq!(VRF_PUBLIC_KEY_UNCOMPRESSED_SIZE, 2 * pk);
+}
+
+/// Test that both encodings verify and yield the same output.
+#[test]
+fn test_compressed_and_uncompressed_encodings() {
+    let seed = b"encoding-seed";
+    let compressed = generate_random_with_encoding(seed, Encoding::Compressed).unwrap();
+    let uncompressed = generate_random_with_encoding(seed, Encoding::Uncompressed).unwrap();
+
+    assert_eq!(compressed.proof.len(), VRF_SIGNATURE_SIZE);
+    assert_eq!(compressed.public_key.len(), VRF_PUBLIC_KEY_SIZE);
+    assert_eq!(uncompressed.proof.len(), VRF_SIGNATURE_UNCOMPRESSED_SIZE);
+    assert_eq!(uncompressed.public_key.len(), VRF_PUBLIC_KEY_UNCOMPRESSED_SIZE);
+
+    assert_eq!(compressed.output, uncompressed.output, "Output must not depend on encoding");
+    assert!(verify_proof(seed, &uncompressed.proof, &uncompressed.public_key).is_ok());
+    assert!(verify_proof(seed, &compressed.proof, &uncompressed.public_key).is_ok());
+    assert!(verify_output(seed, &uncompressed.output, &uncompressed.proof, &compressed.public_key).is_ok());
+}
+
+/// Test that binding a seed to a beacon signature changes the output and verifies only with that beacon.
+#[test]
+fn test_generate_random_bound() {
+    let seed = b"committed-seed";
+    let beacon_a = [0xa5u8; 96];
+    let beacon_b = [0x5au8; 96];
+
+    let bound_a = generate_random_bound(seed, &beacon_a).unwrap();
+    let bound_b = generate_random_bound(seed, &beacon_b).unwrap();
+    assert_ne!(bound_a.output, bound_b.output, "Different beacons must give different outputs");
+    assert_ne!(bound_a.output, generate_random(seed).unwrap().output);
+    assert_eq!(bound_a, generate_random_bound(seed, &beacon_a).unwrap());
+
+    let mut preimage = seed.to_vec();
+    preimage.extend_from_slice(&beacon_a);
+    assert_eq!(bound_seed(seed, &beacon_a), nebula_vrf::utils::hash::sha256(&preimage));
+    assert_eq!(bound_a, generate_random(&bound_seed(seed, &beacon_a)).unwrap());
+
+    assert!(verify_proof_bound(seed, &beacon_a, &bound_a.proof, &bound_a.public_key).is_ok());
+    assert!(matches!(
+        verify_proof_bound(seed, &beacon_b, &bound_a.proof, &bound_a.public_key),
+        Err(VRFError::VerificationFailed)
+    ));
+    assert!(verify_proof_bound(b"other-seed", &beacon_a, &bound_a.proof, &bound_a.public_key).is_err());
+}
+
+/// Test that raw and pre-hashed message modes differ and each verifies only under its own mode.
+#[test]
+fn test_message_modes() {
+    let seed = b"message-mode-seed";
+    let raw = generate_random_with_mode(seed, MessageMode::Raw).unwrap();
+    let hashed = generate_random_with_mode(seed, MessageMode::PreHashed).unwrap();
+
+    assert_eq!(raw, generate_random(seed).unwrap(), "Raw must match the default behavior");
+    assert_eq!(raw.public_key, hashed.public_key, "Key must not depend on the mode");
+    assert_ne!(raw.proof, hashed.proof, "Modes must sign different messages");
+    assert_ne!(raw.output, hashed.output);
+
+    assert!(verify_proof_with_mode(seed, &raw.proof, &raw.public_key, MessageMode::Raw).is_ok());
+    assert!(verify_proof_with_mode(seed, &hashed.proof, &hashed.public_key, MessageMode::PreHashed).is_ok());
+    assert!(matches!(
+        verify_proof_with_mode(seed, &hashed.proof, &hashed.public_key, MessageMode::Raw),
+        Err(VRFError::VerificationFailed)
+    ));
+    assert!(matches!(
+        verify_proof_with_mode(seed, &raw.proof, &raw.public_key, MessageMode::PreHashed),
+        Err(VRFError::VerificationFailed)
+    ));
+
+    // A pre-hashed proof is a raw proof over the digest, as a pre-hashing contract sees it.
+    let digest = nebula_vrf::utils::hash::sha256(seed);
+    assert!(verify_proof(&digest, &hashed.proof, &hashed.public_key).is_ok());
+    let keypair = VrfKeypair::from_ikm(&[7u8; 32]).unwrap();
+    assert_eq!(keypair.prove_with_mode(seed, MessageMode::PreHashed), keypair.prove(&digest));
+}
+
+/// Test that a seed streamed in chunks yields the same digest and a verifiable proof.
+#[test]
+fn test_streamed_seed_hashing() {
+    let seed: Vec<u8> = (0..200_000u32).map(|i| (i % 251) as u8).collect();
+
+    let mut hasher = VrfHasher::new();
+    for chunk in seed.chunks(64 * 1024) {
+        hasher.update(chunk);
+    }
+    let digest = hasher.clone().finalize();
+    assert_eq!(digest, sha256(&seed));
+
+    let vrf = generate_random_from_hasher(hasher).unwrap();
+    assert!(verify_proof(&digest, &vrf.proof, &vrf.public_key).is_ok());
+    assert_eq!(vrf.output, generate_random(&digest).unwrap().output);
+}
+
+/// Test that combining contributions ignores their order but not their content.
+#[test]
+fn test_combine_contributions_is_order_independent() {
+    let (a, b, c): (&[u8], &[u8], &[u8]) = (b"alice-entropy", b"bob-entropy", b"carol-entropy");
+    let seed = combine_contributions(&[a, b, c]);
+    for permutation in [[a, c, b], [b, a, c], [b, c, a], [c, a, b], [c, b, a]] {
+        assert_eq!(combine_contributions(&permutation), seed);
+    }
+
+    assert_ne!(combine_contributions(&[a, b]), seed, "Dropping a contributor must change the seed");
+    assert_ne!(combine_contributions(&[b"ab", b"c"]), combine_contributions(&[b"a", b"bc"]));
+
+    let vrf = generate_random(&seed).unwrap();
+    assert!(verify_proof(&seed, &vrf.proof, &vrf.public_key).is_ok());
+}
+
+/// Test that a proof only verifies under the DST it was generated with.
+#[test]
+fn test_dst_mismatch_fails() {
+    let seed = b"dst-seed";
+    let app_dst = b"MY-APP-VRF-V01";
+    let vrf = generate_random_with_dst(seed, app_dst).unwrap();
+
+    assert!(verify_proof_with_dst(seed, &vrf.proof, &vrf.public_key, app_dst).is_ok());
+
+    let result = verify_proof_with_dst(seed, &vrf.proof, &vrf.public_key, b"OTHER-APP-VRF-V01");
+    assert!(matches!(result, Err(VRFError::VerificationFailed)));
+
+    let result = verify_proof(seed, &vrf.proof, &vrf.public_key);
+    assert!(matches!(result, Err(VRFError::VerificationFailed)));
+
+    let default = generate_random(seed).unwrap();
+    assert!(verify_proof_with_dst(seed, &default.proof, &default.public_key, VRF_DST).is_ok());
+    assert_ne!(default.proof, vrf.proof);
+}
+
+/// Test that wrong-length inputs are reported with the concrete lengths.
+#[test]
+fn test_wrong_length_inputs_report_lengths() {
+    let seed = b"length-seed";
+    let vrf = generate_random(seed).unwrap();
+
+    let short_sig = VRF_SIGNATURE_SIZE - 1;
+    let result = verify_proof(seed, &vrf.proof[..short_sig], &vrf.public_key);
+    assert!(matches!(result, Err(VRFError::InvalidLength { expected: VRF_SIGNATURE_SIZE, got }) if got == short_sig));
+
+    let short_pk = VRF_PUBLIC_KEY_SIZE - 1;
+    let result = verify_proof(seed, &vrf.proof, &vrf.public_key[..short_pk]);
+    assert!(matches!(result, Err(VRFError::InvalidLength { expected: VRF_PUBLIC_KEY_SIZE, got }) if got == short_pk));
+
+    let message = result.unwrap_err().to_string();
+    assert_eq!(message, format!("Invalid length: expected {} bytes, got {}", VRF_PUBLIC_KEY_SIZE, short_pk));
+}
+
+/// Test that verify_and_extract returns the same randomness as verifying and hashing separately.
+#[test]
+fn test_verify_and_extract() {
+    let seed = b"extract-seed";
+    for encoding in [Encoding::Compressed, Encoding::Uncompressed] {
+        let vrf = generate_random_with_encoding(seed, encoding).unwrap();
+        verify_proof(seed, &vrf.proof, &vrf.public_key).unwrap();
+        let separate = proof_to_hash(&vrf.proof).unwrap();
+
+        let fused = verify_and_extract(seed, &vrf.proof, &vrf.public_key).unwrap();
+        assert_eq!(fused, separate);
+        assert_eq!(fused, vrf.randomness().unwrap());
+    }
+
+    let vrf = generate_random(seed).unwrap();
+    assert!(matches!(
+        verify_and_extract(b"wrong-seed", &vrf.proof, &vrf.public_key),
+        Err(VRFError::VerificationFailed)
+    ));
+    assert!(matches!(verify_and_extract(seed, &vrf.proof[1..], &vrf.public_key), Err(VRFError::InvalidLength { .. })));
+}
+
+/// Test that unchecked verification accepts valid proofs and still rejects wrong signatures.
+#[test]
+fn test_verify_proof_unchecked() {
+    let seed = b"trusted-seed";
+    let vrf = generate_random(seed).unwrap();
+    let other = generate_random(b"other-trusted-seed").unwrap();
+
+    assert!(verify_proof_unchecked(seed, &vrf.proof, &vrf.public_key).is_ok());
+    assert!(matches!(
+        verify_proof_unchecked(seed, &other.proof, &vrf.public_key),
+        Err(VRFError::VerificationFailed)
+    ));
+    assert!(matches!(
+        verify_proof_unchecked(b"wrong-seed", &vrf.proof, &vrf.public_key),
+        Err(VRFError::VerificationFailed)
+    ));
+    assert!(matches!(
+        verify_proof_unchecked(seed, &vrf.proof[1..], &vrf.public_key),
+        Err(VRFError::InvalidLength { .. })
+    ));
+}
+
+/// Test that empty and off-by-one signatures and keys fail the length check deterministically.
+#[test]
+fn test_empty_and_off_by_one_lengths() {
+    let seed = b"length-edge-seed";
+    let vrf = generate_random(seed).unwrap();
+    let uncompressed = generate_random_with_encoding(seed, Encoding::Uncompressed).unwrap();
+
+    assert!(matches!(
+        verify_proof(seed, &[], &vrf.public_key),
+        Err(VRFError::InvalidLength { expected: VRF_SIGNATURE_SIZE, got: 0 })
+    ));
+    assert!(matches!(
+        verify_proof(seed, &vrf.proof, &[]),
+        Err(VRFError::InvalidLength { expected: VRF_PUBLIC_KEY_SIZE, got: 0 })
+    ));
+    // The key is checked first when both are empty.
+    assert!(matches!(
+        verify_proof(seed, &[], &[]),
+        Err(VRFError::InvalidLength { expected: VRF_PUBLIC_KEY_SIZE, got: 0 })
+    ));
+
+    let mut long_sig = vrf.proof.clone();
+    long_sig.push(0);
+    let mut long_uncompressed = uncompressed.proof.clone();
+    long_uncompressed.push(0);
+    for sig in [
+        &vrf.proof[..VRF_SIGNATURE_SIZE - 1],
+        &long_sig[..],
+        &uncompressed.proof[..VRF_SIGNATURE_UNCOMPRESSED_SIZE - 1],
+        &long_uncompressed[..],
+    ] {
+        let result = verify_proof(seed, sig, &vrf.public_key);
+        assert!(
+            matches!(result, Err(VRFError::InvalidLength { expected: VRF_SIGNATURE_SIZE, got }) if got == sig.len()),
+            "{}-byte signature must fail the length check",
+            sig.len()
+        );
+    }
+}
+
+/// Test that identity public keys and signatures are rejected before pairing.
+#[test]
+fn test_identity_points_rejected() {
+    let seed = b"identity-seed";
+    let vrf = generate_random(seed).unwrap();
+
+    // Canonical infinity encodings: compression + infinity flags, then zeros.
+    let mut identity_sig = [0u8; VRF_SIGNATURE_SIZE];
+    identity_sig[0] = 0xc0;
+    let mut identity_pk = [0u8; VRF_PUBLIC_KEY_SIZE];
+    identity_pk[0] = 0xc0;
+
+    let result = verify_proof(seed, &identity_sig, &vrf.public_key);
+    assert!(matches!(result, Err(VRFError::IdentityPoint)));
+    let result = verify_proof(seed, &vrf.proof, &identity_pk);
+    assert!(matches!(result, Err(VRFError::IdentityPoint)));
+    let result = verify_proof(seed, &identity_sig, &identity_pk);
+    assert!(matches!(result, Err(VRFError::IdentityPoint)));
+
+    // Uncompressed infinity encodings: infinity flag only, then zeros.
+    let mut identity_sig_uncompressed = [0u8; VRF_SIGNATURE_UNCOMPRESSED_SIZE];
+    identity_sig_uncompressed[0] = 0x40;
+    let result = verify_proof(seed, &identity_sig_uncompressed, &vrf.public_key);
+    assert!(matches!(result, Err(VRFError::IdentityPoint)));
+
+    // All-zero encodings must never verify either.
+    assert!(verify_proof(seed, &[0u8; VRF_SIGNATURE_SIZE], &vrf.public_key).is_err());
+    assert!(verify_proof(seed, &vrf.proof, &[0u8; VRF_PUBLIC_KEY_SIZE]).is_err());
+    assert!(verify_proof(seed, &[0u8; VRF_SIGNATURE_UNCOMPRESSED_SIZE], &[0u8; VRF_PUBLIC_KEY_UNCOMPRESSED_SIZE]).is_err());
+}
+
 /// Test that a corrupted public key does not verify.
 #[test]
 fn test_corrupted_public_key_fails() {
@@ -90,9 +835,12 @@ fn test_corrupted_public_key_fails() {
     let mut corrupted = vrf.public_key.clone();
     corrupted[1] ^= 0xff; // Flip a bit
 
-    let result = verify_proof(seed, &vrf.output, &corrupted);
+    let result = verify_proof(seed, &vrf.proof, &corrupted);
     assert!(
-        matches!(result, Err(VRFError::VerificationFailed) | Err(VRFError::InvalidPublicKey)),
+        matches!(
+            result,
+            Err(VRFError::VerificationFailed) | Err(VRFError::InvalidPublicKey) | Err(VRFError::PublicKeyNotInSubgroup)
+        ),
         "Corrupt public key must not verify"
     );
 }
@@ -118,5 +866,596 @@ fn test_empty_seed_input() {
     assert!(vrf.is_ok(), "VRF generation should handle empty seed");
 
     let proof = vrf.unwrap();
-    assert!(verify_proof(empty, &proof.output, &proof.public_key).is_ok());
+    assert!(verify_proof(empty, &proof.proof, &proof.public_key).is_ok());
+}
+
+/// Test that the output is the proof-to-hash of the signature and is checked on verify.
+#[test]
+fn test_output_derived_from_proof() {
+    let seed = b"proof-to-hash-seed";
+    let vrf = generate_random(seed).unwrap();
+
+    let derived = proof_to_hash(&vrf.proof).expect("proof-to-hash failed");
+    assert_eq!(vrf.output, derived.to_vec(), "Output must be the hash of the proof");
+    assert_ne!(vrf.output, vrf.proof, "Output must not be the raw signature");
+
+    assert!(verify_output(seed, &vrf.output, &vrf.proof, &vrf.public_key).is_ok());
+
+    let mut tampered = vrf.output.clone();
+    tampered[0] ^= 0x01;
+    let result = verify_output(seed, &tampered, &vrf.proof, &vrf.public_key);
+    assert!(matches!(result, Err(VRFError::VerificationFailed)));
+}
+
+/// Test that equivocation needs two valid, distinct signatures from one key.
+#[test]
+fn test_detect_equivocation() {
+    let seed = b"beacon-round-9";
+    let keypair = derive_keypair(b"equivocation-master-secret-32byt", b"signer");
+    let pk = keypair.public_key();
+    let compressed = keypair.prove(seed).proof;
+    let uncompressed = keypair.prove_with_encoding(seed, Encoding::Uncompressed).proof;
+
+    // Identical signatures, and two encodings of the same signature, are not faults.
+    assert!(!detect_equivocation(seed, &compressed, &compressed, &pk).unwrap());
+    assert!(!detect_equivocation(seed, &compressed, &uncompressed, &pk).unwrap());
+
+    // BLS signatures are unique, so a second valid signature cannot be forged
+    // here; genuinely different signatures that fail to verify are not proof.
+    let other_seed = keypair.prove(b"beacon-round-10").proof;
+    let other_key = derive_keypair(b"equivocation-master-secret-32byt", b"other").prove(seed).proof;
+    assert!(!detect_equivocation(seed, &compressed, &other_seed, &pk).unwrap());
+    assert!(!detect_equivocation(seed, &other_key, &compressed, &pk).unwrap());
+
+    assert!(matches!(
+        detect_equivocation(seed, &compressed, &[1u8; 3], &pk),
+        Err(VRFError::InvalidLength { .. })
+    ));
+}
+
+/// Test that batch verification reports each entry in position, including malformed ones.
+#[test]
+fn test_batch_verification_mixed() {
+    let seeds: Vec<Vec<u8>> = (0..6).map(|i| format!("batch-seed-{}", i).into_bytes()).collect();
+    let proofs: Vec<_> = seeds.iter().map(|s| generate_random(s).unwrap()).collect();
+
+    let mut bad_sig = proofs[2].proof.clone();
+    bad_sig[0] ^= 0xff;
+    let truncated_pk = &proofs[4].public_key[..10];
+
+    let items: Vec<(&[u8], &[u8], &[u8])> = vec![
+        (&seeds[0], &proofs[0].proof, &proofs[0].public_key),
+        (&seeds[1], &proofs[1].proof, &proofs[1].public_key),
+        (&seeds[2], &bad_sig, &proofs[2].public_key),
+        (b"wrong-seed", &proofs[3].proof, &proofs[3].public_key),
+        (&seeds[4], &proofs[4].proof, truncated_pk),
+        (&seeds[5], &proofs[5].proof, &proofs[5].public_key),
+    ];
+
+    let results = verify_proofs_batch(&items).expect("batch failed");
+    assert_eq!(results, vec![true, true, false, false, false, true]);
+
+    assert!(verify_proofs_batch(&[]).unwrap().is_empty());
+}
+
+/// Test that a bounded 2-thread pool gives the same batch results as the global pool.
+#[cfg(feature = "rayon")]
+#[test]
+fn test_batch_verification_in_pool() {
+    use nebula_vrf::vrf::{verify_proofs_batch_in, VrfPool};
+
+    let pool = VrfPool::new(2).unwrap();
+    assert_eq!(pool.num_threads(), 2);
+
+    let seeds: Vec<Vec<u8>> = (0..17).map(|i| format!("pool-seed-{}", i).into_bytes()).collect();
+    let proofs: Vec<_> = seeds.iter().map(|s| generate_random(s).unwrap()).collect();
+    let mut items: Vec<(&[u8], &[u8], &[u8])> = seeds
+        .iter()
+        .zip(&proofs)
+        .map(|(s, p)| (s.as_slice(), p.proof.as_slice(), p.public_key.as_slice()))
+        .collect();
+    items[3].0 = b"wrong-seed";
+    items[11].2 = &proofs[11].public_key[..10];
+
+    let expected: Vec<bool> = (0..17).map(|i| i != 3 && i != 11).collect();
+    assert_eq!(verify_proofs_batch_in(&items, Some(&pool)).unwrap(), expected);
+    assert_eq!(verify_proofs_batch_in(&items, None).unwrap(), expected);
+    assert!(verify_proofs_batch_in(&[], Some(&pool)).unwrap().is_empty());
+
+    let payloads = SamplePayload::generate_many_in(8, 16, 16, Some(&pool)).unwrap();
+    assert_eq!(payloads.len(), 8);
+    assert!(payloads.iter().all(|p| p.verify().is_ok()));
+}
+
+/// Bench: batch verification of 1000 proofs beats 1000 sequential verifies.
+///
+/// Run with `cargo test --release -- --ignored test_batch_verification_speed`.
+#[test]
+#[ignore]
+fn test_batch_verification_speed() {
+    const N: usize = 1000;
+    let seeds: Vec<Vec<u8>> = (0..N).map(|i| format!("bench-seed-{}", i).into_bytes()).collect();
+    let proofs: Vec<_> = seeds.iter().map(|s| generate_random(s).unwrap()).collect();
+    let items: Vec<(&[u8], &[u8], &[u8])> = seeds
+        .iter()
+        .zip(&proofs)
+        .map(|(s, p)| (s.as_slice(), p.proof.as_slice(), p.public_key.as_slice()))
+        .collect();
+
+    let start = Instant::now();
+    for (seed, sig, pk) in &items {
+        assert!(verify_proof(seed, sig, pk).is_ok());
+    }
+    let sequential = start.elapsed();
+
+    let start = Instant::now();
+    let results = verify_proofs_batch(&items).unwrap();
+    let batched = start.elapsed();
+
+    assert!(results.iter().all(|&v| v));
+    println!("sequential: {:?}, batched: {:?}", sequential, batched);
+    assert!(batched < sequential, "Batch verification should beat sequential verification");
+}
+
+/// Test that a Verifier agrees with verify_proof and rejects bad keys up front.
+#[test]
+fn test_verifier_caches_public_key() {
+    let keypair = derive_keypair(b"verifier-master-secret-material!", b"signer");
+    let verifier = Verifier::new(&keypair.public_key()).unwrap();
+
+    for seed in [&b"round-1"[..], b"round-2", b""] {
+        let vrf = keypair.prove(seed);
+        assert!(verifier.verify(seed, &vrf.proof).is_ok());
+        assert!(matches!(verifier.verify(b"other-seed", &vrf.proof), Err(VRFError::VerificationFailed)));
+    }
+
+    let other = generate_random(b"other-signer").unwrap();
+    assert!(verifier.verify(b"other-signer", &other.proof).is_err());
+
+    let mut identity_pk = [0u8; VRF_PUBLIC_KEY_SIZE];
+    identity_pk[0] = 0xc0;
+    assert!(matches!(Verifier::new(&identity_pk), Err(VRFError::IdentityPoint)));
+    assert!(matches!(Verifier::new(&[1u8; 3]), Err(VRFError::InvalidLength { .. })));
+}
+
+/// Bench: a cached Verifier beats repeated verify_proof under one key.
+///
+/// Run with `cargo test --release -- --ignored test_verifier_speed`.
+#[test]
+#[ignore]
+fn test_verifier_speed() {
+    const N: usize = 1000;
+    let keypair = derive_keypair(b"verifier-master-secret-material!", b"bench");
+    let pk = keypair.public_key();
+    let seeds: Vec<Vec<u8>> = (0..N).map(|i| format!("bench-seed-{}", i).into_bytes()).collect();
+    let proofs: Vec<_> = seeds.iter().map(|s| keypair.prove(s).proof).collect();
+
+    let start = Instant::now();
+    for (seed, sig) in seeds.iter().zip(&proofs) {
+        assert!(verify_proof(seed, sig, &pk).is_ok());
+    }
+    let repeated = start.elapsed();
+
+    let start = Instant::now();
+    let verifier = Verifier::new(&pk).unwrap();
+    for (seed, sig) in seeds.iter().zip(&proofs) {
+        assert!(verifier.verify(seed, sig).is_ok());
+    }
+    let cached = start.elapsed();
+
+    println!("verify_proof: {:?}, Verifier: {:?}", repeated, cached);
+    assert!(cached < repeated, "Cached verifier should beat repeated key parsing");
+}
+
+/// Test that a SeedContext agrees with verify_proof across many signers.
+#[test]
+fn test_seed_context_matches_verify_proof() {
+    let seed = b"election-round-7";
+    let context = SeedContext::new(seed, VRF_DST);
+
+    for i in 0..8u8 {
+        let keypair = derive_keypair(b"seed-context-master-secret-bytes", &[i]);
+        let vrf = keypair.prove(seed);
+        assert!(verify_proof(seed, &vrf.proof, &vrf.public_key).is_ok());
+        assert!(context.verify(&vrf.proof, &vrf.public_key).is_ok());
+
+        let other = keypair.prove(b"election-round-8");
+        assert!(matches!(context.verify(&other.proof, &other.public_key), Err(VRFError::VerificationFailed)));
+    }
+
+    let signer = generate_random(seed).unwrap();
+    let stranger = generate_random(b"stranger").unwrap();
+    assert!(matches!(context.verify(&signer.proof, &stranger.public_key), Err(VRFError::VerificationFailed)));
+    assert!(matches!(context.verify(&[1u8; 3], &signer.public_key), Err(VRFError::InvalidLength { .. })));
+
+    let other_dst = SeedContext::new(seed, b"OTHER-APP-DST");
+    assert!(matches!(other_dst.verify(&signer.proof, &signer.public_key), Err(VRFError::VerificationFailed)));
+}
+
+/// Test that one keypair signs many seeds under a single stable public key.
+#[test]
+fn test_keypair_signs_many_seeds() {
+    let keypair = VrfKeypair::generate();
+    let pk = keypair.public_key();
+    assert_eq!(pk.len(), VRF_PUBLIC_KEY_SIZE);
+
+    for seed in [&b"round-1"[..], b"round-2", b"round-3"] {
+        let vrf = keypair.prove(seed);
+        assert_eq!(vrf.public_key, pk, "Public key must not depend on the seed");
+        assert!(verify_with_key(&pk, seed, &vrf.proof).is_ok());
+        assert!(matches!(
+            verify_with_key(&pk, b"other-seed", &vrf.proof),
+            Err(VRFError::VerificationFailed)
+        ));
+    }
+
+    let other = VrfKeypair::generate();
+    let vrf = other.prove(b"round-1");
+    assert!(verify_with_key(&pk, b"round-1", &vrf.proof).is_err());
+}
+
+/// Test that keypairs derived from the same IKM are identical and short IKM is rejected.
+#[test]
+fn test_keypair_from_ikm() {
+    let ikm = [7u8; 32];
+    let a = VrfKeypair::from_ikm(&ikm).unwrap();
+    let b = VrfKeypair::from_ikm(&ikm).unwrap();
+    assert_eq!(a.public_key(), b.public_key());
+    assert_eq!(a.prove(b"seed").proof, b.prove(b"seed").proof);
+
+    assert!(VrfKeypair::from_ikm(&[1u8; 16]).is_err());
+}
+
+/// Test that derived keys are reproducible per context and independent across contexts.
+#[test]
+fn test_derive_keypair_contexts() {
+    let master = [42u8; 32];
+    let round1 = derive_keypair(&master, b"round-1");
+    let round1_again = derive_keypair(&master, b"round-1");
+    let round2 = derive_keypair(&master, b"round-2");
+    let other_master = derive_keypair(&[43u8; 32], b"round-1");
+
+    assert_eq!(round1.public_key(), round1_again.public_key());
+    assert_ne!(round1.public_key(), round2.public_key());
+    assert_ne!(round1.public_key(), other_master.public_key());
+
+    let vrf = round2.prove(b"seed");
+    assert!(verify_with_key(&round2.public_key(), b"seed", &vrf.proof).is_ok());
+    assert!(verify_with_key(&round1.public_key(), b"seed", &vrf.proof).is_err());
+}
+
+/// Test that a 3-of-3 committee aggregate verifies only with every member's key.
+#[test]
+fn test_aggregate_three_of_three() {
+    let seed = b"committee-round-7";
+    let members: Vec<VrfKeypair> = (0..3u8)
+        .map(|i| derive_keypair(b"committee-master-secret-material", &[i]))
+        .collect();
+    let proofs: Vec<_> = members.iter().map(|m| m.prove(seed)).collect();
+    let pubkeys: Vec<Vec<u8>> = members.iter().map(|m| m.public_key()).collect();
+    let pubkey_refs: Vec<&[u8]> = pubkeys.iter().map(Vec::as_slice).collect();
+
+    let agg = aggregate_proofs(&proofs).expect("aggregation failed");
+    assert_eq!(agg.len(), VRF_SIGNATURE_SIZE);
+    assert!(verify_aggregate(seed, &agg, &pubkey_refs).is_ok());
+    assert!(proof_to_hash(&agg).is_ok());
+
+    // Missing member, wrong seed, and partial aggregate must all fail.
+    assert!(verify_aggregate(seed, &agg, &pubkey_refs[..2]).is_err());
+    assert!(verify_aggregate(b"other-round", &agg, &pubkey_refs).is_err());
+    let partial = aggregate_proofs(&proofs[..2]).unwrap();
+    assert!(verify_aggregate(seed, &partial, &pubkey_refs).is_err());
+
+    // Duplicate keys and empty inputs are rejected.
+    let duplicated = [pubkey_refs[0], pubkey_refs[0], pubkey_refs[1]];
+    assert!(matches!(verify_aggregate(seed, &agg, &duplicated), Err(VRFError::DuplicateKey)));
+    assert!(verify_aggregate(seed, &agg, &[]).is_err());
+    assert!(aggregate_proofs(&[]).is_err());
+}
+
+/// Test that two distinct co-signers verify and a repeated key is rejected.
+#[test]
+fn test_verify_cosigned() {
+    let seed = b"fairness-game-42";
+    let alice = derive_keypair(b"cosign-master-secret-material-00", b"alice");
+    let bob = derive_keypair(b"cosign-master-secret-material-00", b"bob");
+    let (alice_sig, bob_sig) = (alice.prove(seed).proof, bob.prove(seed).proof);
+    let (alice_pk, bob_pk) = (alice.public_key(), bob.public_key());
+
+    assert!(verify_cosigned(seed, &[&alice_sig, &bob_sig], &[&alice_pk, &bob_pk]).is_ok());
+
+    // Each signature must match its own key and the shared seed.
+    assert!(matches!(
+        verify_cosigned(seed, &[&bob_sig, &alice_sig], &[&alice_pk, &bob_pk]),
+        Err(VRFError::VerificationFailed)
+    ));
+    assert!(verify_cosigned(b"other-seed", &[&alice_sig, &bob_sig], &[&alice_pk, &bob_pk]).is_err());
+
+    // One party declaring the same key twice, even in another encoding, is a duplicate.
+    assert!(matches!(
+        verify_cosigned(seed, &[&alice_sig, &alice_sig], &[&alice_pk, &alice_pk]),
+        Err(VRFError::DuplicateKey)
+    ));
+    let alice_pk_uncompressed = alice.prove_with_encoding(seed, Encoding::Uncompressed).public_key;
+    assert!(matches!(
+        verify_cosigned(seed, &[&alice_sig, &alice_sig], &[&alice_pk, &alice_pk_uncompressed]),
+        Err(VRFError::DuplicateKey)
+    ));
+
+    assert!(matches!(
+        verify_cosigned(seed, &[&alice_sig], &[&alice_pk, &bob_pk]),
+        Err(VRFError::InvalidLength { expected: 2, got: 1 })
+    ));
+    assert!(verify_cosigned(seed, &[], &[]).is_err());
+}
+
+/// Test that the integer views read the first 16 output bytes in the stated byte order.
+#[test]
+fn test_output_integer_views() {
+    use nebula_vrf::vrf::VRFProof;
+
+    let known = VRFProof { output: (0u8..32).collect(), proof: Vec::new(), public_key: Vec::new() };
+    assert_eq!(known.as_u128_be().unwrap(), 0x000102030405060708090a0b0c0d0e0f);
+    assert_eq!(known.as_u128_le().unwrap(), 0x0f0e0d0c0b0a09080706050403020100);
+
+    let vrf = generate_random(b"integer-view-seed").unwrap();
+    let out = vrf.randomness().unwrap();
+    assert_eq!(vrf.as_u128_be().unwrap(), u128::from_be_bytes(out[..16].try_into().unwrap()));
+    assert_eq!(vrf.as_u128_le().unwrap(), vrf.as_u128_be().unwrap().swap_bytes());
+}
+
+/// Test that the big integer view spans the whole output with the u128 view as its top half.
+#[cfg(feature = "num-bigint")]
+#[test]
+fn test_output_biguint_view() {
+    use nebula_vrf::vrf::VRFProof;
+
+    let known = VRFProof { output: (0u8..32).collect(), proof: Vec::new(), public_key: Vec::new() };
+    let expected = num_bigint::BigUint::parse_bytes(
+        b"000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f",
+        16,
+    )
+    .unwrap();
+    assert_eq!(known.as_biguint().unwrap(), expected);
+    assert_eq!(known.as_biguint().unwrap() >> 128u32, num_bigint::BigUint::from(known.as_u128_be().unwrap()));
+}
+
+/// Test that the BLS backend behind `VrfScheme` matches the free functions.
+#[test]
+fn test_bls_vrf_scheme() {
+    use nebula_vrf::vrf::{BlsVrf, VrfScheme};
+
+    let keypair = VrfKeypair::from_ikm(&[7u8; 32]).unwrap();
+    let expected = keypair.prove(b"scheme-seed");
+    let scheme = BlsVrf::from(keypair);
+
+    let proof = scheme.prove(b"scheme-seed");
+    let public_key = scheme.public_key();
+    assert_eq!(proof, expected.proof);
+    assert_eq!(BlsVrf::verify(&public_key, b"scheme-seed", &proof).unwrap(), expected.randomness().unwrap());
+    assert_eq!(BlsVrf::proof_to_hash(&proof).unwrap(), expected.randomness().unwrap());
+    assert!(matches!(BlsVrf::verify(&public_key, b"other-seed", &proof), Err(VRFError::VerificationFailed)));
+}
+
+/// Test that the commit-reveal check runs unchanged over a different backend.
+#[test]
+fn test_verify_reveal_with_other_scheme() {
+    use nebula_vrf::vrf::commit::verify_reveal_with;
+    use nebula_vrf::vrf::VrfScheme;
+    use sha2::{Digest, Sha512};
+
+    /// Insecure stand-in with 64-byte outputs, like ECVRF-EDWARDS25519-SHA512.
+    struct ToyVrf([u8; 4]);
+
+    impl VrfScheme for ToyVrf {
+        type PublicKey = [u8; 4];
+        type Proof = [u8; 64];
+        type Output = [u8; 64];
+
+        fn public_key(&self) -> [u8; 4] {
+            self.0
+        }
+
+        fn prove(&self, seed: &[u8]) -> [u8; 64] {
+            Sha512::new().chain_update(self.0).chain_update(seed).finalize().into()
+        }
+
+        fn proof_to_hash(proof: &[u8; 64]) -> Result<[u8; 64], VRFError> {
+            Ok(*proof)
+        }
+
+        fn verify(public_key: &[u8; 4], seed: &[u8], proof: &[u8; 64]) -> Result<[u8; 64], VRFError> {
+            if ToyVrf(*public_key).prove(seed) == *proof {
+                Ok(*proof)
+            } else {
+                Err(VRFError::VerificationFailed)
+            }
+        }
+    }
+
+    let (seed, salt) = (b"toy-seed", b"toy-salt");
+    let commitment = commit_with_salt(seed, salt);
+    let toy = ToyVrf([1, 2, 3, 4]);
+    let proof = toy.prove(seed);
+    let output = ToyVrf::proof_to_hash(&proof).unwrap();
+
+    assert!(verify_reveal_with::<ToyVrf>(&commitment, seed, salt, &output, &proof, &toy.public_key()).is_ok());
+    assert!(matches!(
+        verify_reveal_with::<ToyVrf>(&commitment, seed, salt, &output, &proof, &[0; 4]),
+        Err(VRFError::VerificationFailed)
+    ));
+    assert!(matches!(
+        verify_reveal_with::<ToyVrf>(&commitment, seed, salt, &output[..32], &proof, &toy.public_key()),
+        Err(VRFError::VerificationFailed)
+    ));
+    assert!(matches!(
+        verify_reveal_with::<ToyVrf>(&commitment, b"other", salt, &output, &proof, &toy.public_key()),
+        Err(VRFError::InvalidCommitment)
+    ));
+}
+
+/// Test that a proof round-trips through its single-blob encoding in both point layouts.
+#[test]
+fn test_proof_bytes_round_trip() {
+    for encoding in [Encoding::Compressed, Encoding::Uncompressed] {
+        let vrf = generate_random_with_encoding(b"blob-seed", encoding).unwrap();
+        let bytes = vrf.to_bytes().unwrap();
+        assert_eq!(bytes.len(), 7 + vrf.output.len() + vrf.proof.len() + vrf.public_key.len());
+        assert_eq!(nebula_vrf::vrf::VRFProof::from_bytes(&bytes).unwrap(), vrf);
+    }
+}
+
+/// Test that proof ids survive re-parsing and differ between proofs and encodings.
+#[test]
+fn test_proof_id() {
+    use nebula_vrf::vrf::VRFProof;
+
+    let vrf = generate_random(b"id-seed").unwrap();
+    let bytes = vrf.to_bytes().unwrap();
+    let copy_a = VRFProof::from_bytes(&bytes).unwrap();
+    let copy_b = VRFProof::from_bytes(&copy_a.to_bytes().unwrap()).unwrap();
+    assert_eq!(copy_a.id().unwrap(), copy_b.id().unwrap());
+    assert_eq!(copy_a.id().unwrap(), vrf.id().unwrap());
+    assert_eq!(vrf.id().unwrap(), nebula_vrf::utils::hash::sha256(&bytes));
+
+    assert_ne!(vrf.id().unwrap(), generate_random(b"other-id-seed").unwrap().id().unwrap());
+    let uncompressed = generate_random_with_encoding(b"id-seed", Encoding::Uncompressed).unwrap();
+    assert_ne!(vrf.id().unwrap(), uncompressed.id().unwrap(), "The id is over the encoded bytes");
+}
+
+/// Test that a field too long for its `u16` length prefix fails to encode
+/// instead of being written with a truncated length.
+#[test]
+fn test_proof_to_bytes_rejects_oversized_fields() {
+    use nebula_vrf::vrf::VRFProof;
+
+    let mut vrf = generate_random(b"blob-seed").unwrap();
+    vrf.public_key = vec![0u8; usize::from(u16::MAX) + 1];
+    assert!(matches!(
+        vrf.to_bytes(),
+        Err(VRFError::InvalidLength { expected: 65535, got: 65536 })
+    ));
+    assert!(vrf.id().is_err());
+
+    vrf.public_key.pop();
+    let bytes = vrf.to_bytes().unwrap();
+    assert_eq!(&bytes[5..7], &[0xff, 0xff]);
+    assert!(VRFProof::from_bytes(&bytes).is_err(), "Sizes are still checked on parse");
+}
+
+/// Test that truncated, padded, or mislabelled blobs are rejected.
+#[test]
+fn test_proof_from_bytes_rejects_malformed() {
+    use nebula_vrf::vrf::VRFProof;
+    let bytes = generate_random(b"blob-seed").unwrap().to_bytes().unwrap();
+
+    let truncated = VRFProof::from_bytes(&bytes[..bytes.len() - 1]);
+    assert!(matches!(truncated, Err(VRFError::InvalidLength { expected, got }) if expected == bytes.len() && got == bytes.len() - 1));
+    assert!(matches!(VRFProof::from_bytes(&bytes[..3]), Err(VRFError::InvalidLength { expected: 7, got: 3 })));
+
+    let mut trailing = bytes.clone();
+    trailing.push(0);
+    assert!(matches!(VRFProof::from_bytes(&trailing), Err(VRFError::InvalidLength { .. })));
+
+    let mut bad_version = bytes.clone();
+    bad_version[0] = 0xff;
+    assert!(matches!(VRFProof::from_bytes(&bad_version), Err(VRFError::UnsupportedVersion { version: 0xff })));
+}
+
+/// Test that versioned commitments round-trip and parsers reject other versions.
+#[test]
+fn test_versioned_formats_reject_unknown_versions() {
+    use nebula_vrf::vrf::commit::{commit_v2, parse_commitment_v2, verify_commit_v2, COMMITMENT_FORMAT_VERSION};
+    use nebula_vrf::vrf::VRFProof;
+
+    let commitment = commit_v2(b"seed", b"salt");
+    assert_eq!(commitment[0], COMMITMENT_FORMAT_VERSION);
+    assert_eq!(parse_commitment_v2(&commitment).unwrap(), commit_with_salt(b"seed", b"salt"));
+    assert!(verify_commit_v2(b"seed", b"salt", &commitment).unwrap());
+    assert!(!verify_commit_v2(b"seed", b"pepper", &commitment).unwrap());
+
+    // A format-1 commitment with a version byte, and an unversioned one.
+    let mut v1 = commitment;
+    v1[0] = 1;
+    assert!(matches!(verify_commit_v2(b"seed", b"salt", &v1), Err(VRFError::UnsupportedVersion { version: 1 })));
+    let unversioned = commit_with_salt(b"seed", b"salt");
+    assert!(matches!(parse_commitment_v2(&unversioned), Err(VRFError::InvalidLength { expected: 33, got: 32 })));
+
+    // A proof blob from a future format is rejected by this version-1 parser.
+    let mut v2_blob = generate_random(b"blob-seed").unwrap().to_bytes().unwrap();
+    v2_blob[0] = 2;
+    assert!(matches!(VRFProof::from_bytes(&v2_blob), Err(VRFError::UnsupportedVersion { version: 2 })));
+}
+
+/// Test that a proof round-trips through JSON with hex-encoded fields.
+#[cfg(feature = "serde")]
+#[test]
+fn test_proof_serde_round_trip() {
+    let vrf = generate_random(b"serde-seed").unwrap();
+
+    let json = serde_json::to_string(&vrf).expect("serialize failed");
+    let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+    assert_eq!(value["output"], hex::encode(&vrf.output));
+    assert_eq!(value["proof"], hex::encode(&vrf.proof));
+    assert_eq!(value["public_key"], hex::encode(&vrf.public_key));
+
+    let decoded: nebula_vrf::vrf::VRFProof = serde_json::from_str(&json).expect("deserialize failed");
+    assert_eq!(decoded, vrf);
+}
+
+/// Test that every output accessor reports a short output as `InvalidLength`
+/// instead of panicking, including on a proof deserialized from JSON.
+#[test]
+fn test_short_output_is_rejected() {
+    use nebula_vrf::vrf::VRFProof;
+
+    let short = VRFProof { output: vec![0u8; 31], proof: Vec::new(), public_key: Vec::new() };
+    let too_short = |r: Result<(), VRFError>| matches!(r, Err(VRFError::InvalidLength { expected: 32, got: 31 }));
+    assert!(too_short(short.randomness().map(|_| ())));
+    assert!(too_short(short.output_at(0).map(|_| ())));
+    assert!(too_short(short.as_u128_be().map(|_| ())));
+    assert!(too_short(short.as_u128_le().map(|_| ())));
+    assert!(too_short(derive_child(&short, b"label").map(|_| ())));
+    #[cfg(feature = "num-bigint")]
+    assert!(too_short(short.as_biguint().map(|_| ())));
+
+    #[cfg(feature = "serde")]
+    {
+        let json = format!(r#"{{"output":"{}","proof":"","public_key":""}}"#, hex::encode([0u8; 31]));
+        let decoded: VRFProof = serde_json::from_str(&json).unwrap();
+        assert!(too_short(decoded.randomness().map(|_| ())));
+    }
+}
+
+/// Test that a keypair's Debug output shows the public key but never the secret.
+#[test]
+fn test_keypair_debug_hides_secret() {
+    let keypair = VrfKeypair::from_ikm(&[0x42; 32]).unwrap();
+    let secret = keypair.expose_secret();
+    let debug = format!("{:?} {:#?}", keypair, keypair);
+
+    assert!(debug.contains(&hex::encode(keypair.public_key())));
+    assert!(!debug.contains(&hex::encode(*secret)));
+    assert!(!debug.contains(&format!("{:?}", *secret)));
+    assert!(!debug.contains("secret"));
+
+    // The same IKM always yields the same secret scalar.
+    assert_eq!(*secret, *VrfKeypair::from_ikm(&[0x42; 32]).unwrap().expose_secret());
+}
+
+/// Test that counter-mode outputs are distinct per index and reproducible.
+#[test]
+fn test_output_at() {
+    let vrf = generate_random(b"counter-seed").unwrap();
+    let outputs: Vec<[u8; 32]> = (0..64).map(|i| vrf.output_at(i).unwrap()).collect();
+    for (i, a) in outputs.iter().enumerate() {
+        assert!(outputs[i + 1..].iter().all(|b| a != b), "index {} repeats", i);
+        assert_ne!(*a, vrf.randomness().unwrap());
+    }
+
+    let mut input = vrf.randomness().unwrap().to_vec();
+    input.extend_from_slice(&0u64.to_be_bytes());
+    let expected = sha256(&input);
+    assert_eq!(vrf.output_at(0).unwrap(), expected);
+    assert_eq!(generate_random(b"counter-seed").unwrap().output_at(0).unwrap(), expected);
 }
@@ -0,0 +1,39 @@
+//! Tests for the wasm_bindgen wrappers, run natively.
+
+#![cfg(feature = "wasm")]
+
+use nebula_vrf::vrf::generate_random;
+use nebula_vrf::wasm::{commit, verify_commit, verify_proof};
+
+/// Test that a generated proof verifies through the hex wrapper.
+#[test]
+fn test_verify_proof_hex() {
+    let vrf = generate_random(b"wasm-seed").unwrap();
+    let seed = hex::encode(b"wasm-seed");
+    let proof = hex::encode(&vrf.proof);
+    let public_key = hex::encode(&vrf.public_key);
+
+    assert!(verify_proof(&seed, &proof, &public_key));
+    assert!(!verify_proof(&hex::encode(b"other-seed"), &proof, &public_key));
+}
+
+/// Test that malformed input returns false or None instead of panicking.
+#[test]
+fn test_bad_input_never_panics() {
+    assert!(!verify_proof("zz", "00", "00"));
+    assert!(!verify_proof("", "", ""));
+    assert!(!verify_proof("00", &"ab".repeat(48), &"cd".repeat(96)));
+
+    assert_eq!(commit("not hex"), None);
+    assert!(!verify_commit("00", "abcd"));
+    assert!(!verify_commit("zz", &"00".repeat(32)));
+}
+
+/// Test that commit and verifyCommit agree with the library.
+#[test]
+fn test_commit_round_trip() {
+    let commitment = commit("0102").unwrap();
+    assert_eq!(commitment, hex::encode(nebula_vrf::vrf::commit::commit(&[1, 2])));
+    assert!(verify_commit("0102", &commitment));
+    assert!(!verify_commit("0103", &commitment));
+}